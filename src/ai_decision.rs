@@ -22,11 +22,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::battle::{BattleSide, PendingAiMove, AI_MELEE_RANGE, AI_MOVE_CAP};
 use crate::constants::PLAYER_SPEED;
+use crate::core::Difficulty;
 use crate::combat_ability::{Ability, AbilityEffect, Ability_Tree};
 use crate::combat_plugin::{
-    Abilities, AIParameters, AbilityIntentEvent, ActionCause, AttackContext, AttackIntentEvent,
-    CombatStats, DefendIntentEvent, PlayerControlled, TargetFocus, TurnEndEvent, TurnInProgress,
-    TurnStartEvent, WaitIntentEvent,
+    AIParameters, Abilities, AbilityIntentEvent, ActionCause, AttackContext, AttackIntentEvent,
+    CombatRng, CombatStats, DefendIntentEvent, PlayerControlled, TargetFocus, TurnEndEvent,
+    TurnInProgress, TurnStartEvent, WaitIntentEvent,
 };
 
 const BEHAVIOR_TREE_PATH: &str = "assets/data/decision_tree.ron";
@@ -135,6 +136,7 @@ pub struct AiDecisionPlugin;
 impl Plugin for AiDecisionPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<BehaviorTreeAssets>()
+            .init_resource::<Difficulty>()
             .add_systems(Startup, load_behavior_trees)
             .add_systems(Update, evaluate_behavior_tree_system);
     }
@@ -166,8 +168,18 @@ pub struct ActorSnapshot {
 
 pub struct BtContext<'a> {
     pub actor: ActorSnapshot,
+    /// The summoner, when the actor is a [`crate::battle::MinionOf`] minion.
+    /// Minions strike whoever stands closest to their owner and heal the owner
+    /// first, whatever their own focus preference says.
+    pub owner: Option<ActorSnapshot>,
     pub allies: Vec<ActorSnapshot>,
     pub enemies: Vec<ActorSnapshot>,
+    /// From [`Difficulty::ai_mistake_chance`]: how often a target or damage
+    /// ability pick ignores the scoring and goes random.
+    pub mistake_chance: f32,
+    /// From [`Difficulty::ai_finisher_threshold`]: enemies at or below this
+    /// health percent are targeted first.
+    pub finisher_below: Option<u8>,
     pub ability_tree: Option<&'a Ability_Tree>,
     pub decision: Option<AiAction>,
 }
@@ -177,6 +189,13 @@ impl BtContext<'_> {
         if self.enemies.is_empty() {
             return None;
         }
+        if let Some(owner) = &self.owner {
+            return self.enemies.iter().min_by(|a, b| {
+                let da = owner.position.distance_squared(a.position);
+                let db = owner.position.distance_squared(b.position);
+                da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+            });
+        }
         match focus {
             TargetFocus::LowestHp => self.enemies.iter().min_by_key(|t| t.hp_percent),
             TargetFocus::HighestHp => self.enemies.iter().max_by_key(|t| t.hp_percent),
@@ -190,14 +209,95 @@ impl BtContext<'_> {
                 let db = self.actor.position.distance_squared(b.position);
                 da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
             }),
+            TargetFocus::Pack => self.enemies.iter().max_by(|a, b| {
+                let sa = pack_target_score(&self.actor, &self.allies, a);
+                let sb = pack_target_score(&self.actor, &self.allies, b);
+                sa.partial_cmp(&sb).unwrap_or(std::cmp::Ordering::Equal)
+            }),
+        }
+    }
+
+    /// The focus pick, or on a blunder (see [`BtContext::mistake_chance`]) any
+    /// enemy at random. On Hard the most wounded enemy under
+    /// [`BtContext::finisher_below`] wins over the focus, except for minions,
+    /// which keep guarding their owner.
+    fn pick_target(&self, focus: TargetFocus, rng: &mut impl Rng) -> Option<Entity> {
+        if self.blunders(rng) && !self.enemies.is_empty() {
+            return Some(self.enemies[rng.random_range(0..self.enemies.len())].entity);
+        }
+        if let Some(threshold) = self.finisher_below.filter(|_| self.owner.is_none()) {
+            let wounded = self
+                .enemies
+                .iter()
+                .filter(|t| t.hp_percent <= threshold)
+                .min_by_key(|t| t.hp_percent);
+            if let Some(wounded) = wounded {
+                return Some(wounded.entity);
+            }
         }
+        self.target_for_focus(focus).map(|t| t.entity)
+    }
+
+    fn blunders(&self, rng: &mut impl Rng) -> bool {
+        self.mistake_chance > 0.0 && rng.random::<f32>() < self.mistake_chance
     }
 
     fn weakest_ally(&self) -> Option<&ActorSnapshot> {
+        if let Some(owner) = self.owner.as_ref().filter(|o| o.hp_percent < 100) {
+            return Some(owner);
+        }
         self.allies.iter().min_by_key(|a| a.hp_percent)
     }
 }
 
+// ---------------------------------------------------------------------------
+// Pack coordination
+// ---------------------------------------------------------------------------
+
+/// Score bonus per packmate already in melee reach of a target, capped at two
+/// flankers so a dogpile doesn't keep attracting more bodies.
+const PACK_FLANK_BONUS: f32 = 15.0;
+/// Packmates closer than this to our approach point count as clustered —
+/// one AoE would catch both of us.
+const PACK_CLUSTER_RADIUS: f32 = AI_MELEE_RANGE * 0.75;
+const PACK_CLUSTER_PENALTY: f32 = 20.0;
+
+/// How attractive `target` is to `actor` under [`TargetFocus::Pack`].
+///
+/// Wounded targets dominate (scaled by aggressiveness so timid packs spread
+/// their attention), packmates already adjacent to the target add a flanking
+/// bonus, and a packmate standing where we'd end up — the target's near side
+/// from our position — costs a clustering penalty scaled by caution. Distance
+/// is a light tie-breaker.
+pub fn pack_target_score(
+    actor: &ActorSnapshot,
+    packmates: &[ActorSnapshot],
+    target: &ActorSnapshot,
+) -> f32 {
+    let aggression = actor.params.aggressiveness.min(10) as f32 / 10.0;
+    let caution = actor.params.caution.min(10) as f32 / 10.0;
+    let wound = (100 - target.hp_percent.min(100)) as f32 * (0.5 + aggression);
+
+    let to_actor = (actor.position - target.position).normalize_or_zero();
+    let approach = target.position + to_actor * AI_MELEE_RANGE;
+    let mut flankers = 0u8;
+    let mut clustered = 0u8;
+    for mate in packmates {
+        if mate.position.distance(target.position) <= AI_MELEE_RANGE {
+            if mate.position.distance(approach) < PACK_CLUSTER_RADIUS {
+                clustered += 1;
+            } else {
+                flankers += 1;
+            }
+        }
+    }
+    let flank = flankers.min(2) as f32 * PACK_FLANK_BONUS;
+    let cluster = clustered as f32 * PACK_CLUSTER_PENALTY * (0.5 + caution);
+    let travel = actor.position.distance(target.position) / AI_MOVE_CAP * 10.0;
+
+    wound + flank - cluster - travel
+}
+
 // ---------------------------------------------------------------------------
 // Evaluator
 // ---------------------------------------------------------------------------
@@ -226,7 +326,7 @@ pub fn tick(node: &BtNode, ctx: &mut BtContext, rng: &mut impl Rng) -> BtStatus
             if total == 0 || weighted.is_empty() {
                 return Failure;
             }
-            let mut roll = rng.gen_range(0..total);
+            let mut roll = crate::combat_plugin::safe_range(rng, 0, total);
             for (weight, child) in weighted {
                 if roll < *weight {
                     return tick(child, ctx, rng);
@@ -281,8 +381,8 @@ pub fn tick(node: &BtNode, ctx: &mut BtContext, rng: &mut impl Rng) -> BtStatus
             bool_to_status(rng.gen_range(0..100) < threshold)
         }
         BtNode::BasicAttack => {
-            if let Some(target) = ctx.target_for_focus(ctx.actor.params.focus_preference) {
-                ctx.decision = Some(AiAction::Attack { target: target.entity });
+            if let Some(target) = ctx.pick_target(ctx.actor.params.focus_preference, rng) {
+                ctx.decision = Some(AiAction::Attack { target });
                 Success
             } else {
                 Failure
@@ -292,12 +392,12 @@ pub fn tick(node: &BtNode, ctx: &mut BtContext, rng: &mut impl Rng) -> BtStatus
             if !ctx.actor.abilities.iter().any(|owned| owned == id) {
                 return Failure;
             }
-            let Some(target) = ctx.target_for_focus(ctx.actor.params.focus_preference) else {
+            let Some(target) = ctx.pick_target(ctx.actor.params.focus_preference, rng) else {
                 return Failure;
             };
             ctx.decision = Some(AiAction::Ability {
                 ability_id: *id,
-                target: target.entity,
+                target,
             });
             Success
         }
@@ -336,6 +436,7 @@ pub fn tick(node: &BtNode, ctx: &mut BtContext, rng: &mut impl Rng) -> BtStatus
                 return Failure;
             };
             let mut best: Option<(u16, u32)> = None;
+            let mut affordable: Vec<u16> = Vec::new();
             for &owned_id in &ctx.actor.abilities {
                 let Some(ability) = tree.0.find(owned_id) else {
                     continue;
@@ -347,19 +448,23 @@ pub fn tick(node: &BtNode, ctx: &mut BtContext, rng: &mut impl Rng) -> BtStatus
                 if damage == 0 {
                     continue;
                 }
+                affordable.push(owned_id);
                 if best.map_or(true, |(_, d)| damage > d) {
                     best = Some((owned_id, damage));
                 }
             }
-            let Some((id, _)) = best else {
+            let Some((mut id, _)) = best else {
                 return Failure;
             };
-            let Some(target) = ctx.target_for_focus(ctx.actor.params.focus_preference) else {
+            if ctx.blunders(rng) {
+                id = affordable[rng.random_range(0..affordable.len())];
+            }
+            let Some(target) = ctx.pick_target(ctx.actor.params.focus_preference, rng) else {
                 return Failure;
             };
             ctx.decision = Some(AiAction::Ability {
                 ability_id: id,
-                target: target.entity,
+                target,
             });
             Success
         }
@@ -398,6 +503,8 @@ pub fn evaluate_behavior_tree_system(
     mut commands: Commands,
     mut turn_start_reader: MessageReader<TurnStartEvent>,
     profiles: Res<BehaviorTreeAssets>,
+    difficulty: Res<Difficulty>,
+    mut rng: ResMut<CombatRng>,
     ability_tree: Option<Res<Ability_Tree>>,
     actors: Query<(
         Entity,
@@ -408,6 +515,7 @@ pub fn evaluate_behavior_tree_system(
         Option<&GlobalTransform>,
     )>,
     profile_q: Query<&BehaviorTreeProfile>,
+    minion_q: Query<&crate::battle::MinionOf>,
     player_q: Query<(), With<PlayerControlled>>,
     mut intent_writer: MessageWriter<AttackIntentEvent>,
     mut ability_writer: MessageWriter<AbilityIntentEvent>,
@@ -416,7 +524,6 @@ pub fn evaluate_behavior_tree_system(
     mut turn_end_writer: MessageWriter<TurnEndEvent>,
     mut turn_in_progress: ResMut<TurnInProgress>,
 ) {
-    let mut rng = rand::rng();
     for ev in turn_start_reader.read() {
         if player_q.get(ev.who).is_ok() {
             continue;
@@ -454,14 +561,21 @@ pub fn evaluate_behavior_tree_system(
             }
         }
 
+        let owner = minion_q
+            .get(ev.who)
+            .ok()
+            .and_then(|m| allies.iter().find(|a| a.entity == m.0).cloned());
         let mut ctx = BtContext {
             actor: actor_snapshot,
+            owner,
             allies,
             enemies,
+            mistake_chance: difficulty.ai_mistake_chance(),
+            finisher_below: difficulty.ai_finisher_threshold(),
             ability_tree: ability_tree.as_deref(),
             decision: None,
         };
-        tick(&profile.logic, &mut ctx, &mut rng);
+        tick(&profile.logic, &mut ctx, &mut rng.0);
 
         let actor = ev.who;
         // When a melee attacker's target is out of reach, defer the strike:
@@ -578,6 +692,142 @@ fn build_snapshot(
 mod tests {
     use super::*;
 
+    fn snapshot(entity: Entity, side: BattleSide, hp_percent: u8, position: Vec2) -> ActorSnapshot {
+        ActorSnapshot {
+            entity,
+            side,
+            hp_percent,
+            magic_percent: 100,
+            action_points: 3,
+            abilities: Vec::new(),
+            params: AIParameters {
+                focus_preference: TargetFocus::Pack,
+                ..AIParameters::default()
+            },
+            position,
+        }
+    }
+
+    /// Two pack enemies facing a healthy frontliner and a wounded backliner
+    /// should both commit to the wounded one, even though the healthy target
+    /// is closer to each of them.
+    #[test]
+    fn pack_focus_fires_low_hp_ally() {
+        let mut world = World::new();
+        let wolf_a = world.spawn_empty().id();
+        let wolf_b = world.spawn_empty().id();
+        let tank = world.spawn_empty().id();
+        let healer = world.spawn_empty().id();
+
+        let wolves = [
+            snapshot(wolf_a, BattleSide::Enemy, 100, Vec2::new(-40.0, 60.0)),
+            snapshot(wolf_b, BattleSide::Enemy, 100, Vec2::new(40.0, 60.0)),
+        ];
+        let party = vec![
+            snapshot(tank, BattleSide::Ally, 100, Vec2::new(0.0, 20.0)),
+            snapshot(healer, BattleSide::Ally, 15, Vec2::new(0.0, -60.0)),
+        ];
+
+        let mut rng = rand::rng();
+        for (i, wolf) in wolves.iter().enumerate() {
+            let mut ctx = BtContext {
+                actor: wolf.clone(),
+                owner: None,
+                allies: vec![wolves[1 - i].clone()],
+                enemies: party.clone(),
+                mistake_chance: 0.0,
+                finisher_below: None,
+                ability_tree: None,
+                decision: None,
+            };
+            assert_eq!(tick(&BtNode::BasicAttack, &mut ctx, &mut rng), BtStatus::Success);
+            match ctx.decision {
+                Some(AiAction::Attack { target }) => assert_eq!(target, healer),
+                other => panic!("expected an attack on the healer, got {other:?}"),
+            }
+        }
+    }
+
+    /// A wolf whose focus always picks the wounded healer. On Easy it
+    /// sometimes swings at the healthy tank instead; on Hard it never does.
+    #[test]
+    fn easy_difficulty_makes_suboptimal_picks() {
+        let mut world = World::new();
+        let wolf = world.spawn_empty().id();
+        let tank = world.spawn_empty().id();
+        let healer = world.spawn_empty().id();
+        let mut actor = snapshot(wolf, BattleSide::Enemy, 100, Vec2::ZERO);
+        actor.params.focus_preference = TargetFocus::LowestHp;
+        let party = vec![
+            snapshot(tank, BattleSide::Ally, 100, Vec2::new(0.0, 20.0)),
+            snapshot(healer, BattleSide::Ally, 15, Vec2::new(0.0, -60.0)),
+        ];
+
+        let picks = |difficulty: Difficulty| {
+            let mut rng = CombatRng::seeded(2512).0;
+            (0..40)
+                .map(|_| {
+                    let mut ctx = BtContext {
+                        actor: actor.clone(),
+                        owner: None,
+                        allies: Vec::new(),
+                        enemies: party.clone(),
+                        mistake_chance: difficulty.ai_mistake_chance(),
+                        finisher_below: difficulty.ai_finisher_threshold(),
+                        ability_tree: None,
+                        decision: None,
+                    };
+                    tick(&BtNode::BasicAttack, &mut ctx, &mut rng);
+                    match ctx.decision {
+                        Some(AiAction::Attack { target }) => target,
+                        other => panic!("expected an attack, got {other:?}"),
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert!(picks(Difficulty::Normal).iter().all(|&t| t == healer));
+        assert!(picks(Difficulty::Hard).iter().all(|&t| t == healer));
+        assert!(picks(Difficulty::Easy).contains(&tank), "Easy never blundered");
+    }
+
+    /// A wolf that always bites whoever stands closest. On Normal that is the
+    /// tank; on Hard it reaches past to finish the wounded healer.
+    #[test]
+    fn hard_difficulty_finishes_off_the_wounded() {
+        let mut world = World::new();
+        let wolf = world.spawn_empty().id();
+        let tank = world.spawn_empty().id();
+        let healer = world.spawn_empty().id();
+        let mut actor = snapshot(wolf, BattleSide::Enemy, 100, Vec2::ZERO);
+        actor.params.focus_preference = TargetFocus::Closest;
+        let party = vec![
+            snapshot(tank, BattleSide::Ally, 100, Vec2::new(0.0, 20.0)),
+            snapshot(healer, BattleSide::Ally, 15, Vec2::new(0.0, -60.0)),
+        ];
+
+        let pick = |difficulty: Difficulty| {
+            let mut ctx = BtContext {
+                actor: actor.clone(),
+                owner: None,
+                allies: Vec::new(),
+                enemies: party.clone(),
+                mistake_chance: difficulty.ai_mistake_chance(),
+                finisher_below: difficulty.ai_finisher_threshold(),
+                ability_tree: None,
+                decision: None,
+            };
+            tick(&BtNode::BasicAttack, &mut ctx, &mut CombatRng::seeded(2512).0);
+            match ctx.decision {
+                Some(AiAction::Attack { target }) => target,
+                other => panic!("expected an attack, got {other:?}"),
+            }
+        };
+
+        assert_eq!(pick(Difficulty::Normal), tank);
+        assert_eq!(pick(Difficulty::Hard), healer);
+    }
+
     /// The shipped profiles must round-trip through serde or the game won't
     /// load any AI behaviour.
     #[test]
@@ -1,32 +1,39 @@
+use std::collections::{HashMap, HashSet};
+
 use bevy::input::keyboard::KeyCode;
-use bevy::prelude::*;
 use bevy::prelude::Messages;
+use bevy::prelude::*;
 
 use crate::characters::CharacterKind;
+use crate::combat_ability::{AbilityShape, MagicSchool, SummonKind, ZoneEffect};
 use crate::combat_plugin::{
-    Abilities, AccumulatedSpeed, ActionCause, AttackContext, AttackIntentEvent, Bound, CombatStats,
-    DamageEvent, DamageType, Dead, DeathEvent, ElementalAffinity, Experience, GrowthAttributes, Level,
-    MagicDistribution, PendingPlayerAction, PlayerAction, PlayerActionEvent, PlayerControlled,
-    ResurrectionStanding, RoundEndEvent, StatModifiers, StatPool, SummonEvent, TurnEndEvent,
+    experience_at_level, zone_shape_covers, Abilities, AccumulatedSpeed, ActionCause,
+    AttackContext, AttackIntentEvent, Attunement, AwardXpEvent, Bound, Buff, CombatStats,
+    DamageEvent, DamageType, Dead, DeathEvent, ElementalAffinity, Experience, GrowthAttributes,
+    Guard, HealEvent, Level, LevelUpEvent, LootItem, MagicDistribution, PendingPlayerAction,
+    PlayerAction, PlayerActionEvent, PlayerControlled, PolarityFlip, ResurrectionStanding,
+    RoundEndEvent, SpawnZoneEvent, StatModifiers, StatPool, SummonEvent, TurnEndEvent,
     TurnInProgress, TurnManager, TurnOrder, TurnStartEvent, WaitIntentEvent,
 };
-use crate::gogyo::{Phase, Polarity};
-use crate::status_effects::{ApplyStatusEvent, BadConditionKind, StatusKind, Tier};
-use std::collections::HashSet;
-use crate::dialogue::{DialogueBoxTriggerEvent, DialogueCatalog, DialogueRuntime};
-use crate::quests::HuntRegistry;
 use crate::constants::{DEFAULT_ACTION_POINTS, GRID_HEIGHT, GRID_WIDTH, PLAYER_SPEED};
 use crate::core::{GameState, Game_State, Global_Variables, MainCamera, Player, Position};
+use crate::dialogue::{
+    ConditionContext, DialogueBoxTriggerEvent, DialogueCatalog, DialogueRuntime,
+};
 use crate::economy::MerchantNpc;
+use crate::gogyo::{Phase, Polarity};
 use crate::governance::{
     CastleAssaultStartedEvent, GovernorCombatant, GovernorNpc, SuccessorCombatant, SuccessorNpc,
 };
-use crate::combat_ability::{MagicSchool, SummonKind};
 use crate::pathfinding::is_walkable_move;
 use crate::quadtree::QuadTree;
+use crate::quests::HuntRegistry;
 use crate::skill_tree::{
     LearnedSkills, MagicCostMultipliers, ProgressionPending, SkillPoints, SkillTreeAccess,
 };
+use crate::status_effects::{
+    ApplyStatusEvent, BadConditionKind, RegenBuff, StatusEffects, StatusKind, Tier,
+};
 
 #[derive(Component, Clone, Copy, Debug)]
 pub struct EnemyEncounter {
@@ -97,6 +104,13 @@ pub struct SummonLifetime {
     pub remaining_turns: u8,
 }
 
+/// Links a summoned combatant to the character who called it. A minion can't
+/// outlast its owner: `dismiss_orphaned_minions_system` removes it the moment
+/// the owner dies or leaves the battle, and its AI fights in the owner's
+/// defence (see [`crate::ai_decision::BtContext::owner`]).
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MinionOf(pub Entity);
+
 /// Marks a summoned, non-combatant obstacle (e.g. a Spirit Ward). It has a
 /// `Collider` but no `CombatStats`, so it never enters turn order and never
 /// receives a `TurnEndEvent` — instead `tick_obstacle_lifetime_system`
@@ -150,11 +164,238 @@ pub struct ObstacleAura {
 #[derive(Component, Clone, Default, Debug)]
 pub struct ObstacleOccupants(pub HashSet<Entity>);
 
+/// Side of one zone tile, in world units (the map's tile grid).
+pub const ZONE_TILE_SIZE: f32 = 32.0;
+
+/// A persistent area left by an [`AbilityEffect::Zone`](crate::combat_ability::AbilityEffect::Zone)
+/// cast — a wall of fire, a healing circle. Covers a fixed set of tiles; once
+/// per battle round `zone_tick_system` applies `effect` to every combatant
+/// standing on one, then counts `remaining_turns` down and despawns the zone
+/// at zero.
+#[derive(Component, Clone, Debug)]
+pub struct ZoneEntity {
+    pub tiles: HashSet<IVec2>,
+    pub effect: ZoneEffect,
+    pub remaining_turns: u8,
+    pub source: Entity,
+}
+
+/// The zone tile containing world position `pos`.
+pub fn zone_tile(pos: Vec2) -> IVec2 {
+    (pos / ZONE_TILE_SIZE).floor().as_ivec2()
+}
+
+/// Tiles whose centres fall inside `shape` laid down from `caster` at
+/// `target` (see [`zone_shape_covers`]), always including the target's own.
+fn zone_tiles(shape: &AbilityShape, caster: Vec2, target: Vec2) -> HashSet<IVec2> {
+    let (centre, reach) = match shape {
+        AbilityShape::Radius(radius) => (target, *radius),
+        AbilityShape::Line { length, thickness } => (caster, length + thickness),
+        AbilityShape::Cone { radius, .. } => (caster, *radius),
+        AbilityShape::Select => (target, 0.0),
+    };
+    let mut tiles = HashSet::from([zone_tile(target)]);
+    let (min, max) = (
+        zone_tile(centre - Vec2::splat(reach)),
+        zone_tile(centre + Vec2::splat(reach)),
+    );
+    for y in min.y..=max.y {
+        for x in min.x..=max.x {
+            let tile = IVec2::new(x, y);
+            let tile_centre = (tile.as_vec2() + 0.5) * ZONE_TILE_SIZE;
+            if zone_shape_covers(shape, caster, target, tile_centre) {
+                tiles.insert(tile);
+            }
+        }
+    }
+    tiles
+}
+
 #[derive(Component, Clone, Copy, Debug)]
 pub struct CombatMoveTarget {
     pub target: Vec2,
 }
 
+/// Battlefield for an encounter: how far from its centre the fight may range,
+/// and the fixed obstacles laid out on it (a corridor is a narrow arena, an
+/// open field a wide one with nothing in the way). Sits on the world
+/// `EnemyEncounter` entity; encounters without one fight on the open map.
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct BattleArena {
+    /// Half the arena's width and height, in world units.
+    pub half_extents: Vec2,
+    /// Obstacle footprints, as rects relative to the arena centre.
+    pub obstacles: Vec<Rect>,
+}
+
+impl BattleArena {
+    /// The obstacle footprints in world space, for an arena laid out around
+    /// `centre`.
+    pub fn obstacle_rects(&self, centre: Vec2) -> impl Iterator<Item = Rect> + '_ {
+        self.obstacles.iter().map(move |footprint| {
+            Rect::from_center_size(centre + footprint.center(), footprint.size())
+        })
+    }
+}
+
+/// Bounds of the arena the current battle is fought in, in world space. `None`
+/// outside battle and for encounters without a [`BattleArena`].
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct ActiveArena(pub Option<Rect>);
+
+impl ActiveArena {
+    pub fn allows(&self, pos: Vec2) -> bool {
+        self.0.is_none_or(|bounds| bounds.contains(pos))
+    }
+}
+
+/// An obstacle laid out by the encounter's [`BattleArena`]. Carries a
+/// `Collider` for the fight's duration, so movement and area casts route
+/// around it, and is despawned by `clear_battle_arena_system` at battle end.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ArenaObstacle;
+
+/// Height of the placeholder box drawn for an [`ArenaObstacle`].
+const ARENA_OBSTACLE_HEIGHT: f32 = 64.0;
+
+/// Battle grid geometry that enemy layouts snap to.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct GridConfig {
+    /// Side of one grid tile, in world units.
+    pub tile_size: f32,
+    /// Tiles from one enemy to the next in a formation.
+    pub spacing: i32,
+}
+
+impl Default for GridConfig {
+    fn default() -> Self {
+        Self {
+            tile_size: ZONE_TILE_SIZE,
+            spacing: 2,
+        }
+    }
+}
+
+impl GridConfig {
+    pub fn tile_of(&self, pos: Vec2) -> IVec2 {
+        (pos / self.tile_size).floor().as_ivec2()
+    }
+
+    pub fn tile_centre(&self, tile: IVec2) -> Vec2 {
+        (tile.as_vec2() + 0.5) * self.tile_size
+    }
+
+    /// Tiles whose squares overlap `rect`.
+    pub fn tiles_under(&self, rect: Rect) -> impl Iterator<Item = IVec2> {
+        let grid = *self;
+        let (lo, hi) = (grid.tile_of(rect.min), grid.tile_of(rect.max));
+        let overlaps = move |tile: &IVec2| {
+            let square =
+                Rect::from_center_size(grid.tile_centre(*tile), Vec2::splat(grid.tile_size));
+            !square.intersect(rect).is_empty()
+        };
+        (lo.y..=hi.y)
+            .flat_map(move |y| (lo.x..=hi.x).map(move |x| IVec2::new(x, y)))
+            .filter(overlaps)
+    }
+}
+
+/// How an encounter's enemies are arranged around where it was engaged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Formation {
+    /// Shoulder to shoulder, across the party's line of approach.
+    #[default]
+    Line,
+    /// Packed in rings around the anchor.
+    Cluster,
+    /// A leader at the anchor and pairs fanning out towards the party on both
+    /// sides, closing a pincer.
+    Flanking,
+}
+
+impl Formation {
+    /// The `i`-th slot, in tiles: `x` across the approach, `y` along it (away
+    /// from the party).
+    fn slot(self, i: i32, spacing: i32) -> IVec2 {
+        // 0, +1, -1, +2, -2, ...
+        let fan = if i % 2 == 1 { (i + 1) / 2 } else { -(i / 2) };
+        match self {
+            Formation::Line => IVec2::new(fan * spacing, 0),
+            Formation::Flanking => IVec2::new(fan * spacing * 2, -fan.abs() * spacing),
+            Formation::Cluster => {
+                // Ring 0 is the anchor; ring r holds 8r tiles, walked clockwise.
+                if i == 0 {
+                    return IVec2::ZERO;
+                }
+                let mut ring = 1;
+                let mut first = 1;
+                while i >= first + 8 * ring {
+                    first += 8 * ring;
+                    ring += 1;
+                }
+                let k = i - first;
+                let side = k / (2 * ring);
+                let step = k % (2 * ring);
+                let (x, y) = match side {
+                    0 => (-ring + step, ring),
+                    1 => (ring, ring - step),
+                    2 => (ring - step, -ring),
+                    _ => (-ring, -ring + step),
+                };
+                IVec2::new(x, y) * spacing
+            }
+        }
+    }
+}
+
+/// World positions for `count` enemies in `formation` around `anchor`, one per
+/// grid tile. The formation is turned to face along `facing` (snapped to the
+/// grid's axes so slots stay on tiles), skips tiles in `occupied` (the party)
+/// and outside `bounds`, and never reuses a tile. The same inputs always give
+/// the same layout. Returns fewer than `count` only when the arena is full.
+pub fn enemy_layout(
+    formation: Formation,
+    count: usize,
+    anchor: Vec2,
+    facing: Vec2,
+    bounds: Option<Rect>,
+    occupied: &HashSet<IVec2>,
+    grid: &GridConfig,
+) -> Vec<Vec2> {
+    let along = if facing.x.abs() >= facing.y.abs() {
+        IVec2::new(if facing.x < 0.0 { -1 } else { 1 }, 0)
+    } else {
+        IVec2::new(0, if facing.y < 0.0 { -1 } else { 1 })
+    };
+    let across = along.perp();
+    let origin = grid.tile_of(anchor);
+    let fits = |tile: IVec2, taken: &HashSet<IVec2>| {
+        !occupied.contains(&tile)
+            && !taken.contains(&tile)
+            && bounds.is_none_or(|b| b.contains(grid.tile_centre(tile)))
+    };
+
+    let mut taken = HashSet::new();
+    let mut placed = Vec::with_capacity(count);
+    // The formation's own slots first; once those run off the arena, pack the
+    // rest in tight rings around the anchor.
+    let budget = (count as i32 * 4).max(8);
+    let slots = (0..budget)
+        .map(|i| formation.slot(i, grid.spacing.max(1)))
+        .chain((0..budget * 8).map(|i| Formation::Cluster.slot(i, 1)));
+    for slot in slots {
+        if placed.len() == count {
+            break;
+        }
+        let tile = origin + across * slot.x + along * slot.y;
+        if fits(tile, &taken) {
+            taken.insert(tile);
+            placed.push(grid.tile_centre(tile));
+        }
+    }
+    placed
+}
+
 #[derive(Resource, Default)]
 pub struct BattleState {
     pub active: bool,
@@ -195,8 +436,11 @@ pub fn battle_trigger_system(
             Option<&SuccessorNpc>,
             Option<&WorldYokai>,
             Option<&FinalBoss>,
+            Option<&BattleArena>,
         ),
     >,
+    mut active_arena: ResMut<ActiveArena>,
+    grid: Res<GridConfig>,
     // Downed companions (`Dead`) sit the fight out — they can't be dragged into
     // battle until revived at the shrine.
     ally_q: Query<(Entity, &Transform, Option<&CharacterKind>), (With<WorldAlly>, Without<Dead>)>,
@@ -216,8 +460,16 @@ pub fn battle_trigger_system(
     let player_kind = player_kind.copied();
 
     let player_pos = player_tf.translation.truncate();
-    for (enemy_entity, enemy_tf, encounter, governor_opt, successor_opt, yokai_opt, boss_opt) in
-        enemy_q.iter()
+    for (
+        enemy_entity,
+        enemy_tf,
+        encounter,
+        governor_opt,
+        successor_opt,
+        yokai_opt,
+        boss_opt,
+        arena_opt,
+    ) in enemy_q.iter()
     {
         let enemy_pos = enemy_tf.translation.truncate();
         if player_pos.distance(enemy_pos) <= 32.0 {
@@ -227,6 +479,13 @@ pub fn battle_trigger_system(
             if let Some(city_id) = governor_city_id.or(successor_target.map(|(id, _)| id)) {
                 assault_starts.write(CastleAssaultStartedEvent { city_id });
             }
+            let arena = arena_opt.map(|arena| {
+                let centre = player_pos.lerp(enemy_pos, 0.5);
+                let bounds = spawn_battle_arena(&mut commands, arena, centre);
+                (bounds, arena.obstacle_rects(centre).collect::<Vec<_>>())
+            });
+            active_arena.0 = arena.as_ref().map(|(bounds, _)| *bounds);
+            let allies: Vec<_> = ally_q.iter().map(|(e, t, k)| (e, *t, k.copied())).collect();
             start_battle(
                 &mut commands,
                 &mut battle_state,
@@ -240,9 +499,11 @@ pub fn battle_trigger_system(
                 player_entity,
                 player_tf.translation,
                 enemy_tf.translation,
-                ally_q.iter().map(|(e, t, k)| (e, *t, k.copied())).collect(),
+                allies,
                 player_kind,
                 boss_opt.is_some(),
+                &grid,
+                arena,
             );
             break;
         }
@@ -266,9 +527,35 @@ pub fn start_battle(
     allies_world: Vec<(Entity, Transform, Option<CharacterKind>)>,
     player_kind: Option<CharacterKind>,
     is_final_boss: bool,
+    grid: &GridConfig,
+    arena: Option<(Rect, Vec<Rect>)>,
 ) {
     battle_state.active = true;
     battle_state.enemy_id = Some(enemy_id);
+    crate::movement::cancel_player_path(commands, player_world_entity);
+
+    // Snap the foe onto its own grid tile, clear of the party, inside the
+    // arena and off its obstacles. Every way into a fight comes through here,
+    // so engaged, ambushing and hunted foes all land the same way.
+    let mut blocked: HashSet<IVec2> = std::iter::once(player_world_pos)
+        .chain(allies_world.iter().map(|(_, t, _)| t.translation))
+        .map(|p| grid.tile_of(p.truncate()))
+        .collect();
+    let (bounds, obstacles) = arena.unzip();
+    for rect in obstacles.into_iter().flatten() {
+        blocked.extend(grid.tiles_under(rect));
+    }
+    let enemy_world_pos = enemy_layout(
+        Formation::default(),
+        1,
+        enemy_world_pos.truncate(),
+        (enemy_world_pos - player_world_pos).truncate(),
+        bounds,
+        &blocked,
+        grid,
+    )
+    .first()
+    .map_or(enemy_world_pos, |p| p.extend(enemy_world_pos.z));
 
     let player = spawn_player_combat(commands, player_world_entity, player_world_pos, player_kind);
     let mut participants = vec![player];
@@ -311,6 +598,45 @@ pub fn start_battle(
     );
 }
 
+/// Lay out `arena`'s obstacles around `centre` and return the arena's world
+/// bounds for [`ActiveArena`]. The obstacles' colliders reach the `QuadTree`
+/// through `crate::world::update_cache` like any other collider.
+pub fn spawn_battle_arena(commands: &mut Commands, arena: &BattleArena, centre: Vec2) -> Rect {
+    for bounds in arena.obstacle_rects(centre) {
+        commands.spawn((
+            ArenaObstacle,
+            crate::quadtree::Collider { bounds },
+            crate::light_plugin::Occluder::new(bounds.size()),
+            crate::render3d::PlaceholderVisual::prop(
+                Color::srgb(0.42, 0.40, 0.36),
+                bounds.size(),
+                ARENA_OBSTACLE_HEIGHT,
+            ),
+            Transform::from_translation(bounds.center().extend(0.0)),
+            Name::new("Arena Obstacle"),
+        ));
+    }
+    Rect::from_center_half_size(centre, arena.half_extents)
+}
+
+/// Tears the arena down on [`BattleEndEvent`]: its obstacles are despawned
+/// (the removed colliders reopen the map on the next `QuadTree` rebuild) and
+/// movement is no longer bounded.
+pub fn clear_battle_arena_system(
+    mut commands: Commands,
+    mut battle_ends: MessageReader<BattleEndEvent>,
+    mut active_arena: ResMut<ActiveArena>,
+    obstacles_q: Query<Entity, With<ArenaObstacle>>,
+) {
+    if battle_ends.read().count() == 0 {
+        return;
+    }
+    for entity in &obstacles_q {
+        commands.entity(entity).despawn();
+    }
+    active_arena.0 = None;
+}
+
 fn spawn_player_combat(
     commands: &mut Commands,
     world_entity: Entity,
@@ -356,6 +682,7 @@ fn spawn_player_combat(
         lethality: <StatPool<i32>>::new(14),
         hit: <StatPool<i32>>::new(80),
         armor: <StatPool<i32>>::new(10),
+        resist: <StatPool<i32>>::new(6),
         speed: <StatPool<i32>>::new(10),
         evasion: <StatPool<i32>>::new(10),
         mind: <StatPool<i32>>::new(8),
@@ -410,16 +737,16 @@ fn spawn_enemy_combat(
     governor_city_id: Option<u16>,
     successor_target: Option<(u16, u32)>,
 ) -> Entity {
-    let (hp, lethality, hit, armor, agility) = match enemy_id {
+    let (hp, lethality, hit, armor, agility, level) = match enemy_id {
         // The Gashadokuro: a wall of bone meant to take the whole party several
         // rounds and punish a glass-cannon line-up.
-        FINAL_BOSS_ENCOUNTER_ID => (420, 22, 82, 16, 7),
+        FINAL_BOSS_ENCOUNTER_ID => (420, 22, 82, 16, 7, 20),
         // The Jorōgumo: a tough mid-game gatekeeper — tankier and faster than
         // rank-and-file yokai, but well short of the final boss.
-        MINIBOSS_ENCOUNTER_ID => (220, 16, 78, 10, 9),
-        1 => (80, 10, 70, 6, 8),
-        2 => (120, 14, 75, 10, 6),
-        _ => (60, 8, 65, 4, 7),
+        MINIBOSS_ENCOUNTER_ID => (220, 16, 78, 10, 9, 10),
+        1 => (80, 10, 70, 6, 8, 3),
+        2 => (120, 14, 75, 10, 6, 5),
+        _ => (60, 8, 65, 4, 7, 1),
     };
 
     // 五行 innate element (defence side of the wheel). Picked to give the
@@ -453,6 +780,7 @@ fn spawn_enemy_combat(
         lethality: <StatPool<i32>>::new(lethality),
         hit: <StatPool<i32>>::new(hit),
         armor: <StatPool<i32>>::new(armor),
+        resist: <StatPool<i32>>::new(armor / 2),
         speed: <StatPool<i32>>::new(agility),
         evasion: <StatPool<i32>>::new(agility),
         mind: <StatPool<i32>>::new(6),
@@ -499,8 +827,8 @@ fn spawn_enemy_combat(
     } else {
         e.insert(Abilities(vec![]));
     }
-    e.insert(Experience(0));
-    e.insert(Level(1));
+    e.insert(Experience(experience_at_level(level)));
+    e.insert(Level(level));
     e.insert(AccumulatedSpeed(0));
     e.insert(StatModifiers(Vec::new()));
     e.insert(CombatMovePoints::default());
@@ -570,10 +898,10 @@ pub fn spawn_yokai_combatant(
 
     // Stat block per species. Onibi is the fragile striker; Kappa is sturdy
     // melee; Kasha is squishy but high-mind.
-    let (hp, lethality, hit, armor, speed, mind, yokai_pool) = match kind {
-        YokaiKind::Onibi => (35, 14, 70, 4, 18, 12, 6.0_f32),
-        YokaiKind::Kappa => (90, 16, 65, 12, 9, 6, 3.0_f32),
-        YokaiKind::Kasha => (55, 8, 60, 6, 12, 18, 8.0_f32),
+    let (hp, lethality, hit, armor, speed, mind, yokai_pool, level) = match kind {
+        YokaiKind::Onibi => (35, 14, 70, 4, 18, 12, 6.0_f32, 2),
+        YokaiKind::Kappa => (90, 16, 65, 12, 9, 6, 3.0_f32, 4),
+        YokaiKind::Kasha => (55, 8, 60, 6, 12, 18, 8.0_f32, 3),
     };
 
     // 五行 innate element, by species nature: Onibi (鬼火) is a darting flame,
@@ -604,6 +932,7 @@ pub fn spawn_yokai_combatant(
         lethality: <StatPool<i32>>::new(lethality),
         hit: <StatPool<i32>>::new(hit),
         armor: <StatPool<i32>>::new(armor),
+        resist: <StatPool<i32>>::new(mind / 2),
         speed: <StatPool<i32>>::new(speed),
         evasion: <StatPool<i32>>::new(speed),
         mind: <StatPool<i32>>::new(mind),
@@ -616,8 +945,8 @@ pub fn spawn_yokai_combatant(
     });
     e.insert(GrowthAttributes::default());
     e.insert(Abilities(kind.abilities()));
-    e.insert(Experience(0));
-    e.insert(Level(1));
+    e.insert(Experience(experience_at_level(level)));
+    e.insert(Level(level));
     e.insert(AccumulatedSpeed(0));
     e.insert(StatModifiers(Vec::new()));
     e.insert(Reactions::default());
@@ -680,13 +1009,14 @@ fn spawn_ally_combat(
 /// Spawn a temporary summoned combatant (currently only the onmyōji's
 /// shikigami) as an autonomous ally at `world_pos`. It carries the "aggressive"
 /// BT profile so `crate::ai_decision::evaluate_behavior_tree_system` drives its
-/// turns against the enemy side, and a [`SummonLifetime`] so it leaves the
-/// field after a few turns.
+/// turns against the enemy side, a [`SummonLifetime`] so it leaves the field
+/// after a few turns, and a [`MinionOf`] tying it to `owner`.
 pub fn spawn_summoned_combatant(
     commands: &mut Commands,
     kind: SummonKind,
     world_pos: Vec3,
     lifetime_turns: u8,
+    owner: Entity,
 ) -> Entity {
     use crate::combat_plugin::Reactions;
 
@@ -721,6 +1051,7 @@ pub fn spawn_summoned_combatant(
         lethality: <StatPool<i32>>::new(lethality),
         hit: <StatPool<i32>>::new(hit),
         armor: <StatPool<i32>>::new(armor),
+        resist: <StatPool<i32>>::new(mind / 2),
         speed: <StatPool<i32>>::new(speed),
         evasion: <StatPool<i32>>::new(speed),
         mind: <StatPool<i32>>::new(mind),
@@ -743,6 +1074,7 @@ pub fn spawn_summoned_combatant(
     e.insert(SummonLifetime {
         remaining_turns: lifetime_turns.max(1),
     });
+    e.insert(MinionOf(owner));
     e.id()
 }
 
@@ -954,8 +1286,13 @@ pub fn resolve_summon_system(
         } else {
             // Offset slightly so the familiar doesn't spawn exactly on its caster.
             let pos = base + Vec3::new(1.0, 1.0, 0.0);
-            let summoned =
-                spawn_summoned_combatant(&mut commands, ev.kind, pos, ev.lifetime_turns);
+            let summoned = spawn_summoned_combatant(
+                &mut commands,
+                ev.kind,
+                pos,
+                ev.lifetime_turns,
+                ev.summoner,
+            );
             battle_state.participants.push(summoned);
             info!("Summoned {:?} (lifetime {} turns)", ev.kind, ev.lifetime_turns);
         }
@@ -1017,7 +1354,7 @@ pub fn obstacle_aura_tick_system(
                     damage_type,
                 } => {
                     damage_writer.write(DamageEvent {
-                        attacker: src,
+                        attacker: None,
                         target,
                         amount,
                         damage_type,
@@ -1039,6 +1376,110 @@ pub fn obstacle_aura_tick_system(
     }
 }
 
+/// Where a combatant actually stands: the player moves its linked world entity,
+/// everyone else moves their own.
+pub(crate) fn combat_position(
+    entity: Entity,
+    links: &Query<&BattleWorldLink>,
+    transforms: &Query<&Transform>,
+) -> Option<Vec2> {
+    let body = links.get(entity).map(|l| l.world_entity).unwrap_or(entity);
+    transforms.get(body).ok().map(|tf| tf.translation.truncate())
+}
+
+/// Consumes [`SpawnZoneEvent`]s, fixing each zone's tile coverage at the
+/// moment of casting (it doesn't follow the caster or target afterwards).
+pub fn spawn_zone_system(
+    mut commands: Commands,
+    mut events: MessageReader<SpawnZoneEvent>,
+    links: Query<&BattleWorldLink>,
+    transforms: Query<&Transform>,
+) {
+    for ev in events.read() {
+        let Some(target) = combat_position(ev.target, &links, &transforms) else {
+            continue;
+        };
+        let caster = combat_position(ev.caster, &links, &transforms).unwrap_or(target);
+        let tiles = zone_tiles(&ev.shape, caster, target);
+        info!("Zone {:?} laid over {} tiles for {} rounds", ev.effect, tiles.len(), ev.turns);
+        commands.spawn((
+            ZoneEntity {
+                tiles,
+                effect: ev.effect,
+                remaining_turns: ev.turns.max(1),
+                source: ev.caster,
+            },
+            Transform::from_translation(target.extend(0.0)),
+            Name::new("Zone"),
+        ));
+    }
+}
+
+/// Once per round (`RoundEndEvent`, like [`obstacle_aura_tick_system`]), every
+/// combatant standing on a zone's tiles gets its effect; the zone then loses a
+/// turn and is despawned when it runs out.
+pub fn zone_tick_system(
+    mut commands: Commands,
+    mut round_ends: MessageReader<RoundEndEvent>,
+    mut zones: Query<(Entity, &mut ZoneEntity)>,
+    combatants: Query<Entity, With<BattleParticipant>>,
+    links: Query<&BattleWorldLink>,
+    transforms: Query<&Transform>,
+    mut damage_writer: MessageWriter<DamageEvent>,
+    mut heal_writer: MessageWriter<HealEvent>,
+    mut status_writer: MessageWriter<ApplyStatusEvent>,
+) {
+    if round_ends.read().count() == 0 {
+        return;
+    }
+    for (zone, mut z) in zones.iter_mut() {
+        // Kill credit goes to whoever cast the zone, while they're still around.
+        let caster = combatants.contains(z.source).then_some(z.source);
+        for target in combatants.iter() {
+            let Some(pos) = combat_position(target, &links, &transforms) else {
+                continue;
+            };
+            if !z.tiles.contains(&zone_tile(pos)) {
+                continue;
+            }
+            match z.effect {
+                ZoneEffect::Damage { amount, damage_type } => {
+                    damage_writer.write(DamageEvent {
+                        attacker: caster,
+                        target,
+                        amount,
+                        damage_type,
+                        cause: ActionCause::World,
+                    });
+                }
+                ZoneEffect::Heal { amount } => {
+                    heal_writer.write(HealEvent {
+                        healer: z.source,
+                        target,
+                        amount,
+                        element: None,
+                        cause: ActionCause::World,
+                    });
+                }
+                ZoneEffect::Status { kind, tier } => {
+                    status_writer.write(ApplyStatusEvent {
+                        target,
+                        kind,
+                        tier,
+                        source: Some(z.source),
+                        expiry_override: None,
+                        resource_focus: None,
+                    });
+                }
+            }
+        }
+        z.remaining_turns = z.remaining_turns.saturating_sub(1);
+        if z.remaining_turns == 0 {
+            commands.entity(zone).despawn();
+        }
+    }
+}
+
 /// Bites whoever steps onto a passable `on_pass` obstacle. Occupancy is tracked
 /// per obstacle so the hit lands once on *entry* rather than every frame the
 /// mover stands on it. The player moves its world entity but takes damage on its
@@ -1049,7 +1490,7 @@ pub fn obstacle_on_pass_system(
     players: Query<(Entity, &BattleWorldLink), (With<BattleParticipant>, With<PlayerControlled>)>,
     world_tf: Query<&Transform, With<Player>>,
     ai: Query<(Entity, &Transform), (With<BattleParticipant>, Without<PlayerControlled>)>,
-    mut obstacles: Query<(Entity, &Transform, &ObstacleEffects, &mut ObstacleOccupants)>,
+    mut obstacles: Query<(&Transform, &ObstacleEffects, &mut ObstacleOccupants)>,
     mut damage_writer: MessageWriter<DamageEvent>,
     // Reused across frames so the per-frame mover list doesn't reallocate.
     mut movers: Local<Vec<(Entity, Vec2)>>,
@@ -1068,7 +1509,7 @@ pub fn obstacle_on_pass_system(
         movers.push((e, tf.translation.truncate()));
     }
 
-    for (obs, otf, effects, mut occ) in obstacles.iter_mut() {
+    for (otf, effects, mut occ) in obstacles.iter_mut() {
         let Some((amount, damage_type)) = effects.on_pass else {
             continue;
         };
@@ -1081,7 +1522,7 @@ pub fn obstacle_on_pass_system(
             if inside && !was_inside {
                 occ.0.insert(ent);
                 damage_writer.write(DamageEvent {
-                    attacker: obs,
+                    attacker: None,
                     target: ent,
                     amount,
                     damage_type,
@@ -1113,6 +1554,7 @@ pub fn ai_combat_movement_system(
     targets: Query<&Transform, Without<PendingAiMove>>,
     links: Query<&BattleWorldLink>,
     world_tf: Query<&Transform, With<Player>>,
+    arena: Res<ActiveArena>,
     mut attack_writer: MessageWriter<AttackIntentEvent>,
     mut wait_writer: MessageWriter<WaitIntentEvent>,
     mut turn_end_writer: MessageWriter<TurnEndEvent>,
@@ -1122,13 +1564,14 @@ pub fn ai_combat_movement_system(
         return;
     }
     let walkable = |p: Vec2| {
-        is_walkable_move(
-            Position {
-                x: p.x as i32,
-                y: p.y as i32,
-            },
-            &quad_tree,
-        )
+        arena.allows(p)
+            && is_walkable_move(
+                Position {
+                    x: p.x as i32,
+                    y: p.y as i32,
+                },
+                &quad_tree,
+            )
     };
 
     for (actor, mut transform, mut pending) in movers.iter_mut() {
@@ -1267,6 +1710,33 @@ pub fn tick_summon_lifetime_system(
     }
 }
 
+/// Despawn every [`MinionOf`] whose owner died this frame or is no longer a
+/// living battle participant (fled, dismissed, despawned), and scrub it from
+/// turn bookkeeping. `try_despawn` because the battle-end teardown in
+/// `end_battle_on_death` may already be despawning the same minion.
+pub fn dismiss_orphaned_minions_system(
+    mut commands: Commands,
+    mut deaths: MessageReader<DeathEvent>,
+    mut battle_state: ResMut<BattleState>,
+    mut tm: ResMut<TurnManager>,
+    mut turn_order: ResMut<TurnOrder>,
+    minions: Query<(Entity, &MinionOf)>,
+    owners: Query<&CombatStats, With<BattleParticipant>>,
+) {
+    let fallen: Vec<Entity> = deaths.read().map(|d| d.entity).collect();
+    for (minion, &MinionOf(owner)) in &minions {
+        let owner_standing = !fallen.contains(&owner)
+            && owners.get(owner).is_ok_and(|s| s.health.current > 0);
+        if owner_standing {
+            continue;
+        }
+        commands.entity(minion).try_despawn();
+        tm.participants.retain(|&e| e != minion);
+        turn_order.queue.retain(|&e| e != minion);
+        battle_state.participants.retain(|&e| e != minion);
+    }
+}
+
 /// Generic (unnamed) ally combat stat block.
 fn generic_ally_stats() -> CombatStats {
     CombatStats {
@@ -1281,6 +1751,7 @@ fn generic_ally_stats() -> CombatStats {
         lethality: <StatPool<i32>>::new(12),
         hit: <StatPool<i32>>::new(75),
         armor: <StatPool<i32>>::new(8),
+        resist: <StatPool<i32>>::new(6),
         speed: <StatPool<i32>>::new(9),
         evasion: <StatPool<i32>>::new(9),
         mind: <StatPool<i32>>::new(8),
@@ -1360,7 +1831,10 @@ pub fn setup_player_turns(
 pub fn sync_combat_move_points_from_world(
     game_state: Res<GameState>,
     pending: Res<PendingPlayerAction>,
-    mut combat_q: Query<(&BattleWorldLink, &mut CombatMovePoints), (With<BattleParticipant>, Without<Player>)>,
+    mut combat_q: Query<
+        (&BattleWorldLink, &mut CombatMovePoints),
+        (With<BattleParticipant>, Without<Player>),
+    >,
     world_q: Query<&CombatMovePoints, (With<Player>, Without<BattleParticipant>)>,
 ) {
     if game_state.0 != Game_State::Battle {
@@ -1386,6 +1860,7 @@ pub fn combat_movement_system(
     )>,
     game_state: Res<GameState>,
     quad_tree: Res<QuadTree>,
+    arena: Res<ActiveArena>,
     obstacles: Query<(&Transform, &ObstacleEffects), (Without<Player>, Without<MainCamera>)>,
     input: Res<ButtonInput<KeyCode>>,
     time: Res<Time>,
@@ -1445,7 +1920,7 @@ pub fn combat_movement_system(
                     y: new_y as i32,
                 };
 
-                if is_walkable_move(new_pos, &quad_tree) {
+                if arena.allows(Vec2::new(new_x, new_y)) && is_walkable_move(new_pos, &quad_tree) {
                     let mult = obstacle_slow_mult(transform.translation.truncate(), &obstacles);
                     let charge = diagonal_speed.min(mp.remaining);
                     let dist = charge * mult;
@@ -1483,7 +1958,7 @@ pub fn combat_movement_system(
                     y: new_y as i32,
                 };
 
-                if is_walkable_move(new_pos, &quad_tree) {
+                if arena.allows(Vec2::new(new_x, new_y)) && is_walkable_move(new_pos, &quad_tree) {
                     let mult = obstacle_slow_mult(transform.translation.truncate(), &obstacles);
                     let charge = movement_speed.min(mp.remaining);
                     let dist = charge * mult;
@@ -1773,7 +2248,8 @@ pub fn hunt_proximity_trigger(
     mut game_state: ResMut<GameState>,
     mut pending: ResMut<PendingHuntBattle>,
     hunts: Res<HuntRegistry>,
-    player_q: Query<&Transform, (With<Player>, Without<HuntTarget>)>,
+    conditions: ConditionContext,
+    player_q: Query<(Entity, &Transform), (With<Player>, Without<HuntTarget>)>,
     target_q: Query<(Entity, &Transform, &HuntTarget), Without<HuntCutscenePlayed>>,
 ) {
     if !matches!(game_state.0, Game_State::Exploring) {
@@ -1782,7 +2258,7 @@ pub fn hunt_proximity_trigger(
     if runtime.active || pending.hunt_target.is_some() {
         return;
     }
-    let Ok(player_tf) = player_q.single() else {
+    let Ok((player_entity, player_tf)) = player_q.single() else {
         return;
     };
     let player_pos = player_tf.translation.truncate();
@@ -1790,12 +2266,13 @@ pub fn hunt_proximity_trigger(
         if player_pos.distance(tf.translation.truncate()) > HUNT_PROXIMITY_RADIUS {
             continue;
         }
+        crate::movement::cancel_player_path(&mut commands, player_entity);
         commands.entity(entity).insert(HuntCutscenePlayed);
         pending.hunt_target = Some(entity);
         if let Some(hunt) = hunts.0.get(&target.hunt_id) {
             if let Some(scene) = hunt.pre_battle_scene.as_ref() {
                 if catalog.scenes.contains_key(scene)
-                    && runtime.start(scene.clone(), &catalog)
+                    && runtime.start(scene.clone(), &catalog, &conditions.view())
                 {
                     events_dialogue_box.write(DialogueBoxTriggerEvent);
                     game_state.0 = Game_State::Interacting;
@@ -1834,6 +2311,7 @@ pub fn start_pending_hunt_battle(
     mut tm: ResMut<TurnManager>,
     mut turn_order: ResMut<TurnOrder>,
     mut game_state: ResMut<GameState>,
+    grid: Res<GridConfig>,
     player_q: Query<(Entity, &Transform, Option<&CharacterKind>), With<Player>>,
     hunt_q: Query<
         (&Transform, &EnemyEncounter, Option<&WorldYokai>),
@@ -1872,6 +2350,8 @@ pub fn start_pending_hunt_battle(
         Vec::new(),
         player_kind,
         false,
+        &grid,
+        None,
     );
     pending.hunt_target = None;
 }
@@ -1897,15 +2377,16 @@ pub fn sync_player_combat_bound(
     }
 }
 
-/// When a player-controlled battle participant dies, retire it from the fight
-/// (the shipped `end_battle_on_death` only retires enemies) and re-emit
-/// `DeathEvent` on its *world* entity so the resurrection pipeline (which queries
-/// `Bound` / `ResurrectionStanding` on the world entity) marks it downed.
+/// When a player-controlled battle participant dies, lay it down (the shipped
+/// `end_battle_on_death` only retires enemies) and re-emit `DeathEvent` on its
+/// *world* entity so the resurrection pipeline (which queries `Bound` /
+/// `ResurrectionStanding` on the world entity) marks it downed.
 ///
 /// The run only ends once the *whole* party is down: a single fallen companion —
 /// or even the leader, as long as one ally still stands — leaves the battle
-/// running. The downed members keep their `Dead` flag on the world entity so
-/// they can be revived later at the shrine.
+/// running. The fallen stay on the field as `Dead` participants, out of the
+/// rotation, so a revive can raise them before the fight ends; otherwise they
+/// keep their `Dead` flag on the world entity until the shrine.
 pub fn bridge_player_death_to_world(
     // Reads `DeathEvent` and re-emits ones targeting world entities. Bevy 0.18
     // forbids `Res<Messages<T>>` + `ResMut<Messages<T>>` in one system, so reader
@@ -1913,7 +2394,7 @@ pub fn bridge_player_death_to_world(
     // then write them once the read borrow is released.
     mut deaths: ParamSet<(MessageReader<DeathEvent>, MessageWriter<DeathEvent>)>,
     participants_q: Query<
-        (&BattleSide, &BattleWorldLink),
+        (&BattleSide, &BattleWorldLink, Has<Dead>),
         (With<BattleParticipant>, With<PlayerControlled>),
     >,
     allies_q: Query<(Entity, &BattleSide, &CombatStats), With<BattleParticipant>>,
@@ -1922,16 +2403,18 @@ pub fn bridge_player_death_to_world(
     mut tm: ResMut<TurnManager>,
     mut turn_order: ResMut<TurnOrder>,
     mut game_state: ResMut<GameState>,
+    mut battle_end: MessageWriter<BattleEndEvent>,
 ) {
     // Collect this frame's ally casualties (battle participant + the world entity
     // to bridge the death onto).
     let mut fallen: Vec<Entity> = Vec::new();
     let mut bridged: Vec<DeathEvent> = Vec::new();
     for ev in deaths.p0().read() {
-        let Ok((side, link)) = participants_q.get(ev.entity) else {
+        let Ok((side, link, already_down)) = participants_q.get(ev.entity) else {
             continue;
         };
-        if !matches!(side, BattleSide::Ally) {
+        // A blow landing on someone already down doesn't kill them twice.
+        if !matches!(side, BattleSide::Ally) || already_down {
             continue;
         }
         fallen.push(ev.entity);
@@ -1947,13 +2430,13 @@ pub fn bridge_player_death_to_world(
         return;
     }
 
-    // Retire each fallen ally so it never takes another turn or lingers on the
-    // field (mirrors how `end_battle_on_death` retires enemies).
+    // Take each fallen ally out of the rotation but leave it in
+    // `battle_state.participants`, so the encounter teardown still despawns it
+    // and a revive has someone to raise until then.
     for entity in &fallen {
-        commands.entity(*entity).despawn();
+        commands.entity(*entity).insert(Dead);
         tm.participants.retain(|e| e != entity);
         turn_order.queue.retain(|e| e != entity);
-        battle_state.participants.retain(|e| e != entity);
     }
 
     // Is anyone on the party's side still standing?
@@ -1973,7 +2456,10 @@ pub fn bridge_player_death_to_world(
         tm.participants.clear();
         turn_order.queue.clear();
         battle_state.active = false;
-        battle_state.enemy_id = None;
+        battle_end.write(BattleEndEvent {
+            outcome: BattleOutcome::Defeat,
+            enemy_id: battle_state.enemy_id.take(),
+        });
         game_state.0 = Game_State::GameOver;
         info!("bridge_player_death_to_world: party wiped — run over");
     } else {
@@ -2000,6 +2486,7 @@ pub fn end_battle_on_death(
         With<BattleParticipant>,
     >,
     obstacles_q: Query<Entity, With<SummonedObstacle>>,
+    mut battle_end: MessageWriter<BattleEndEvent>,
 ) {
     if !battle_state.active || game_state.0 != Game_State::Battle {
         return;
@@ -2059,7 +2546,10 @@ pub fn end_battle_on_death(
     tm.participants.clear();
     turn_order.queue.clear();
     battle_state.active = false;
-    battle_state.enemy_id = None;
+    battle_end.write(BattleEndEvent {
+        outcome: BattleOutcome::Victory,
+        enemy_id: battle_state.enemy_id.take(),
+    });
     // Felling the final boss cleanses the land and wins the run; any other
     // victory just returns the party to the overworld.
     if boss_slain {
@@ -2071,9 +2561,575 @@ pub fn end_battle_on_death(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Battle results
+// ---------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BattleOutcome {
+    Victory,
+    Defeat,
+}
+
+/// Fired once when an encounter is torn down — by `end_battle_on_death` when
+/// the last enemy (or the boss) falls, or by `bridge_player_death_to_world`
+/// when the whole party is down.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct BattleEndEvent {
+    pub outcome: BattleOutcome,
+    pub enemy_id: Option<u32>,
+}
+
+/// One party member's line on the results screen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemberResult {
+    pub entity: Entity,
+    pub name: String,
+    pub xp_gained: u32,
+    pub levels_gained: u32,
+}
+
+/// Summary of the current (or most recent) battle for a results UI to render.
+/// Accumulated from the XP / level-up / death messages while the fight runs
+/// (drops are tallied by `equipment::enemy_loot_drop_system` as it rolls them)
+/// and sealed with an `outcome` on [`BattleEndEvent`]. It stays readable
+/// after the battle (the combatants are despawned by then, hence the names are
+/// captured up front) and is reset when the next battle begins.
+#[derive(Resource, Default, Clone, Debug)]
+pub struct BattleResults {
+    pub members: Vec<MemberResult>,
+    pub gold: u32,
+    pub loot: Vec<LootItem>,
+    /// Display names of the combatants (either side) that fell, in order.
+    pub fallen: Vec<String>,
+    /// `None` while the battle is still running.
+    pub outcome: Option<BattleOutcome>,
+}
+
+impl BattleResults {
+    pub fn total_xp(&self) -> u32 {
+        self.members.iter().map(|m| m.xp_gained).sum()
+    }
+
+    pub fn member(&self, entity: Entity) -> Option<&MemberResult> {
+        self.members.iter().find(|m| m.entity == entity)
+    }
+
+    fn member_mut(&mut self, entity: Entity, name: Option<&Name>) -> &mut MemberResult {
+        let idx = match self.members.iter().position(|m| m.entity == entity) {
+            Some(idx) => idx,
+            None => {
+                self.members.push(MemberResult {
+                    entity,
+                    name: display_name(entity, name),
+                    xp_gained: 0,
+                    levels_gained: 0,
+                });
+                self.members.len() - 1
+            }
+        };
+        &mut self.members[idx]
+    }
+
+    /// Fold a drop into the tally, merging quantities of the same item id.
+    pub fn add_loot(&mut self, coins: u32, items: &[LootItem]) {
+        self.gold = self.gold.saturating_add(coins);
+        for item in items {
+            match self.loot.iter_mut().find(|l| l.id == item.id) {
+                Some(existing) => {
+                    existing.quantity = existing.quantity.saturating_add(item.quantity)
+                },
+                None => self.loot.push(item.clone()),
+            }
+        }
+    }
+}
+
+fn display_name(entity: Entity, name: Option<&Name>) -> String {
+    name.map(|n| n.as_str().to_string())
+        .unwrap_or_else(|| format!("{entity:?}"))
+}
+
+/// Builds [`BattleResults`] from the battle's messages. A fresh battle (the
+/// state is active again while the previous results are sealed) starts a new
+/// summary; [`BattleEndEvent`] seals it.
+pub fn record_battle_results_system(
+    battle_state: Res<BattleState>,
+    mut results: ResMut<BattleResults>,
+    mut xp_events: MessageReader<AwardXpEvent>,
+    mut level_events: MessageReader<LevelUpEvent>,
+    mut deaths: MessageReader<DeathEvent>,
+    mut battle_ends: MessageReader<BattleEndEvent>,
+    names: Query<&Name>,
+    world_bodies: Query<(), Or<(With<Player>, With<WorldAlly>)>>,
+) {
+    if battle_state.active && results.outcome.is_some() {
+        *results = BattleResults::default();
+    }
+    for ev in xp_events.read() {
+        let member = results.member_mut(ev.recipient, names.get(ev.recipient).ok());
+        member.xp_gained = member.xp_gained.saturating_add(ev.amount);
+    }
+    for ev in level_events.read() {
+        let gained = ev.new_level.saturating_sub(ev.old_level) as u32;
+        if gained == 0 {
+            continue;
+        }
+        let member = results.member_mut(ev.who, names.get(ev.who).ok());
+        member.levels_gained += gained;
+    }
+    for ev in deaths.read() {
+        // Ally deaths are re-emitted on the overworld entity by
+        // `bridge_player_death_to_world`; skip the echo so nobody dies twice.
+        if world_bodies.contains(ev.entity) {
+            continue;
+        }
+        let name = display_name(ev.entity, names.get(ev.entity).ok());
+        results.fallen.push(name);
+    }
+    for ev in battle_ends.read() {
+        results.outcome = Some(ev.outcome);
+        info!(
+            "Battle results: {:?}, {} XP, {} gold, {} item stacks, {} fallen",
+            ev.outcome,
+            results.total_xp(),
+            results.gold,
+            results.loot.len(),
+            results.fallen.len()
+        );
+    }
+}
+
+/// Strips what a fight leaves on its survivors once a [`BattleEndEvent`]
+/// fires: every [`StatModifiers`] entry, every [`Buff`] entity, the
+/// combat-scoped statuses (see [`StatusEffects::clear_combat_scoped`]), the
+/// turn-counted riders (regen, guard, attunement, polarity flip), and any
+/// [`ZoneEntity`] still on the field.
+///
+/// Recovery policy: action points and movement are refilled to base, since
+/// they are per-turn budgets with no meaning outside a fight. Health, morale
+/// and magic carry over as they stand and come back with rest.
+pub fn cleanup_combat_effects_system(
+    mut commands: Commands,
+    mut battle_ends: MessageReader<BattleEndEvent>,
+    mut modifiers_q: Query<&mut StatModifiers>,
+    mut statuses_q: Query<&mut StatusEffects>,
+    mut stats_q: Query<&mut CombatStats>,
+    effect_entities_q: Query<Entity, Or<(With<Buff>, With<ZoneEntity>)>>,
+    riders_q: Query<
+        Entity,
+        Or<(With<RegenBuff>, With<Guard>, With<Attunement>, With<PolarityFlip>)>,
+    >,
+) {
+    if battle_ends.read().count() == 0 {
+        return;
+    }
+    // Cleared in place: the participants are being despawned this same frame,
+    // and a deferred insert on a despawned entity panics.
+    for mut mods in &mut modifiers_q {
+        mods.0.clear();
+    }
+    for mut statuses in &mut statuses_q {
+        statuses.clear_combat_scoped();
+    }
+    for mut stats in &mut stats_q {
+        stats.action_points.current = stats.action_points.base;
+        stats.movement.current = stats.movement.base;
+    }
+    for entity in &effect_entities_q {
+        commands.entity(entity).try_despawn();
+    }
+    for entity in &riders_q {
+        commands
+            .entity(entity)
+            .try_remove::<(RegenBuff, Guard, Attunement, PolarityFlip)>();
+    }
+}
+
 pub fn end_battle(
     mut game_state: ResMut<GameState>,
     _turn_manager: Res<TurnManager>,
 ) {
     game_state.0 = Game_State::Exploring;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results_app() -> App {
+        let mut app = App::new();
+        app.add_message::<AwardXpEvent>()
+            .add_message::<LevelUpEvent>()
+            .add_message::<DeathEvent>()
+            .add_message::<BattleEndEvent>()
+            .insert_resource(BattleState {
+                active: true,
+                ..default()
+            })
+            .init_resource::<BattleResults>()
+            .add_systems(
+                Update,
+                (
+                    crate::combat_plugin::award_kill_xp_system,
+                    crate::combat_plugin::award_xp_system,
+                    crate::equipment::enemy_loot_drop_system,
+                    record_battle_results_system,
+                )
+                    .chain(),
+            );
+        app
+    }
+
+    /// Kills go through the same systems a real battle runs: the killer's XP
+    /// and level-up, and the corpse's rolled purse and items, all land in the
+    /// summary exactly once.
+    #[test]
+    fn kills_roll_xp_and_loot_up_into_battle_results() {
+        let mut app = results_app();
+        let world = app.world_mut();
+        let hero_xp = experience_at_level(2) - 50;
+        let hero = world
+            .spawn((
+                Name::new("Hero"),
+                BattleParticipant,
+                BattleSide::Ally,
+                Experience(hero_xp),
+                Level(1),
+            ))
+            .id();
+        let foe = |world: &mut World, name: &str| {
+            world
+                .spawn((
+                    Name::new(name.to_string()),
+                    BattleParticipant,
+                    BattleSide::Enemy,
+                    EnemyEncounter { id: MINIBOSS_ENCOUNTER_ID },
+                    Experience(experience_at_level(1)),
+                    Transform::default(),
+                ))
+                .id()
+        };
+        let oni = foe(world, "Oni");
+        let kappa = foe(world, "Kappa");
+
+        world.write_message(DeathEvent { entity: oni, killer: Some(hero) });
+        app.update();
+        app.world_mut().write_message(DeathEvent { entity: kappa, killer: None });
+        app.update();
+
+        app.world_mut().resource_mut::<BattleState>().active = false;
+        app.world_mut().write_message(BattleEndEvent {
+            outcome: BattleOutcome::Victory,
+            enemy_id: Some(1),
+        });
+        app.update();
+
+        let results = app.world().resource::<BattleResults>();
+        assert_eq!(results.outcome, Some(BattleOutcome::Victory));
+        let expected_xp =
+            crate::combat_plugin::calculate_xp_award(hero_xp, experience_at_level(1));
+        assert_eq!(results.total_xp(), expected_xp);
+        let hero_line = results.member(hero).expect("hero has a results line");
+        assert_eq!(hero_line.name, "Hero");
+        assert_eq!((hero_line.xp_gained, hero_line.levels_gained), (expected_xp, 1));
+        // Both bosses drop a purse, field medicine and sacred sake.
+        assert!(results.gold >= 2 * 800, "gold {}", results.gold);
+        let quantity = |id| results.loot.iter().find(|l| l.id == id).map(|l| l.quantity);
+        assert_eq!(quantity(1001), Some(2));
+        assert_eq!(quantity(1003), Some(2));
+        assert_eq!(results.fallen, vec!["Oni".to_string(), "Kappa".to_string()]);
+    }
+
+    #[test]
+    fn killing_the_owner_dismisses_its_minion() {
+        let mut app = App::new();
+        app.add_message::<DeathEvent>()
+            .init_resource::<BattleState>()
+            .init_resource::<TurnManager>()
+            .init_resource::<TurnOrder>()
+            .add_systems(Update, dismiss_orphaned_minions_system);
+        let alive = || CombatStats { health: <StatPool<i32>>::new(20), ..default() };
+        let world = app.world_mut();
+        let owner = world.spawn((BattleParticipant, alive())).id();
+        let other_owner = world.spawn((BattleParticipant, alive())).id();
+        let minion = world.spawn((BattleParticipant, alive(), MinionOf(owner))).id();
+        let bystander = world.spawn((BattleParticipant, alive(), MinionOf(other_owner))).id();
+        world.resource_mut::<BattleState>().participants =
+            vec![owner, other_owner, minion, bystander];
+        world.resource_mut::<TurnOrder>().queue = [minion, bystander].into();
+        app.update();
+        assert!(app.world().get_entity(minion).is_ok(), "owner alive: minion stays");
+
+        app.world_mut().get_mut::<CombatStats>(owner).unwrap().health.current = 0;
+        app.world_mut().write_message(DeathEvent { entity: owner, killer: None });
+        app.update();
+
+        assert!(app.world().get_entity(minion).is_err());
+        assert!(app.world().get_entity(bystander).is_ok());
+        let state = app.world().resource::<BattleState>();
+        assert!(!state.participants.contains(&minion));
+        assert_eq!(app.world().resource::<TurnOrder>().queue, [bystander]);
+    }
+
+    #[test]
+    fn battle_end_strips_buffs_and_poison_from_survivors() {
+        use crate::combat_plugin::{Stat, StatModifier};
+        use crate::constants::TIMESTAMP_TICKS_PER_HOUR;
+        use crate::status_effects::ContractDebuffKind::MirrorWound;
+        use crate::status_effects::{BuffKind, DebuffKind, Expiry, StatusInstance};
+
+        let mut app = App::new();
+        app.add_message::<BattleEndEvent>()
+            .add_systems(Update, cleanup_combat_effects_system);
+        let status = |kind, expiry| StatusInstance {
+            kind,
+            tier: 1,
+            expiry,
+            source: None,
+            dot_counter: 0,
+            resource_focus: None,
+        };
+        let in_hours = |n: u32| Expiry::AtTimestamp(n * TIMESTAMP_TICKS_PER_HOUR);
+        let mut stats = CombatStats {
+            action_points: <StatPool<i32>>::new(4),
+            ..default()
+        };
+        stats.action_points.current = 1;
+        let world = app.world_mut();
+        let survivor = world
+            .spawn((
+                stats,
+                StatModifiers(vec![StatModifier {
+                    stat: Stat::Lethality,
+                    multiplier: 1.5,
+                    expires_at_timestamp: Some(99),
+                    source: None,
+                    tag: None,
+                }]),
+                StatusEffects(vec![
+                    status(
+                        StatusKind::BadCondition(BadConditionKind::Bleeding),
+                        Expiry::AtTimestamp(9),
+                    ),
+                    status(StatusKind::Contract(MirrorWound), Expiry::UntilAtonement),
+                    // Fire-In's elemental proc, scoped to the fight.
+                    status(StatusKind::Debuff(DebuffKind::SlowRegeneration), Expiry::EndOfCombat),
+                    status(StatusKind::Debuff(DebuffKind::Sluggish), Expiry::AtTimestamp(7)),
+                    status(StatusKind::Debuff(DebuffKind::Fragile), Expiry::UntilCleansed),
+                    status(StatusKind::Buff(BuffKind::Swift), Expiry::AtTimestamp(5)),
+                    status(StatusKind::Buff(BuffKind::BolsteredMorale), in_hours(6)),
+                ]),
+                RegenBuff {
+                    amount_per_turn: 3,
+                    remaining_turns: 2,
+                    resource: crate::status_effects::ResourceKind::Health,
+                },
+            ))
+            .id();
+        let buff = world
+            .spawn(Buff {
+                stat: Stat::Hit,
+                multiplier: 1.2,
+                ends_at_timestamp: 99,
+                source: Some(survivor),
+            })
+            .id();
+        app.update();
+        assert_eq!(app.world().get::<StatModifiers>(survivor).unwrap().0.len(), 1);
+
+        app.world_mut().write_message(BattleEndEvent {
+            outcome: BattleOutcome::Victory,
+            enemy_id: None,
+        });
+        app.update();
+
+        let world = app.world();
+        assert!(world.get::<StatModifiers>(survivor).unwrap().0.is_empty());
+        assert!(world.get_entity(buff).is_err());
+        assert!(world.get::<RegenBuff>(survivor).is_none());
+        let statuses = world.get::<StatusEffects>(survivor).unwrap();
+        assert!(!statuses.has(StatusKind::BadCondition(BadConditionKind::Bleeding)));
+        assert!(statuses.has(StatusKind::Contract(MirrorWound)));
+        assert!(!statuses.has(StatusKind::Debuff(DebuffKind::SlowRegeneration)));
+        assert!(!statuses.has(StatusKind::Debuff(DebuffKind::Sluggish)));
+        assert!(statuses.has(StatusKind::Debuff(DebuffKind::Fragile)));
+        assert!(!statuses.has(StatusKind::Buff(BuffKind::Swift)));
+        let blessed = statuses.has(StatusKind::Buff(BuffKind::BolsteredMorale));
+        assert!(blessed, "the hour-long blessing outlives the battle");
+        assert_eq!(world.get::<CombatStats>(survivor).unwrap().action_points.current, 4);
+    }
+
+    #[test]
+    fn arena_obstacle_blocks_the_middle_until_battle_end() {
+        use crate::pathfinding::line_of_sight;
+
+        let mut app = App::new();
+        app.add_message::<BattleEndEvent>()
+            .init_resource::<QuadTree>()
+            .init_resource::<crate::dialogue::CachedInteractables>()
+            .init_resource::<crate::dialogue::InteractableIndex>()
+            .init_resource::<ActiveArena>()
+            .add_systems(Update, (clear_battle_arena_system, crate::world::update_cache).chain());
+        let arena = BattleArena {
+            half_extents: Vec2::new(320.0, 96.0),
+            obstacles: vec![Rect::from_center_size(Vec2::ZERO, Vec2::splat(64.0))],
+        };
+        let centre = Vec2::new(400.0, 300.0);
+        let bounds = spawn_battle_arena(&mut app.world_mut().commands(), &arena, centre);
+        app.world_mut().flush();
+        app.world_mut().resource_mut::<ActiveArena>().0 = Some(bounds);
+        app.update();
+
+        let at = |p: Vec2| Position { x: p.x as i32, y: p.y as i32 };
+        let (west, east) = (centre - Vec2::X * 160.0, centre + Vec2::X * 160.0);
+        let quad_tree = app.world().resource::<QuadTree>();
+        assert!(!is_walkable_move(at(centre), quad_tree));
+        assert!(is_walkable_move(at(west), quad_tree));
+        assert!(!line_of_sight(quad_tree, west, east));
+        let active = app.world().resource::<ActiveArena>();
+        assert!(active.allows(west));
+        assert!(!active.allows(centre + Vec2::Y * 200.0));
+
+        app.world_mut().write_message(BattleEndEvent {
+            outcome: BattleOutcome::Victory,
+            enemy_id: None,
+        });
+        app.update();
+        app.update();
+
+        let world = app.world_mut();
+        let mut obstacles = world.query_filtered::<(), With<ArenaObstacle>>();
+        assert_eq!(obstacles.iter(world).count(), 0);
+        let quad_tree = world.resource::<QuadTree>();
+        assert!(is_walkable_move(at(centre), quad_tree));
+        assert!(line_of_sight(quad_tree, west, east));
+        assert!(world.resource::<ActiveArena>().0.is_none());
+    }
+
+    #[test]
+    fn damage_zone_burns_its_occupant_each_round_until_it_expires() {
+        let mut app = App::new();
+        app.add_message::<SpawnZoneEvent>()
+            .add_message::<RoundEndEvent>()
+            .add_message::<DamageEvent>()
+            .add_message::<HealEvent>()
+            .add_message::<ApplyStatusEvent>()
+            .add_systems(Update, (spawn_zone_system, zone_tick_system).chain());
+        let world = app.world_mut();
+        let caster = world.spawn((BattleParticipant, Transform::from_xyz(0.0, 0.0, 0.0))).id();
+        let victim = world.spawn((BattleParticipant, Transform::from_xyz(200.0, 16.0, 0.0))).id();
+        let bystander =
+            world.spawn((BattleParticipant, Transform::from_xyz(400.0, 16.0, 0.0))).id();
+        world.write_message(SpawnZoneEvent {
+            caster,
+            target: victim,
+            shape: AbilityShape::Radius(40.0),
+            effect: ZoneEffect::Damage { amount: 6, damage_type: DamageType::Fire },
+            turns: 2,
+        });
+        app.update();
+
+        let mut burns = Vec::new();
+        for round in 0..3 {
+            // The caster leaves after the first round; the zone keeps burning.
+            if round == 1 {
+                app.world_mut().despawn(caster);
+            }
+            app.world_mut().write_message(RoundEndEvent);
+            app.update();
+            let hits: Vec<_> = app
+                .world()
+                .resource::<Messages<DamageEvent>>()
+                .iter_current_update_messages()
+                .map(|ev| (ev.attacker, ev.target, ev.amount))
+                .collect();
+            burns.push(hits);
+        }
+
+        assert_eq!(
+            burns,
+            vec![vec![(Some(caster), victim, 6)], vec![(None, victim, 6)], vec![]]
+        );
+        assert!(!burns.concat().iter().any(|(_, e, _)| *e == bystander || *e == caster));
+        let world = app.world_mut();
+        assert_eq!(world.query::<&ZoneEntity>().iter(world).count(), 0);
+    }
+
+    #[test]
+    fn three_enemies_take_distinct_tiles_inside_the_arena() {
+        let grid = GridConfig::default();
+        let arena = Rect::from_center_half_size(Vec2::new(500.0, 500.0), Vec2::new(160.0, 96.0));
+        let party: HashSet<IVec2> = [grid.tile_of(Vec2::new(440.0, 500.0))].into();
+        for formation in [Formation::Line, Formation::Cluster, Formation::Flanking] {
+            let spots = enemy_layout(
+                formation,
+                3,
+                Vec2::new(520.0, 500.0),
+                Vec2::X,
+                Some(arena),
+                &party,
+                &grid,
+            );
+            assert_eq!(spots.len(), 3, "{formation:?}");
+            let tiles: HashSet<IVec2> = spots.iter().map(|&p| grid.tile_of(p)).collect();
+            assert_eq!(tiles.len(), 3, "{formation:?} stacked two enemies: {spots:?}");
+            assert!(tiles.is_disjoint(&party), "{formation:?}");
+            assert!(spots.iter().all(|&p| arena.contains(p)), "{formation:?}: {spots:?}");
+            let anchor = Vec2::new(520.0, 500.0);
+            let again = enemy_layout(formation, 3, anchor, Vec2::X, Some(arena), &party, &grid);
+            assert_eq!(spots, again);
+        }
+    }
+
+    #[test]
+    fn start_battle_places_the_foe_clear_of_arena_obstacles() {
+        let grid = GridConfig::default();
+        let mut world = World::new();
+        let player = world.spawn_empty().id();
+        let foe = world.spawn_empty().id();
+        let (player_pos, foe_pos) = (Vec3::new(400.0, 300.0, 0.0), Vec3::new(432.0, 300.0, 0.0));
+        let centre = player_pos.truncate().lerp(foe_pos.truncate(), 0.5);
+        let rock = Rect::from_center_size(foe_pos.truncate(), Vec2::splat(grid.tile_size));
+        let arena = (Rect::from_center_half_size(centre, Vec2::splat(160.0)), vec![rock]);
+        let mut battle_state = BattleState::default();
+        start_battle(
+            &mut world.commands(),
+            &mut battle_state,
+            &mut TurnManager::default(),
+            &mut TurnOrder::default(),
+            0,
+            None,
+            None,
+            None,
+            foe,
+            player,
+            player_pos,
+            foe_pos,
+            Vec::new(),
+            None,
+            false,
+            &grid,
+            Some(arena.clone()),
+        );
+        world.flush();
+
+        let enemy = *battle_state.participants.last().unwrap();
+        let spot = world.get::<Transform>(enemy).unwrap().translation.truncate();
+        let tile = grid.tile_of(spot);
+        assert!(grid.tiles_under(rock).all(|t| t != tile), "{spot} is under the rock");
+        assert_ne!(tile, grid.tile_of(player_pos.truncate()));
+        assert!(arena.0.contains(spot));
+    }
+
+    #[test]
+    fn next_battle_starts_a_fresh_summary() {
+        let mut app = results_app();
+        app.world_mut().resource_mut::<BattleResults>().outcome = Some(BattleOutcome::Defeat);
+        app.world_mut().resource_mut::<BattleResults>().gold = 40;
+        app.update();
+        let results = app.world().resource::<BattleResults>();
+        assert_eq!(results.outcome, None);
+        assert_eq!(results.gold, 0);
+    }
+}
@@ -9,11 +9,15 @@
 //! overworld where no combatants exist. Consumables are listed (they're used
 //! from the in-battle item menu); skill points are still spent on `K`.
 
+use std::collections::HashMap;
+
 use bevy::prelude::*;
 
 use crate::characters::{CharacterKind, SelectedParty};
 use crate::combat_ability::Ability_Tree;
-use crate::combat_plugin::{EquipmentSlotType, InventoryItemCatalog, InventoryItemKind};
+use crate::combat_plugin::{
+    AttributePointsChangedEvent, EquipmentSlotType, InventoryItemCatalog, InventoryItemKind,
+};
 use crate::core::{GameState, Game_State};
 use crate::economy::{ItemCatalog, PlayerInventory, PlayerWallet};
 use crate::equipment::{can_equip, equip_item, member_accepts, unequip_item, PartyEquipment};
@@ -29,7 +33,13 @@ impl Plugin for CharacterSheetPlugin {
         app.init_resource::<SheetState>()
             .add_systems(Update, toggle_character_sheet)
             .add_systems(Update, handle_sheet_actions)
-            .add_systems(Update, sync_character_sheet.after(handle_sheet_actions));
+            .add_systems(Update, track_attribute_points)
+            .add_systems(
+                Update,
+                sync_character_sheet
+                    .after(handle_sheet_actions)
+                    .after(track_attribute_points),
+            );
     }
 }
 
@@ -39,6 +49,8 @@ impl Plugin for CharacterSheetPlugin {
 struct SheetState {
     selected: usize,
     dirty: bool,
+    /// Last reported `(available, spent)` attribute points per member.
+    attribute_points: HashMap<CharacterKind, (u32, u32)>,
 }
 
 #[derive(Component, Clone)]
@@ -102,6 +114,21 @@ fn handle_sheet_actions(
     }
 }
 
+/// Remember each member's attribute pool as [`AttributePointsChangedEvent`]s
+/// arrive and repaint the sheet.
+fn track_attribute_points(
+    mut events: MessageReader<AttributePointsChangedEvent>,
+    kinds: Query<&CharacterKind>,
+    mut sheet: ResMut<SheetState>,
+) {
+    for ev in events.read() {
+        if let Ok(kind) = kinds.get(ev.who) {
+            sheet.attribute_points.insert(*kind, (ev.available, ev.spent));
+            sheet.dirty = true;
+        }
+    }
+}
+
 /// Sum of the stat bonuses from everything `kind` currently has equipped.
 #[derive(Default)]
 struct EquipBonus {
@@ -176,6 +203,7 @@ fn sync_character_sheet(
     let selected = sheet.selected.min(party.0.len() - 1);
     let kind = party.0[selected];
     let leader = party.0.first().copied();
+    let attribute_points = sheet.attribute_points.get(&kind).copied();
 
     commands
         .spawn((overlay_root(), CharacterSheetRoot))
@@ -261,11 +289,12 @@ fn sync_character_sheet(
                 let b = equipped_bonus(&party_equipment, &item_catalog, kind);
                 col.spawn((
                     Text::new(format!(
-                        "HP {}   Lethality {}{}   Hit {}{}   Armor {}{}   Agility {}{}   Mind {}{}   Morale {}{}",
+                        "HP {}   Lethality {}{}   Hit {}{}   Armor {}{}   Resist {}   Agility {}{}   Mind {}{}   Morale {}{}",
                         stats.health.base,
                         stats.lethality.base, fmt_bonus(b.lethality),
                         stats.hit.base, fmt_bonus(b.hit),
                         stats.armor.base, fmt_bonus(b.armor),
+                        stats.resist.base,
                         stats.evasion.base, fmt_bonus(b.agility),
                         stats.mind.base, fmt_bonus(b.mind),
                         stats.morale.base, fmt_bonus(b.morale),
@@ -291,11 +320,16 @@ fn sync_character_sheet(
                     .collect();
                 let learned = progression.0.get(&kind).map(|p| p.learned.len()).unwrap_or(0);
                 let sp = progression.0.get(&kind).map(|p| p.available).unwrap_or(0);
+                let attributes = attribute_points
+                    .map(|(available, spent)| {
+                        format!("   ·   Attribute points: {available} ({spent} spent)")
+                    })
+                    .unwrap_or_default();
                 col.spawn((
                     Text::new(format!(
-                        "Abilities: {}\nSkills learned: {}   ·   Skill points: {} (spend on K)",
+                        "Abilities: {}\nSkills learned: {}   ·   Skill points: {} (spend on K){}",
                         if ability_names.is_empty() { "—".into() } else { ability_names.join(", ") },
-                        learned, sp,
+                        learned, sp, attributes,
                     )),
                     TextFont {
                         font_size: font_size::SMALL,
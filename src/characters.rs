@@ -587,6 +587,7 @@ impl CharacterKind {
                 lethality: s(25),
                 hit: s(32),
                 armor: s(7),
+                resist: s(4),
                 speed: s(37),
                 evasion: s(37),
                 mind: s(3),
@@ -609,6 +610,7 @@ impl CharacterKind {
                 lethality: s(12),
                 hit: s(20),
                 armor: s(10),
+                resist: s(14),
                 speed: s(18),
                 evasion: s(18),
                 mind: s(22),
@@ -631,6 +633,7 @@ impl CharacterKind {
                 lethality: s(34),
                 hit: s(28),
                 armor: s(18),
+                resist: s(8),
                 speed: s(22),
                 evasion: s(22),
                 mind: s(8),
@@ -653,6 +656,7 @@ impl CharacterKind {
                 lethality: s(16),
                 hit: s(18),
                 armor: s(6),
+                resist: s(15),
                 speed: s(20),
                 evasion: s(20),
                 mind: s(20),
@@ -678,6 +682,7 @@ impl CharacterKind {
                 lethality: s(28),
                 hit: s(26),
                 armor: s(12),
+                resist: s(6),
                 speed: s(30),
                 evasion: s(26),
                 mind: s(6),
@@ -703,6 +708,7 @@ impl CharacterKind {
                 lethality: s(9),
                 hit: s(24),
                 armor: s(7),
+                resist: s(16),
                 speed: s(18),
                 evasion: s(18),
                 mind: s(24),
@@ -728,6 +734,7 @@ impl CharacterKind {
                 lethality: s(8),
                 hit: s(30),
                 armor: s(5),
+                resist: s(15),
                 speed: s(17),
                 evasion: s(16),
                 mind: s(24),
@@ -752,6 +759,7 @@ impl CharacterKind {
                 lethality: s(20),
                 hit: s(24),
                 armor: s(28),
+                resist: s(6),
                 speed: s(8),
                 evasion: s(6),
                 mind: s(6),
@@ -776,6 +784,7 @@ impl CharacterKind {
                 lethality: s(10),
                 hit: s(22),
                 armor: s(9),
+                resist: s(14),
                 speed: s(16),
                 evasion: s(16),
                 mind: s(22),
@@ -800,6 +809,7 @@ impl CharacterKind {
                 lethality: s(10),
                 hit: s(24),
                 armor: s(7),
+                resist: s(16),
                 speed: s(17),
                 evasion: s(16),
                 mind: s(24),
@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use std::fmt;
 use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use bevy::prelude::*;
@@ -6,11 +7,17 @@ use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 use crate::combat_plugin::{
-    ActionCause, ApplyAttunementEvent, ApplyBuffEvent, ApplyPolarityFlipEvent, AttackIntentEvent,
-    DamageQueue, DamageTag, DamageType, DrainMoraleEvent, HealEvent, QueuedDamage, Stat, SummonEvent,
+    get_stat_value, Abilities, ActionCause, ApplyAttunementEvent, ApplyBuffEvent, ApplyGuardEvent,
+    ApplyPolarityFlipEvent, AttackIntentEvent, CombatRng, CombatStats, DamageType,
+    DrainMoraleEvent, DrainResourceEvent, HealEvent, PlayerActionWriters, ReviveEvent,
+    ModifierTag, SpawnZoneEvent, Stat, StatModifier, SummonEvent,
 };
+use crate::core::{GameState, Game_State, Player};
 use crate::gogyo::{Element, Phase};
-use crate::status_effects::{ApplyStatusEvent, RemoveStatusEvent, ResourceKind, StatusKind};
+use crate::story_flags::{FlagChangedEvent, StoryFlags};
+use crate::status_effects::{
+    ApplyRegenBuffEvent, ApplyStatusEvent, RegenBuff, RemoveStatusEvent, ResourceKind, StatusKind,
+};
 
 /// Which kind of temporary combatant a [`AbilityEffect::Summon`] brings onto
 /// the field. The concrete stat block / side / AI profile for each is built in
@@ -84,6 +91,10 @@ pub enum AbilityEffect {
         multiplier: f32,
         effects: Option<Vec<u16>>,
         scaled_with: Stat,
+        /// Lands on the caster, once per cast, whoever the cast targets — a
+        /// stance or war cry. Otherwise each target gets it.
+        #[serde(default)]
+        on_caster: bool,
     },
     /// Apply a Bad Condition / Debuff / Buff / Contract Debuff to each
     /// target. Default GDD duration is used (the apply system reads it from
@@ -110,6 +121,59 @@ pub enum AbilityEffect {
     /// 五行 lever — temporarily flip each target's In/Yō polarity for `duration`
     /// turns (the Reversal Seal etc.; §3a of the design doc).
     FlipPolarity { duration: u8 },
+    /// Grant each target a temporary [`RegenBuff`](crate::status_effects::RegenBuff)
+    /// that restores `amount_per_turn` of `resource` at the start of each of
+    /// their next `turns` turns — a cleric's fast healing, on top of their
+    /// permanent rest regen.
+    Regen { amount_per_turn: u32, turns: u8, resource: ResourceKind },
+    /// Burn a rolled `floor..ceiling` of each target's `resource` — magic
+    /// (from the pool of the casting ability's school) or morale, never
+    /// health, which only falls through the damage pipeline. With `transfer`
+    /// the caster gains whatever was actually taken. Applied by
+    /// `crate::combat_plugin::apply_resource_drain_system`.
+    ResourceDrain {
+        resource: ResourceKind,
+        floor: u32,
+        ceiling: u32,
+        #[serde(default)]
+        transfer: bool,
+    },
+    /// The caster stands guard over each target for `turns` of their own
+    /// turns, intercepting attacks aimed at them (see
+    /// [`Guard`](crate::combat_plugin::Guard)). A caster guards one ally at a
+    /// time, so on a multi-target cast the last target wins.
+    Guard { turns: u8 },
+    /// Leave the cast's shape on the ground as a persistent zone (a wall of
+    /// fire, a healing circle) for `turns` battle rounds. Each round, everyone
+    /// standing on a covered tile gets `effect`. A radius is centred on the
+    /// first target; a line or cone runs from the caster towards it. Resolved
+    /// once per cast via [`SpawnZoneEvent`](crate::combat_plugin::SpawnZoneEvent);
+    /// see [`crate::battle::ZoneEntity`].
+    Zone { effect: ZoneEffect, turns: u8 },
+    /// Bring a fallen (`Dead`, but not `PermanentlyDead`) ally back on their
+    /// feet with `hp_fraction` of their max health, and back into the turn
+    /// rotation. Resolved by `apply_revive_system`, which turns away targets
+    /// that are still standing or beyond saving.
+    Revive { hp_fraction: f32 },
+    /// Out of combat only: roll `stat` + d[`SKILL_CHECK_DIE`] against
+    /// `difficulty`. A failed check ends the cast — the effects authored after
+    /// it don't resolve — so "check, then reveal, then flag" reads in order.
+    /// See [`handle_field_ability`]; ignored by [`handle_ability`].
+    SkillCheck { stat: Stat, difficulty: i32 },
+    /// Out of combat only: uncover every [`Concealed`] thing within `radius` world
+    /// units of the caster.
+    Reveal { radius: f32 },
+    /// Out of combat only: set a [`StoryFlags`] flag (a discovery, a persuaded
+    /// guard) for quests, dialogue and world rules to pick up.
+    SetFlag { flag: String },
+}
+
+/// What an [`AbilityEffect::Zone`] does to each occupant, once per round.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
+pub enum ZoneEffect {
+    Damage { amount: i32, damage_type: DamageType },
+    Heal { amount: u32 },
+    Status { kind: StatusKind, tier: u8 },
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -154,6 +218,16 @@ pub struct Ability {
     pub shape: AbilityShape,
     pub duration: u8,
     pub targets: u8,
+    /// Whether the caster can be caught in their own area shape. `None` (the
+    /// default for older data) derives it from the effects — see
+    /// [`Ability::includes_self`].
+    #[serde(default)]
+    pub includes_self: Option<bool>,
+    /// Line and cone only: the fraction of damage still dealt at the far end
+    /// of the shape, falling off linearly from full damage at the caster.
+    /// `None` (the default) keeps damage flat across the whole area.
+    #[serde(default)]
+    pub falloff: Option<f32>,
 }
 
 // ---------------------------------------------------------------------------
@@ -200,6 +274,310 @@ impl Ability {
     pub fn get_sub_id(&self) -> u16 {
         self.id & SUB_ID_MASK
     }
+
+    /// Whether the caster is a valid target of their own cast. An explicit
+    /// `includes_self` wins; otherwise anything that hurts (damage, morale
+    /// drain) spares the caster and purely supportive casts include them, so a
+    /// radius heal centred on the caster heals them too.
+    pub fn includes_self(&self) -> bool {
+        self.includes_self.unwrap_or_else(|| {
+            !self.effects.iter().any(|e| {
+                matches!(
+                    e,
+                    AbilityEffect::Damage { .. }
+                        | AbilityEffect::DrainMorale { .. }
+                        | AbilityEffect::ResourceDrain { .. }
+                )
+            })
+        })
+    }
+
+    /// Whether this ability does anything outside combat — a skill check,
+    /// reveal or flag set for [`handle_field_ability`] to resolve.
+    pub fn is_field_use(&self) -> bool {
+        self.effects.iter().any(|e| {
+            matches!(
+                e,
+                AbilityEffect::SkillCheck { .. }
+                    | AbilityEffect::Reveal { .. }
+                    | AbilityEffect::SetFlag { .. }
+            )
+        })
+    }
+
+    /// Damage multiplier for a target `progress` of the way (0.0 at the
+    /// caster, 1.0 at max range) through a line or cone. Always 1.0 for other
+    /// shapes or when [`Ability::falloff`] is unset.
+    pub fn falloff_multiplier(&self, progress: f32) -> f32 {
+        match (self.falloff, &self.shape) {
+            (Some(min), AbilityShape::Line { .. } | AbilityShape::Cone { .. }) => {
+                let min = min.clamp(0.0, 1.0);
+                1.0 - (1.0 - min) * progress.clamp(0.0, 1.0)
+            }
+            _ => 1.0,
+        }
+    }
+
+    /// Check that the authored numbers make sense together: costs aren't
+    /// negative, every rolled range has `floor <= ceiling`, at least one
+    /// target is allowed, and the shape has positive extents. An effect-less
+    /// ability is only accepted as a single-target `Select` action (Reload
+    /// and friends resolve outside the effect list); an empty area cast is a
+    /// data mistake.
+    pub fn validate(&self) -> Result<(), AbilityValidationError> {
+        if self.health_cost < 0
+            || self.action_point_cost < 0
+            || self.magic_cost.is_nan()
+            || self.magic_cost < 0.0
+        {
+            return Err(AbilityValidationError::NegativeCost);
+        }
+        if self.targets == 0 {
+            return Err(AbilityValidationError::NoTargets);
+        }
+        if self.effects.is_empty() && !matches!(self.shape, AbilityShape::Select) {
+            return Err(AbilityValidationError::NoEffects);
+        }
+        for (index, effect) in self.effects.iter().enumerate() {
+            let range = match effect {
+                AbilityEffect::Heal { floor, ceiling, .. }
+                | AbilityEffect::Damage { floor, ceiling, .. }
+                | AbilityEffect::DrainMorale { floor, ceiling, .. }
+                | AbilityEffect::ResourceDrain { floor, ceiling, .. } => Some((*floor, *ceiling)),
+                _ => None,
+            };
+            if let Some((floor, ceiling)) = range {
+                if floor > ceiling {
+                    return Err(AbilityValidationError::InvertedRange { index, floor, ceiling });
+                }
+            }
+            if matches!(
+                effect,
+                AbilityEffect::ResourceDrain { resource: ResourceKind::Health, .. }
+            ) {
+                return Err(AbilityValidationError::DrainsHealth { index });
+            }
+            if let AbilityEffect::Revive { hp_fraction } = effect {
+                // Written as a positive check so NaN fails too.
+                if !(*hp_fraction > 0.0 && *hp_fraction <= 1.0) {
+                    return Err(AbilityValidationError::BadReviveFraction { index });
+                }
+            }
+        }
+        if let Some(min) = self.falloff {
+            if !(0.0..=1.0).contains(&min) {
+                return Err(AbilityValidationError::BadShape("falloff must be in [0, 1]"));
+            }
+        }
+        // Written as positive checks so NaN extents fail too.
+        let positive = |v: f32| v > 0.0;
+        match self.shape {
+            AbilityShape::Radius(radius) if !positive(radius) => {
+                Err(AbilityValidationError::BadShape("radius must be positive"))
+            }
+            AbilityShape::Line { length, thickness }
+                if !(positive(length) && positive(thickness)) =>
+            {
+                Err(AbilityValidationError::BadShape("line length and thickness must be positive"))
+            }
+            AbilityShape::Cone { angle, .. } if !(positive(angle) && angle <= 360.0) => {
+                Err(AbilityValidationError::BadShape("cone angle must be in (0, 360]"))
+            }
+            AbilityShape::Cone { radius, .. } if !positive(radius) => {
+                Err(AbilityValidationError::BadShape("cone radius must be positive"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Why [`Ability::validate`] rejected an ability.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AbilityValidationError {
+    /// A health, magic or AP cost is negative (or NaN).
+    NegativeCost,
+    /// `targets` is zero, so the ability could never land.
+    NoTargets,
+    /// An area ability with nothing in `effects`.
+    NoEffects,
+    /// The effect at `index` rolls from a `floor` above its `ceiling`.
+    InvertedRange { index: usize, floor: u32, ceiling: u32 },
+    /// The shape has a non-positive extent or an out-of-range cone angle.
+    BadShape(&'static str),
+    /// The `ResourceDrain` at `index` targets health.
+    DrainsHealth { index: usize },
+    /// The `Revive` at `index` restores a fraction outside `(0, 1]`.
+    BadReviveFraction { index: usize },
+}
+
+impl fmt::Display for AbilityValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NegativeCost => f.write_str("costs must not be negative"),
+            Self::NoTargets => f.write_str("targets must be at least 1"),
+            Self::NoEffects => f.write_str("area ability has no effects"),
+            Self::InvertedRange { index, floor, ceiling } => {
+                write!(f, "effect {index} has floor {floor} above ceiling {ceiling}")
+            }
+            Self::BadShape(reason) => f.write_str(reason),
+            Self::DrainsHealth { index } => {
+                write!(f, "effect {index} drains health; use a Damage effect instead")
+            }
+            Self::BadReviveFraction { index } => {
+                write!(f, "effect {index} revives with a health fraction outside (0, 1]")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AbilityValidationError {}
+
+/// Fluent constructor for [`Ability`], for defining abilities in Rust (tests,
+/// scripted content) without going through RON. Unset fields take the same
+/// defaults a minimal data entry would: no costs or cooldown, single-target
+/// `Select` shape, elementally neutral Kiho.
+///
+/// ```ignore
+/// let blast = AbilityBuilder::new(7, "Blast")
+///     .damage(10, 20, DamageType::Fire, Stat::Lethality, Stat::Armor)
+///     .cooldown(3)
+///     .shape(AbilityShape::Radius(2.0))
+///     .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct AbilityBuilder {
+    ability: Ability,
+}
+
+impl AbilityBuilder {
+    pub fn new(id: u16, name: impl Into<String>) -> Self {
+        Self {
+            ability: Ability {
+                id,
+                next_id: None,
+                name: name.into(),
+                health_cost: 0,
+                magic_cost: 0.0,
+                magic_school: MagicSchool::default(),
+                element: None,
+                action_point_cost: 0,
+                cooldown: 0,
+                description: String::new(),
+                effects: Vec::new(),
+                shape: AbilityShape::Select,
+                duration: 0,
+                targets: 1,
+                includes_self: None,
+                falloff: None,
+            },
+        }
+    }
+
+    /// Append a plain `Damage` effect (no low-morale amplification).
+    pub fn damage(
+        self,
+        floor: u32,
+        ceiling: u32,
+        damage_type: DamageType,
+        scaled_with: Stat,
+        defended_with: Stat,
+    ) -> Self {
+        self.effect(AbilityEffect::Damage {
+            floor,
+            ceiling,
+            damage_type,
+            scaled_with,
+            defended_with,
+            amplify_low_morale: 0.0,
+        })
+    }
+
+    pub fn heal(self, floor: u32, ceiling: u32, scaled_with: Stat) -> Self {
+        self.effect(AbilityEffect::Heal { floor, ceiling, scaled_with })
+    }
+
+    pub fn resource_drain(
+        self,
+        resource: ResourceKind,
+        floor: u32,
+        ceiling: u32,
+        transfer: bool,
+    ) -> Self {
+        self.effect(AbilityEffect::ResourceDrain { resource, floor, ceiling, transfer })
+    }
+
+    /// Append any effect; the typed helpers above cover the common cases.
+    pub fn effect(mut self, effect: AbilityEffect) -> Self {
+        self.ability.effects.push(effect);
+        self
+    }
+
+    pub fn cooldown(mut self, turns: u8) -> Self {
+        self.ability.cooldown = turns;
+        self
+    }
+
+    pub fn shape(mut self, shape: AbilityShape) -> Self {
+        self.ability.shape = shape;
+        self
+    }
+
+    pub fn action_points(mut self, cost: i32) -> Self {
+        self.ability.action_point_cost = cost;
+        self
+    }
+
+    pub fn magic(mut self, school: MagicSchool, cost: f32) -> Self {
+        self.ability.magic_school = school;
+        self.ability.magic_cost = cost;
+        self
+    }
+
+    pub fn health_cost(mut self, cost: i32) -> Self {
+        self.ability.health_cost = cost;
+        self
+    }
+
+    pub fn element(mut self, element: Element) -> Self {
+        self.ability.element = Some(element);
+        self
+    }
+
+    pub fn description(mut self, text: impl Into<String>) -> Self {
+        self.ability.description = text.into();
+        self
+    }
+
+    pub fn duration(mut self, turns: u8) -> Self {
+        self.ability.duration = turns;
+        self
+    }
+
+    pub fn targets(mut self, count: u8) -> Self {
+        self.ability.targets = count;
+        self
+    }
+
+    /// Chain into the next rank of this ability.
+    pub fn next(mut self, id: u16) -> Self {
+        self.ability.next_id = Some(id);
+        self
+    }
+
+    pub fn includes_self(mut self, includes: bool) -> Self {
+        self.ability.includes_self = Some(includes);
+        self
+    }
+
+    /// Fraction of damage left at max range for line/cone shapes.
+    pub fn falloff(mut self, min_fraction: f32) -> Self {
+        self.ability.falloff = Some(min_fraction);
+        self
+    }
+
+    pub fn build(self) -> Ability {
+        self.ability
+    }
 }
 
 #[derive(Clone)]
@@ -367,29 +745,50 @@ fn write_guard(node: &Arc<RwLock<AbilityNode>>) -> Option<RwLockWriteGuard<'_, A
     }
 }
 
-pub fn handle_ability(
+/// Roll an ability's `floor..ceiling` amount. A degenerate range
+/// (`ceiling <= floor`, e.g. a fixed-value heal authored as `floor == ceiling`)
+/// yields `floor` instead of panicking in `gen_range`.
+pub fn roll_ability_amount(rng: &mut impl Rng, floor: u32, ceiling: u32) -> u32 {
+    crate::combat_plugin::safe_range(rng, floor, ceiling)
+}
+
+/// Running state shared by the effects of one cast, in authored order, so an
+/// earlier effect can shape a later one before the ECS has caught up.
+#[derive(Debug, Default)]
+pub struct CastContext {
+    /// Buffs this cast has put on its own caster so far. Carried on every later
+    /// `Damage` effect's `AttackContext::multipliers`, so "buff lethality, then
+    /// strike" hits with the buff even though `apply_buff_system` hasn't
+    /// persisted it yet (the damage pipeline skips any whose [`ModifierTag`]
+    /// it already has).
+    pub caster_buffs: Vec<StatModifier>,
+}
+
+/// Resolve `ability` from `caster` against every entity in `affected`, each
+/// paired with its distance falloff multiplier (see
+/// [`crate::combat_plugin::get_affected_characters`]; `1.0` for single-target
+/// casts), which scales rolled damage. All amount rolls draw from `rng` (the
+/// shared [`CombatRng`](crate::combat_plugin::CombatRng)) so a seeded run is
+/// reproducible. Effects resolve in authored order, each over every target,
+/// sharing one [`CastContext`], and go out through the caller's `writers`.
+pub(crate) fn handle_ability(
     caster: Entity,
     ability: &Ability,
-    affected: &[Entity],
+    affected: &[(Entity, f32)],
     now: u32,
-    dq: &mut DamageQueue,
-    attack_intent_events: &mut MessageWriter<AttackIntentEvent>,
-    heal_events: &mut MessageWriter<HealEvent>,
-    buff_events: &mut MessageWriter<ApplyBuffEvent>,
-    apply_status_events: &mut MessageWriter<ApplyStatusEvent>,
-    remove_status_events: &mut MessageWriter<RemoveStatusEvent>,
-    summon_events: &mut MessageWriter<SummonEvent>,
-    attune_events: &mut MessageWriter<ApplyAttunementEvent>,
-    flip_events: &mut MessageWriter<ApplyPolarityFlipEvent>,
-    drain_morale_events: &mut MessageWriter<DrainMoraleEvent>,
+    rng: &mut impl Rng,
+    writers: &mut PlayerActionWriters,
 ) {
-    for (target_index, &target) in affected.iter().enumerate() {
-        let cause = ActionCause::Ability { id: ability.id };
-        for effect in &ability.effects {
+    let cause = ActionCause::Ability { id: ability.id };
+    let mut cast = CastContext::default();
+    // Effect-major: each effect lands on every target before the next effect
+    // starts, so the authored effect order is the resolution order.
+    for (index, effect) in ability.effects.iter().enumerate() {
+        for &(target, falloff) in affected {
             match effect {
                 AbilityEffect::Heal { floor, ceiling, .. } => {
-                    let amount = rand::rng().gen_range(*floor..*ceiling);
-                    heal_events.write(HealEvent {
+                    let amount = roll_ability_amount(rng, *floor, *ceiling);
+                    writers.heal.write(HealEvent {
                         healer: caster,
                         target,
                         amount,
@@ -398,8 +797,8 @@ pub fn handle_ability(
                     });
                 }
                 AbilityEffect::DrainMorale { floor, ceiling, scaled_with } => {
-                    let base = rand::rng().gen_range(*floor..*ceiling) as i32;
-                    drain_morale_events.write(DrainMoraleEvent {
+                    let base = roll_ability_amount(rng, *floor, *ceiling) as i32;
+                    writers.drain_morale.write(DrainMoraleEvent {
                         drainer: caster,
                         target,
                         amount: base,
@@ -411,37 +810,22 @@ pub fn handle_ability(
                     floor,
                     ceiling,
                     damage_type,
-                    scaled_with,
-                    defended_with,
-                    amplify_low_morale,
+                    ..
                 } => {
-                    let base = rand::rng().gen_range(*floor..*ceiling) as i32;
-
-                    let mut tags = vec![DamageTag::FromAbility(ability.id)];
-                    if *amplify_low_morale > 0.0 {
-                        tags.push(DamageTag::AmplifyLowMorale(*amplify_low_morale));
-                    }
-
-                    dq.0.push(QueuedDamage {
-                        attacker: caster,
-                        target,
-                        amount: base,
-                        damage_type: *damage_type,
-                        element: ability.element,
-                        scaled_with: vec![(*scaled_with, 1.0)],
-                        defended_with: vec![(*defended_with, 1.0)],
-                        accuracy_override: None,
-                        crit_multiplier: 1.0,
-                        tags,
-                        cause: cause.clone(),
-                    });
-
-                    attack_intent_events.write(AttackIntentEvent {
+                    // The rolled base rides the attack pipeline as flat damage
+                    // so it only lands if *this* target's own hit roll does
+                    // (`queue_damage_from_before_attack`): every target of an
+                    // area cast dodges, crits and soaks independently.
+                    let rolled = roll_ability_amount(rng, *floor, *ceiling) as f32;
+                    let base = (rolled * falloff).round() as i32;
+                    writers.intent.write(AttackIntentEvent {
                         attacker: caster,
                         target,
                         ability: Some(ability.clone()),
                         context: crate::combat_plugin::AttackContext {
                             damage_type: Some(*damage_type),
+                            extra_flat_damage: base,
+                            multipliers: cast.caster_buffs.clone(),
                             ..Default::default()
                         },
                         cause: cause.clone(),
@@ -451,11 +835,17 @@ pub fn handle_ability(
                     stat,
                     multiplier,
                     effects,
+                    on_caster,
                     ..
                 } => {
-                    buff_events.write(ApplyBuffEvent {
+                    let tag = Some(ModifierTag::Cast {
+                        ability: ability.id,
+                        effect: index,
+                        at: now,
+                    });
+                    writers.buff.write(ApplyBuffEvent {
                         applier: caster,
-                        target,
+                        target: if *on_caster { caster } else { target },
                         stat: *stat,
                         multiplier: *multiplier,
                         duration_in_ticks: ability.duration as u32,
@@ -463,14 +853,26 @@ pub fn handle_ability(
                         applied_at: now,
                         element: ability.element,
                         cause: cause.clone(),
+                        tag,
                     });
+                    if *on_caster {
+                        cast.caster_buffs.push(StatModifier {
+                            stat: *stat,
+                            multiplier: *multiplier,
+                            expires_at_timestamp: Some(now.saturating_add(ability.duration as u32)),
+                            source: Some(caster),
+                            tag,
+                        });
+                        // One grant per cast, not one per target.
+                        break;
+                    }
                 }
                 AbilityEffect::ApplyStatus {
                     kind,
                     tier,
                     resource_focus,
                 } => {
-                    apply_status_events.write(ApplyStatusEvent {
+                    writers.apply_status.write(ApplyStatusEvent {
                         target,
                         kind: *kind,
                         tier: *tier,
@@ -480,7 +882,7 @@ pub fn handle_ability(
                     });
                 }
                 AbilityEffect::RemoveStatus { kind } => {
-                    remove_status_events.write(RemoveStatusEvent {
+                    writers.remove_status.write(RemoveStatusEvent {
                         target,
                         kind: *kind,
                     });
@@ -488,17 +890,16 @@ pub fn handle_ability(
                 AbilityEffect::Summon { kind, lifetime_turns } => {
                     // Caster-centric, not per-target: emit once per cast so a
                     // multi-target ability doesn't conjure a familiar per foe.
-                    if target_index == 0 {
-                        summon_events.write(SummonEvent {
-                            summoner: caster,
-                            kind: *kind,
-                            lifetime_turns: *lifetime_turns,
-                            target: affected.first().copied(),
-                        });
-                    }
+                    writers.summon.write(SummonEvent {
+                        summoner: caster,
+                        kind: *kind,
+                        lifetime_turns: *lifetime_turns,
+                        target: Some(target),
+                    });
+                    break;
                 }
                 AbilityEffect::Attune { phase, duration } => {
-                    attune_events.write(ApplyAttunementEvent {
+                    writers.attune.write(ApplyAttunementEvent {
                         target,
                         phase: *phase,
                         duration: *duration as u32,
@@ -506,12 +907,249 @@ pub fn handle_ability(
                     });
                 }
                 AbilityEffect::FlipPolarity { duration } => {
-                    flip_events.write(ApplyPolarityFlipEvent {
+                    writers.flip.write(ApplyPolarityFlipEvent {
                         target,
                         duration: *duration as u32,
                         source: Some(caster),
                     });
                 }
+                AbilityEffect::Regen {
+                    amount_per_turn,
+                    turns,
+                    resource,
+                } => {
+                    writers.regen.write(ApplyRegenBuffEvent {
+                        target,
+                        buff: RegenBuff {
+                            amount_per_turn: *amount_per_turn,
+                            remaining_turns: *turns,
+                            resource: *resource,
+                        },
+                        source: Some(caster),
+                    });
+                }
+                AbilityEffect::ResourceDrain {
+                    resource,
+                    floor,
+                    ceiling,
+                    transfer,
+                } => {
+                    writers.drain_resource.write(DrainResourceEvent {
+                        drainer: caster,
+                        target,
+                        resource: *resource,
+                        school: ability.magic_school,
+                        amount: roll_ability_amount(rng, *floor, *ceiling),
+                        transfer: *transfer,
+                        cause: cause.clone(),
+                    });
+                }
+                AbilityEffect::Guard { turns } => {
+                    writers.guard.write(ApplyGuardEvent {
+                        protector: caster,
+                        target,
+                        turns: *turns,
+                    });
+                }
+                AbilityEffect::Zone { effect, turns } => {
+                    // One zone per cast, anchored on the first target.
+                    writers.zone.write(SpawnZoneEvent {
+                        caster,
+                        target,
+                        shape: ability.shape.clone(),
+                        effect: *effect,
+                        turns: *turns,
+                    });
+                    break;
+                }
+                AbilityEffect::Revive { hp_fraction } => {
+                    writers.revive.write(ReviveEvent {
+                        reviver: caster,
+                        target,
+                        hp_fraction: *hp_fraction,
+                    });
+                }
+                // World-facing effects resolve through `handle_field_ability`;
+                // in a fight there is nothing for them to act on.
+                AbilityEffect::SkillCheck { .. }
+                | AbilityEffect::Reveal { .. }
+                | AbilityEffect::SetFlag { .. } => {}
+            }
+        }
+    }
+}
+
+/// Die rolled on top of the caster's stat for an [`AbilityEffect::SkillCheck`].
+pub const SKILL_CHECK_DIE: i32 = 20;
+
+/// Something in the world (a buried cache, a trap, a secret door) that stays
+/// out of sight, and can't be talked to or examined, until an
+/// [`AbilityEffect::Reveal`] uncovers it. See [`sync_concealed_visibility`].
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct Concealed;
+
+/// Hide whatever has just been [`Concealed`] and show again whatever has been
+/// uncovered since the last run.
+pub fn sync_concealed_visibility(
+    concealed: Query<Entity, Added<Concealed>>,
+    mut uncovered: RemovedComponents<Concealed>,
+    mut visibility: Query<&mut Visibility>,
+) {
+    for entity in &concealed {
+        if let Ok(mut vis) = visibility.get_mut(entity) {
+            *vis = Visibility::Hidden;
+        }
+    }
+    for entity in uncovered.read() {
+        if let Ok(mut vis) = visibility.get_mut(entity) {
+            *vis = Visibility::Inherited;
+        }
+    }
+}
+
+/// What an out-of-combat cast did to the world; see [`handle_field_ability`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FieldOutcome {
+    /// `false` once an [`AbilityEffect::SkillCheck`] failed; nothing after it
+    /// resolved.
+    pub passed: bool,
+    pub revealed: Vec<Entity>,
+    pub flags: Vec<String>,
+}
+
+/// Resolve `ability` outside combat, against the world rather than
+/// combatants: skill checks roll against the caster's stats, reveals pick
+/// from `concealed` (each with its position) by distance to `caster_at`, and flag
+/// sets are collected for the caller to apply. Effects resolve in authored
+/// order like [`handle_ability`]; combatant effects are skipped, since there
+/// is nobody to land on.
+pub fn handle_field_ability(
+    ability: &Ability,
+    caster_stats: Option<&CombatStats>,
+    caster_at: Vec3,
+    concealed: &[(Entity, Vec3)],
+    rng: &mut impl Rng,
+) -> FieldOutcome {
+    let mut outcome = FieldOutcome {
+        passed: true,
+        ..Default::default()
+    };
+    for effect in &ability.effects {
+        match effect {
+            AbilityEffect::SkillCheck { stat, difficulty } => {
+                let roll = rng.random_range(1..=SKILL_CHECK_DIE);
+                if get_stat_value(*stat, caster_stats) + roll < *difficulty {
+                    outcome.passed = false;
+                    break;
+                }
+            }
+            AbilityEffect::Reveal { radius } => {
+                outcome.revealed.extend(
+                    concealed
+                        .iter()
+                        .filter(|(_, at)| at.distance(caster_at) <= *radius)
+                        .map(|(entity, _)| *entity),
+                );
+            }
+            AbilityEffect::SetFlag { flag } => outcome.flags.push(flag.clone()),
+            _ => {}
+        }
+    }
+    outcome
+}
+
+/// Request to use one of `caster`'s abilities while exploring or in dialogue.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct UseFieldAbilityEvent {
+    pub caster: Entity,
+    pub ability_id: u16,
+}
+
+/// Exploration quick-cast keys: the n-th key uses the leader's n-th field
+/// ability (see [`Ability::is_field_use`]), in the order they learned them.
+/// The same digits pick skills in the combat HUD.
+pub const FIELD_ABILITY_KEYS: [KeyCode; 9] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+];
+
+/// Turn a [`FIELD_ABILITY_KEYS`] press while exploring into a
+/// [`UseFieldAbilityEvent`] for the leader.
+pub fn field_ability_hotkey_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    game_state: Res<GameState>,
+    ability_tree: Option<Res<Ability_Tree>>,
+    leader_q: Query<(Entity, &Abilities), With<Player>>,
+    mut uses: MessageWriter<UseFieldAbilityEvent>,
+) {
+    if game_state.0 != Game_State::Exploring {
+        return;
+    }
+    let Some(slot) = FIELD_ABILITY_KEYS.iter().position(|k| keys.just_pressed(*k)) else {
+        return;
+    };
+    let (Some(tree), Ok((leader, known))) = (ability_tree, leader_q.single()) else {
+        return;
+    };
+    let picked = known
+        .0
+        .iter()
+        .filter_map(|&id| tree.0.find(id))
+        .filter(Ability::is_field_use)
+        .nth(slot);
+    if let Some(ability) = picked {
+        uses.write(UseFieldAbilityEvent { caster: leader, ability_id: ability.id });
+    }
+}
+
+/// Applies [`handle_field_ability`] for each [`UseFieldAbilityEvent`]:
+/// uncovered things lose [`Concealed`] and flags are set in [`StoryFlags`] (with a
+/// [`FlagChangedEvent`], so world rules see them). The caster must know the
+/// ability. Rolls draw from the shared [`CombatRng`].
+pub fn use_field_ability_system(
+    mut commands: Commands,
+    mut uses: MessageReader<UseFieldAbilityEvent>,
+    ability_tree: Option<Res<Ability_Tree>>,
+    casters: Query<(&Transform, &Abilities, Option<&CombatStats>)>,
+    concealed_q: Query<(Entity, &Transform), With<Concealed>>,
+    mut rng: ResMut<CombatRng>,
+    mut story_flags: ResMut<StoryFlags>,
+    mut flag_w: MessageWriter<FlagChangedEvent>,
+) {
+    let Some(tree) = ability_tree else {
+        return;
+    };
+    for ev in uses.read() {
+        let Ok((transform, known, stats)) = casters.get(ev.caster) else {
+            continue;
+        };
+        if !known.0.contains(&ev.ability_id) {
+            warn!("{:?} does not know ability {}", ev.caster, ev.ability_id);
+            continue;
+        }
+        let Some(ability) = tree.0.find(ev.ability_id) else {
+            warn!("Field use by {:?} references unknown ability id {}", ev.caster, ev.ability_id);
+            continue;
+        };
+        let concealed: Vec<(Entity, Vec3)> = concealed_q
+            .iter()
+            .map(|(entity, tf)| (entity, tf.translation))
+            .collect();
+        let outcome =
+            handle_field_ability(&ability, stats, transform.translation, &concealed, &mut rng.0);
+        for entity in outcome.revealed {
+            commands.entity(entity).remove::<Concealed>();
+        }
+        for flag in outcome.flags {
+            if story_flags.set(flag.clone()) {
+                flag_w.write(FlagChangedEvent { name: flag, set: true });
             }
         }
     }
@@ -547,6 +1185,154 @@ mod tests {
         assert!(sub <= MAX_SUB_ID);
     }
 
+    #[test]
+    fn seeded_ability_rolls_are_reproducible() {
+        use crate::combat_plugin::CombatRng;
+        let roll_all = |seed| {
+            let mut rng = CombatRng::seeded(seed);
+            (0..16)
+                .map(|_| roll_ability_amount(&mut rng.0, 5, 40))
+                .collect::<Vec<_>>()
+        };
+        let first = roll_all(42);
+        assert_eq!(first, roll_all(42));
+        assert!(first.iter().all(|&v| (5..40).contains(&v)));
+    }
+
+    #[test]
+    fn equal_bounds_roll_returns_exact_value() {
+        let mut rng = crate::combat_plugin::CombatRng::seeded(7);
+        for _ in 0..8 {
+            assert_eq!(roll_ability_amount(&mut rng.0, 12, 12), 12);
+        }
+    }
+
+    #[test]
+    fn builder_sets_requested_fields() {
+        let ability = AbilityBuilder::new(pack_ability_id(2, 9), "Ember Burst")
+            .damage(10, 20, DamageType::Fire, Stat::Lethality, Stat::Armor)
+            .cooldown(3)
+            .shape(AbilityShape::Radius(2.0))
+            .action_points(2)
+            .build();
+
+        assert_eq!(ability.id, pack_ability_id(2, 9));
+        assert_eq!(ability.get_level(), 2);
+        assert_eq!(ability.name, "Ember Burst");
+        assert_eq!(ability.cooldown, 3);
+        assert_eq!(ability.action_point_cost, 2);
+        assert!(matches!(ability.shape, AbilityShape::Radius(r) if r == 2.0));
+        assert_eq!(ability.effects.len(), 1);
+        assert!(matches!(
+            ability.effects[0],
+            AbilityEffect::Damage {
+                floor: 10,
+                ceiling: 20,
+                damage_type: DamageType::Fire,
+                scaled_with: Stat::Lethality,
+                defended_with: Stat::Armor,
+                ..
+            }
+        ));
+        // Damage with no explicit override spares the caster.
+        assert!(!ability.includes_self());
+    }
+
+    #[test]
+    fn coherent_ability_validates() {
+        let ability = AbilityBuilder::new(3, "Cone Breath")
+            .damage(8, 14, DamageType::Fire, Stat::Lethality, Stat::Armor)
+            .shape(AbilityShape::Cone { angle: 90.0, radius: 3.0 })
+            .build();
+        assert_eq!(ability.validate(), Ok(()));
+    }
+
+    #[test]
+    fn inverted_damage_range_fails_validation() {
+        let ability = AbilityBuilder::new(4, "Backwards")
+            .heal(5, 5, Stat::Mind)
+            .damage(20, 10, DamageType::Physical, Stat::Lethality, Stat::Armor)
+            .build();
+        assert_eq!(
+            ability.validate(),
+            Err(AbilityValidationError::InvertedRange { index: 1, floor: 20, ceiling: 10 }),
+        );
+    }
+
+    #[test]
+    fn detect_outside_combat_reveals_cache_and_sets_flag() {
+        use crate::combat_plugin::StatPool;
+
+        let strike = AbilityBuilder::new(pack_ability_id(1, 39), "Strike")
+            .damage(5, 5, DamageType::Physical, Stat::Lethality, Stat::Armor)
+            .build();
+        let detect = AbilityBuilder::new(pack_ability_id(1, 40), "Detect")
+            .effect(AbilityEffect::SkillCheck { stat: Stat::Mind, difficulty: 10 })
+            .effect(AbilityEffect::Reveal { radius: 4.0 })
+            .effect(AbilityEffect::SetFlag { flag: "found_shrine_cache".to_string() })
+            .build();
+        let mut tree = AbilityTree::new();
+        tree.insert(strike.clone());
+        tree.insert(detect.clone());
+
+        let mut app = App::new();
+        app.add_message::<UseFieldAbilityEvent>()
+            .add_message::<FlagChangedEvent>()
+            .init_resource::<StoryFlags>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .insert_resource(GameState(Game_State::Exploring))
+            .insert_resource(CombatRng::seeded(5))
+            .insert_resource(Ability_Tree(tree))
+            .add_systems(
+                Update,
+                (
+                    field_ability_hotkey_system,
+                    use_field_ability_system,
+                    sync_concealed_visibility,
+                )
+                    .chain(),
+            );
+        let stats = CombatStats { mind: <StatPool<i32>>::new(12), ..Default::default() };
+        let world = app.world_mut();
+        world.spawn((
+            Player,
+            Transform::default(),
+            Abilities(vec![strike.id, detect.id]),
+            stats.clone(),
+        ));
+        let cache = world
+            .spawn((Transform::from_xyz(3.0, 0.0, 0.0), Visibility::default(), Concealed))
+            .id();
+        let far = world
+            .spawn((Transform::from_xyz(40.0, 0.0, 0.0), Visibility::default(), Concealed))
+            .id();
+        app.update();
+        assert_eq!(app.world().get::<Visibility>(cache), Some(&Visibility::Hidden));
+
+        // Strike isn't a field ability, so the first key is Detect.
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Digit1);
+        app.update();
+
+        assert!(app.world().resource::<StoryFlags>().is_set("found_shrine_cache"));
+        assert!(app.world().get::<Concealed>(cache).is_none());
+        assert_eq!(app.world().get::<Visibility>(cache), Some(&Visibility::Inherited));
+        assert!(app.world().get::<Concealed>(far).is_some());
+        assert_eq!(app.world().get::<Visibility>(far), Some(&Visibility::Hidden));
+
+        // Against a check no roll can pass, nothing after it resolves.
+        let mut rng = CombatRng::seeded(5);
+        let hard = AbilityBuilder::new(pack_ability_id(1, 41), "Hard Look")
+            .effect(AbilityEffect::SkillCheck {
+                stat: Stat::Mind,
+                difficulty: 12 + SKILL_CHECK_DIE + 1,
+            })
+            .effect(AbilityEffect::SetFlag { flag: "never".to_string() })
+            .build();
+        let outcome = handle_field_ability(&hard, Some(&stats), Vec3::ZERO, &[], &mut rng.0);
+        assert!(!outcome.passed);
+        assert!(outcome.flags.is_empty());
+    }
+
     /// The shipped ability data must deserialise and every id must decode to a
     /// level within the cap — guards the 5/11 re-mint against regressions.
     #[test]
@@ -557,6 +1343,9 @@ mod tests {
             ron::de::from_str(&text).expect("AbilitiesExample.ron deserialises into Vec<Ability>");
         assert!(!abilities.is_empty());
         for a in &abilities {
+            if let Err(err) = a.validate() {
+                panic!("ability {} ('{}') is invalid: {err}", a.id, a.name);
+            }
             assert!(
                 a.get_level() <= MAX_LEVEL,
                 "ability {} ('{}') decodes to level {} > {MAX_LEVEL}",
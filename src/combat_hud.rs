@@ -14,27 +14,35 @@
 //! - **Keyboard**: `↑`/`↓` move the focus, `1`–`9` jump straight to an option,
 //!   `Enter` chooses the focused option. While picking a target, `←`/`→`/`Tab`
 //!   cycle enemies, `Enter` commits, `Esc` cancels.
+//! - **Confirm step** (when [`ActionStaging`] is enabled): a chosen action is
+//!   only staged; `Enter` confirms it and `Backspace` takes it back to choose
+//!   again. Nothing is spent until it's confirmed.
 //!
 //! Affordability (AP / magic-pool cost) and status gates (Silenced, Terrified)
 //! are evaluated every frame, so unusable options are dimmed and explain why in
-//! the hint line. The target-click system runs before
+//! the hint line. During a scripted [`CombatTutorial`] everything but the
+//! current step's action is dimmed too, and the hint line carries the step's
+//! instructions. The target-click system runs before
 //! [`crate::movement::mouse_click`] and consumes the click when it acts, so
 //! click-to-move still works while the HUD is idle.
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
 use crate::battle::{BattleParticipant, BattleSide};
 use crate::combat_ability::{Ability, Ability_Tree, MagicSchool};
 use crate::combat_plugin::{
-    effective_element, Abilities, Attunement, CombatStats, ElementalAffinity, Inventory,
-    InventoryItemCatalog, InventoryItemKind, PendingPlayerAction, PlayerAction,
-    PlayerActionEvent, PolarityFlip, OVERLOAD_THRESHOLD,
+    effective_element, Abilities, ActionStaging, Attunement, CombatStats,
+    ConfirmStagedActionEvent, ElementalAffinity, Inventory, InventoryItemCatalog,
+    InventoryItemKind, PendingPlayerAction, PlayerAction, PlayerActionEvent, PolarityFlip,
+    StageActionEvent, StatModifiers, UndoStagedActionEvent, OVERLOAD_THRESHOLD,
 };
+use crate::combat_tutorial::{CombatTutorial, TutorialAction};
 use crate::gogyo::{damage_multiplier_overloaded, Element, Phase, Polarity};
 use crate::constants::{BASIC_ATTACK_ACTION_POINT_COST, ITEM_ACTION_POINT_COST};
-use crate::core::{GameState, Game_State, MainCamera};
+use crate::core::{GameState, Game_State, MainCamera, Timestamp};
 use crate::skill_tree::MagicCostMultipliers;
-use crate::status_effects::{action_gates, magic_cost_multiplier, StatusEffects};
+use crate::status_effects::{action_gates, magic_cost_multiplier, Expiry, StatusEffects};
 use crate::ui_style::{font_size, palette, radius, spacing};
 
 /// Plugin entry point.
@@ -46,6 +54,7 @@ impl Plugin for CombatHudPlugin {
             // Spawn / despawn must run before the input handlers so a freshly
             // spawned HUD is interactable on the same frame.
             .add_systems(Update, manage_combat_hud_lifetime)
+            .add_systems(Update, sync_hud_snapshot)
             .add_systems(
                 Update,
                 (handle_combat_hud_mouse, handle_combat_hud_keyboard)
@@ -126,6 +135,100 @@ enum CategoryKind {
     Open(FlyoutKind),
 }
 
+// ---------------------------------------------------------------------------
+// Status bar data
+// ---------------------------------------------------------------------------
+
+/// One icon on a combatant's status bar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusIconEntry {
+    pub icon_id: String,
+    /// Tier for statuses; number of live modifiers for a stat buff/debuff.
+    pub stacks: u8,
+    /// Turns until it wears off (the world clock advances one tick per combat
+    /// turn). `None` for effects that last until combat ends or are cleansed.
+    pub remaining_turns: Option<u32>,
+    pub beneficial: bool,
+}
+
+/// Everything a status bar needs to draw one combatant, rebuilt every frame
+/// by `sync_hud_snapshot` from their [`StatusEffects`] and [`StatModifiers`].
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct HudSnapshot {
+    pub statuses: Vec<StatusIconEntry>,
+}
+
+impl HudSnapshot {
+    /// Statuses first in application order, then one entry per raised or
+    /// lowered stat, with modifiers on the same stat and direction folded into
+    /// a stack that lasts as long as its longest member.
+    pub fn build(
+        status: Option<&StatusEffects>,
+        modifiers: Option<&StatModifiers>,
+        now: u32,
+    ) -> Self {
+        let remaining = |end: u32| end.saturating_sub(now);
+        let mut statuses: Vec<StatusIconEntry> = status
+            .map(|se| se.0.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|s| StatusIconEntry {
+                icon_id: s.kind.icon_id(),
+                stacks: s.tier,
+                remaining_turns: match s.expiry {
+                    Expiry::AtTimestamp(end) => Some(remaining(end)),
+                    _ => None,
+                },
+                beneficial: s.kind.is_beneficial(),
+            })
+            .collect();
+
+        let first_modifier = statuses.len();
+        for m in modifiers.map(|m| m.0.as_slice()).unwrap_or_default() {
+            if m.multiplier == 1.0 || m.expires_at_timestamp.is_some_and(|end| end <= now) {
+                continue;
+            }
+            let raised = m.multiplier > 1.0;
+            let icon_id = m.stat.icon_id(raised);
+            let left = m.expires_at_timestamp.map(remaining);
+            match statuses[first_modifier..].iter_mut().find(|e| e.icon_id == icon_id) {
+                Some(entry) => {
+                    entry.stacks = entry.stacks.saturating_add(1);
+                    entry.remaining_turns = match (entry.remaining_turns, left) {
+                        (Some(a), Some(b)) => Some(a.max(b)),
+                        _ => None,
+                    };
+                }
+                None => statuses.push(StatusIconEntry {
+                    icon_id,
+                    stacks: 1,
+                    remaining_turns: left,
+                    beneficial: raised,
+                }),
+            }
+        }
+        Self { statuses }
+    }
+}
+
+/// Keep every combatant's [`HudSnapshot`] current. Only writes when the
+/// contents change so the status bar can react to `Changed<HudSnapshot>`.
+fn sync_hud_snapshot(
+    mut commands: Commands,
+    timestamp: Res<Timestamp>,
+    q: Query<
+        (Entity, Option<&StatusEffects>, Option<&StatModifiers>, Option<&HudSnapshot>),
+        With<BattleParticipant>,
+    >,
+) {
+    for (entity, status, modifiers, current) in &q {
+        let snapshot = HudSnapshot::build(status, modifiers, timestamp.0);
+        if current != Some(&snapshot) {
+            commands.entity(entity).insert(snapshot);
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Markers
 // ---------------------------------------------------------------------------
@@ -758,6 +861,10 @@ fn format_item_label(def: &crate::combat_plugin::InventoryItemDefinition) -> Str
     let detail = match &def.kind {
         InventoryItemKind::Consumable { effect, .. } => match effect {
             crate::combat_plugin::ConsumableEffect::Heal { amount } => format!("heal {amount}"),
+            crate::combat_plugin::ConsumableEffect::Regen {
+                amount_per_turn,
+                turns,
+            } => format!("regen {amount_per_turn}×{turns}"),
         },
         InventoryItemKind::Equipment(_) => "equipment".to_string(),
     };
@@ -789,6 +896,8 @@ fn placeholder_ability(id: u16) -> Ability {
         shape: crate::combat_ability::AbilityShape::Select,
         duration: 0,
         targets: 1,
+        includes_self: None,
+        falloff: None,
     }
 }
 
@@ -818,6 +927,7 @@ struct ActorCtx<'a> {
     cost_mult: f32,
     mults: MagicCostMultipliers,
     tree: Option<&'a Ability_Tree>,
+    tutorial: Option<&'a CombatTutorial>,
 }
 
 impl ActorCtx<'_> {
@@ -830,6 +940,18 @@ impl ActorCtx<'_> {
     }
 
     fn usability(&self, action: HudAction) -> Usability {
+        if let Some(tutorial) = self.tutorial {
+            let kind = match action {
+                HudAction::Attack => TutorialAction::Attack,
+                HudAction::Ability(id) => TutorialAction::UseAbility(u32::from(id)),
+                HudAction::Item(id) => TutorialAction::UseItem(id),
+                HudAction::Defend => TutorialAction::Defend,
+                HudAction::Wait => TutorialAction::Wait,
+            };
+            if !tutorial.allows_kind(kind) {
+                return Usability::no("Not this step (tutorial)");
+            }
+        }
         match action {
             HudAction::Defend | HudAction::Wait => Usability::ok(),
             HudAction::Attack => {
@@ -884,6 +1006,7 @@ fn resolve_actor<'a>(
     status_q: &Query<&StatusEffects>,
     mult_q: &Query<&MagicCostMultipliers>,
     tree: Option<&'a Ability_Tree>,
+    tutorial: Option<&'a CombatTutorial>,
 ) -> Option<ActorCtx<'a>> {
     let stats = stats_q.get(actor).ok()?;
     let se = status_q.get(actor).ok();
@@ -893,6 +1016,7 @@ fn resolve_actor<'a>(
         cost_mult: magic_cost_multiplier(se),
         mults: mult_q.get(actor).copied().unwrap_or_default(),
         tree,
+        tutorial,
     })
 }
 
@@ -913,15 +1037,22 @@ fn handle_combat_hud_mouse(
     stats_q: Query<&CombatStats>,
     status_q: Query<&StatusEffects>,
     mult_q: Query<&MagicCostMultipliers>,
+    tutorial: Option<Res<CombatTutorial>>,
     enemies_q: Query<(Entity, &BattleSide), With<BattleParticipant>>,
-    mut actions: MessageWriter<PlayerActionEvent>,
+    mut actions: ActionSink,
 ) {
-    if state.mode != HudMode::Idle {
+    if state.mode != HudMode::Idle || actions.staging.staged.is_some() {
         return;
     }
     let Some(actor) = pending.entity else { return };
-    let Some(ctx) = resolve_actor(actor, &stats_q, &status_q, &mult_q, ability_tree.as_deref())
-    else {
+    let Some(ctx) = resolve_actor(
+        actor,
+        &stats_q,
+        &status_q,
+        &mult_q,
+        ability_tree.as_deref(),
+        tutorial.as_deref(),
+    ) else {
         return;
     };
 
@@ -951,7 +1082,7 @@ fn activate_category(
     ctx: &ActorCtx,
     state: &mut CombatHudState,
     enemies_q: &Query<(Entity, &BattleSide), With<BattleParticipant>>,
-    actions: &mut MessageWriter<PlayerActionEvent>,
+    actions: &mut ActionSink,
 ) {
     match kind {
         CategoryKind::Direct(action) => {
@@ -982,16 +1113,27 @@ fn handle_combat_hud_keyboard(
     stats_q: Query<&CombatStats>,
     status_q: Query<&StatusEffects>,
     mult_q: Query<&MagicCostMultipliers>,
+    tutorial: Option<Res<CombatTutorial>>,
     category_q: Query<&CombatHudCategory>,
     option_q: Query<&CombatHudOption>,
     enemies_q: Query<(Entity, &BattleSide), With<BattleParticipant>>,
-    mut actions: MessageWriter<PlayerActionEvent>,
+    mut actions: ActionSink,
 ) {
     if game_state.0 != Game_State::Battle {
         return;
     }
     let Some(actor) = pending.entity else { return };
 
+    // A staged pick holds the panel until it's confirmed or taken back.
+    if actions.staging.staged.is_some() {
+        if keys.just_pressed(KeyCode::Enter) {
+            actions.confirm.write(ConfirmStagedActionEvent);
+        } else if keys.just_pressed(KeyCode::Backspace) {
+            actions.undo.write(UndoStagedActionEvent);
+        }
+        return;
+    }
+
     // Esc backs out: cancel targeting, or close an open flyout.
     if keys.just_pressed(KeyCode::Escape) {
         if state.mode != HudMode::Idle {
@@ -1063,7 +1205,12 @@ fn handle_combat_hud_keyboard(
                             opts.iter().find(|o| o.index == focus).map(|o| o.action)
                         {
                             if let Some(ctx) = resolve_actor(
-                                actor, &stats_q, &status_q, &mult_q, ability_tree.as_deref(),
+                                actor,
+                                &stats_q,
+                                &status_q,
+                                &mult_q,
+                                ability_tree.as_deref(),
+                                tutorial.as_deref(),
                             ) {
                                 choose_option(action, &ctx, &mut state, &enemies_q, &mut actions);
                             }
@@ -1090,7 +1237,12 @@ fn handle_combat_hud_keyboard(
                             category_q.iter().find(|c| c.index == focus).map(|c| c.kind)
                         {
                             if let Some(ctx) = resolve_actor(
-                                actor, &stats_q, &status_q, &mult_q, ability_tree.as_deref(),
+                                actor,
+                                &stats_q,
+                                &status_q,
+                                &mult_q,
+                                ability_tree.as_deref(),
+                                tutorial.as_deref(),
                             ) {
                                 activate_category(kind, &ctx, &mut state, &enemies_q, &mut actions);
                             }
@@ -1118,31 +1270,50 @@ const NUMBER_KEYS: &[(KeyCode, usize)] = &[
 // Choosing / committing actions
 // ---------------------------------------------------------------------------
 
+/// Where a chosen action goes: straight into the turn, or into the confirm
+/// step while [`ActionStaging`] is enabled.
+#[derive(SystemParam)]
+struct ActionSink<'w> {
+    staging: Res<'w, ActionStaging>,
+    direct: MessageWriter<'w, PlayerActionEvent>,
+    stage: MessageWriter<'w, StageActionEvent>,
+    confirm: MessageWriter<'w, ConfirmStagedActionEvent>,
+    undo: MessageWriter<'w, UndoStagedActionEvent>,
+}
+
+impl ActionSink<'_> {
+    fn send(&mut self, action: PlayerAction) {
+        if self.staging.enabled {
+            self.stage.write(StageActionEvent { action });
+        } else {
+            self.direct.write(PlayerActionEvent { action });
+        }
+    }
+}
+
 /// Resolve a chosen option: fire immediately, or arm target selection.
 fn choose_option(
     action: HudAction,
     ctx: &ActorCtx,
     state: &mut CombatHudState,
     enemies_q: &Query<(Entity, &BattleSide), With<BattleParticipant>>,
-    actions: &mut MessageWriter<PlayerActionEvent>,
+    actions: &mut ActionSink,
 ) {
     if !ctx.usability(action).enabled {
         return;
     }
     match action {
         HudAction::Defend => {
-            actions.write(PlayerActionEvent { action: PlayerAction::Defend });
+            actions.send(PlayerAction::Defend);
             state.mode = HudMode::Idle;
         }
         HudAction::Wait => {
-            actions.write(PlayerActionEvent { action: PlayerAction::Wait });
+            actions.send(PlayerAction::Wait);
             state.mode = HudMode::Idle;
         }
         HudAction::Item(id) => {
             // Self-target consumables for now.
-            actions.write(PlayerActionEvent {
-                action: PlayerAction::UseItem(id, None),
-            });
+            actions.send(PlayerAction::UseItem(id, None));
             state.mode = HudMode::Idle;
         }
         HudAction::Attack => {
@@ -1160,16 +1331,14 @@ fn commit_target(
     selected: SelectedAction,
     target: Entity,
     state: &mut CombatHudState,
-    actions: &mut MessageWriter<PlayerActionEvent>,
+    actions: &mut ActionSink,
 ) {
     match selected {
         SelectedAction::Attack => {
-            actions.write(PlayerActionEvent { action: PlayerAction::Attack(target) });
+            actions.send(PlayerAction::Attack(target));
         }
         SelectedAction::Ability(id) => {
-            actions.write(PlayerActionEvent {
-                action: PlayerAction::UseAbility(id as u32, target),
-            });
+            actions.send(PlayerAction::UseAbility(id as u32, target));
         }
     }
     state.mode = HudMode::Idle;
@@ -1190,7 +1359,7 @@ fn handle_target_click(
     camera_q: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
     windows: Query<&Window>,
     enemies_q: Query<(Entity, &Transform, &BattleSide), With<BattleParticipant>>,
-    mut actions: MessageWriter<PlayerActionEvent>,
+    mut actions: ActionSink,
 ) {
     if game_state.0 != Game_State::Battle {
         return;
@@ -1292,6 +1461,7 @@ fn sync_combat_hud(
     stats_q: Query<&CombatStats>,
     status_q: Query<&StatusEffects>,
     mult_q: Query<&MagicCostMultipliers>,
+    tutorial: Option<Res<CombatTutorial>>,
     name_q: Query<&Name>,
     mut category_q: Query<
         (
@@ -1324,8 +1494,14 @@ fn sync_combat_hud(
     mut header_q: Query<&mut Text, With<CombatHudHeader>>,
 ) {
     let Some(actor) = pending.entity else { return };
-    let Some(ctx) = resolve_actor(actor, &stats_q, &status_q, &mult_q, ability_tree.as_deref())
-    else {
+    let Some(ctx) = resolve_actor(
+        actor,
+        &stats_q,
+        &status_q,
+        &mult_q,
+        ability_tree.as_deref(),
+        tutorial.as_deref(),
+    ) else {
         return;
     };
 
@@ -1436,12 +1612,14 @@ fn sync_combat_hud(
 #[allow(clippy::too_many_arguments)]
 fn sync_hint(
     state: Res<CombatHudState>,
+    staging: Res<ActionStaging>,
     pending: Res<PendingPlayerAction>,
     ability_tree: Option<Res<Ability_Tree>>,
     item_catalog: Option<Res<InventoryItemCatalog>>,
     stats_q: Query<&CombatStats>,
     status_q: Query<&StatusEffects>,
     mult_q: Query<&MagicCostMultipliers>,
+    tutorial: Option<Res<CombatTutorial>>,
     name_q: Query<&Name>,
     category_q: Query<&CombatHudCategory>,
     options_q: Query<&CombatHudOption>,
@@ -1449,49 +1627,80 @@ fn sync_hint(
 ) {
     let Ok(mut hint) = hint_q.single_mut() else { return };
     let Some(actor) = pending.entity else { return };
-    let Some(ctx) = resolve_actor(actor, &stats_q, &status_q, &mult_q, ability_tree.as_deref())
-    else {
+    let Some(ctx) = resolve_actor(
+        actor,
+        &stats_q,
+        &status_q,
+        &mult_q,
+        ability_tree.as_deref(),
+        tutorial.as_deref(),
+    ) else {
         return;
     };
 
-    let desired = match state.mode {
-        HudMode::AwaitingTarget(selected) => {
-            let target_name = state
-                .target
-                .and_then(|t| name_q.get(t).ok())
-                .map(|n| n.to_string())
-                .unwrap_or_else(|| "—".to_string());
-            let (what, est) = match selected {
-                SelectedAction::Attack => {
-                    ("Attack".to_string(), format!("≈{} dmg", ctx.stats.lethality.current))
-                }
-                SelectedAction::Ability(id) => match ctx.ability(id) {
-                    Some(a) => (a.name.clone(), ability_estimate(&a)),
-                    None => ("Ability".to_string(), String::new()),
-                },
-            };
-            format!(
-                "{what} → {target_name} {est}  ·  ←/→ cycle · Enter confirm · Esc cancel · or click an enemy"
-            )
+    let desired = if let Some(action) = staging.staged_action() {
+        let target_name = |t: &Entity| name_q.get(*t).map(|n| n.to_string()).unwrap_or_default();
+        let what = match action {
+            PlayerAction::Attack(t) => format!("Attack → {}", target_name(t)),
+            PlayerAction::UseAbility(id, t) => {
+                let name = u16::try_from(*id)
+                    .ok()
+                    .and_then(|id| ctx.ability(id))
+                    .map(|a| a.name.clone())
+                    .unwrap_or_else(|| "Ability".to_string());
+                format!("{name} → {}", target_name(t))
+            }
+            PlayerAction::UseItem(..) => "Use item".to_string(),
+            PlayerAction::Defend => "Defend".to_string(),
+            PlayerAction::Wait => "Wait".to_string(),
+        };
+        format!("{what}  ·  Enter confirm · Backspace undo")
+    } else if let Some(step) = tutorial
+        .as_ref()
+        .and_then(|t| t.current_step())
+        .filter(|_| state.mode == HudMode::Idle)
+    {
+        step.hint.clone()
+    } else {
+        match state.mode {
+            HudMode::AwaitingTarget(selected) => {
+                let target_name = state
+                    .target
+                    .and_then(|t| name_q.get(t).ok())
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|| "—".to_string());
+                let (what, est) = match selected {
+                    SelectedAction::Attack => {
+                        ("Attack".to_string(), format!("≈{} dmg", ctx.stats.lethality.current))
+                    }
+                    SelectedAction::Ability(id) => match ctx.ability(id) {
+                        Some(a) => (a.name.clone(), ability_estimate(&a)),
+                        None => ("Ability".to_string(), String::new()),
+                    },
+                };
+                format!(
+                    "{what} → {target_name} {est}  ·  ←/→ cycle · Enter confirm · Esc cancel · or click an enemy"
+                )
+            }
+            HudMode::Idle => match state.open {
+                // Describe the focused option inside the open flyout.
+                Some(kind) => options_q
+                    .iter()
+                    .find(|o| o.flyout == kind && o.index == state.opt_focus)
+                    .map(|o| describe_action(o.action, &ctx, item_catalog.as_deref()))
+                    .unwrap_or_else(|| {
+                        "↑/↓ choose · Enter use · ←/Esc back".to_string()
+                    }),
+                // Describe the focused top-level category.
+                None => category_q
+                    .iter()
+                    .find(|c| c.index == state.cat_focus)
+                    .map(|c| describe_category(c.kind, &ctx))
+                    .unwrap_or_else(|| {
+                        "←/→ choose · Enter / ↑ open · click to act".to_string()
+                    }),
+            },
         }
-        HudMode::Idle => match state.open {
-            // Describe the focused option inside the open flyout.
-            Some(kind) => options_q
-                .iter()
-                .find(|o| o.flyout == kind && o.index == state.opt_focus)
-                .map(|o| describe_action(o.action, &ctx, item_catalog.as_deref()))
-                .unwrap_or_else(|| {
-                    "↑/↓ choose · Enter use · ←/Esc back".to_string()
-                }),
-            // Describe the focused top-level category.
-            None => category_q
-                .iter()
-                .find(|c| c.index == state.cat_focus)
-                .map(|c| describe_category(c.kind, &ctx))
-                .unwrap_or_else(|| {
-                    "←/→ choose · Enter / ↑ open · click to act".to_string()
-                }),
-        },
     };
 
     if hint.0 != desired {
@@ -1649,6 +1858,13 @@ fn describe_action(
                     crate::combat_plugin::ConsumableEffect::Heal { amount } => {
                         format!("{}: restore {amount} health.", d.name)
                     }
+                    crate::combat_plugin::ConsumableEffect::Regen {
+                        amount_per_turn,
+                        turns,
+                    } => format!(
+                        "{}: restore {amount_per_turn} health at the start of each of your next {turns} turns.",
+                        d.name
+                    ),
                 },
                 InventoryItemKind::Equipment(_) => format!("{}: equipment.", d.name),
             })
@@ -1844,4 +2060,58 @@ mod tests {
         assert!(techniques.matches(&technique));
         assert!(!techniques.matches(&magic));
     }
+
+    /// Bleeding (the poison-style DoT) for 4 more turns plus a Speed buff for
+    /// 2 more: two entries, each with its own countdown.
+    #[test]
+    fn snapshot_lists_dot_and_stat_buff_with_durations() {
+        use crate::combat_plugin::{Stat, StatModifier};
+        use crate::status_effects::{BadConditionKind, StatusInstance, StatusKind};
+
+        let mut app = App::new();
+        app.insert_resource(Timestamp(10))
+            .add_systems(Update, sync_hud_snapshot);
+        let bleeding = StatusKind::BadCondition(BadConditionKind::Bleeding);
+        let fighter = app
+            .world_mut()
+            .spawn((
+                BattleParticipant,
+                StatusEffects(vec![StatusInstance {
+                    kind: bleeding,
+                    tier: 2,
+                    expiry: Expiry::AtTimestamp(14),
+                    source: None,
+                    dot_counter: 0,
+                    resource_focus: None,
+                }]),
+                StatModifiers(vec![StatModifier {
+                    stat: Stat::Speed,
+                    multiplier: 1.25,
+                    expires_at_timestamp: Some(12),
+                    source: None,
+                    tag: None,
+                }]),
+            ))
+            .id();
+        app.update();
+
+        let snapshot = app.world().get::<HudSnapshot>(fighter).unwrap();
+        assert_eq!(
+            snapshot.statuses,
+            vec![
+                StatusIconEntry {
+                    icon_id: bleeding.icon_id(),
+                    stacks: 2,
+                    remaining_turns: Some(4),
+                    beneficial: false,
+                },
+                StatusIconEntry {
+                    icon_id: Stat::Speed.icon_id(true),
+                    stacks: 1,
+                    remaining_turns: Some(2),
+                    beneficial: true,
+                },
+            ]
+        );
+    }
 }
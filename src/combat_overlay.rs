@@ -24,6 +24,7 @@ use crate::combat_plugin::{
 use crate::core::{GameState, Game_State, MainCamera, Player, Position};
 use crate::pathfinding::reachable_tiles;
 use crate::quadtree::QuadTree;
+use crate::settings::CombatSpeed;
 use crate::status_effects::{StatusEffects, StatusKind};
 use crate::ui_style::{font_size, palette, radius, spacing};
 
@@ -825,9 +826,10 @@ fn spawn_damage_numbers(
 fn animate_damage_numbers(
     mut commands: Commands,
     time: Res<Time>,
+    speed: Option<Res<CombatSpeed>>,
     mut q: Query<(Entity, &mut FloatingNumber, &mut Node, &mut TextColor)>,
 ) {
-    let dt = time.delta_secs();
+    let dt = speed.as_deref().copied().unwrap_or_default().scale(time.delta_secs());
     for (e, mut fnum, mut node, mut color) in &mut q {
         fnum.age += dt;
         let t = (fnum.age / DMG_LIFETIME).clamp(0.0, 1.0);
@@ -1056,7 +1058,8 @@ fn record_battle_log(
         if ev.amount <= 0 {
             continue;
         }
-        log.push(format!("{} → {} for {}", name(ev.attacker), name(ev.target), ev.amount));
+        let source = ev.attacker.map(name).unwrap_or_else(|| "Hazard".to_string());
+        log.push(format!("{source} → {} for {}", name(ev.target), ev.amount));
     }
 }
 
@@ -26,6 +26,38 @@ const CRITICAL_HIT_FRACTION: f32 = 0.10;
 /// window. Stacks multiplicatively with weakness multipliers.
 const CRITICAL_HIT_DAMAGE_MULTIPLIER: f32 = 1.5;
 
+/// How far an attacker's morale tilts their own hit roll. Morale is read as a
+/// signed lean around the half-full mark: a full pool adds `hit_shift` to the
+/// hit chance and `crit_shift` to [`CRITICAL_HIT_FRACTION`], an empty one
+/// subtracts them, and half morale is neutral. Insert a different value to
+/// retune; without the resource the defaults apply.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct MoraleSwing {
+    pub hit_shift: f32,
+    pub crit_shift: f32,
+}
+
+impl Default for MoraleSwing {
+    fn default() -> Self {
+        Self {
+            hit_shift: 0.05,
+            crit_shift: 0.05,
+        }
+    }
+}
+
+impl MoraleSwing {
+    /// -1.0 at empty morale, 0.0 at half, +1.0 at full. Entities without a
+    /// morale pool (base 0) sit at neutral.
+    pub fn lean(morale: &StatPool<i32>) -> f32 {
+        if morale.base <= 0 {
+            return 0.0;
+        }
+        let fill = (morale.current as f32 / morale.base as f32).clamp(0.0, 1.0);
+        fill * 2.0 - 1.0
+    }
+}
+
 /// TO DO: Implement what the AI pointed out bellow
 /// One important note: the current turn flow still allows one committed action per turn. So AP now exists, is configurable per character, and is refilled correctly, but spending multiple actions inside a single turn is not implemented yet. If you want, I can do that next.
 /// One caveat: the combat runtime still does not spend ability magic costs at cast time, because that path was already not implemented before this change. The data model is ready for school-specific costs now, but the actual resource deduction logic is still the next step.
@@ -79,6 +111,18 @@ pub enum DamageType {
     True,
 }
 
+impl DamageType {
+    /// The defensive stat that soaks this kind of damage: armor for blows,
+    /// resist for fire and ice. True damage ignores both.
+    pub fn mitigated_by(self) -> Option<Stat> {
+        match self {
+            DamageType::Physical => Some(Stat::Armor),
+            DamageType::Fire | DamageType::Ice => Some(Stat::Resist),
+            DamageType::True => None,
+        }
+    }
+}
+
 /// A combatant's innate place on the 五行 Gogyō wheel (see [`crate::gogyo`]).
 ///
 /// Part of the *hybrid* elemental carrier: this is the unit's natural element
@@ -114,6 +158,20 @@ pub struct Attunement {
     pub expiry: u32,
 }
 
+/// A protector standing guard over an ally: attacks aimed at `target` land on
+/// `protector` instead, at [`GUARD_DAMAGE_TAKEN`] of their strength. Lives on
+/// the protector (one ward at a time); `tick_guard_system` counts it down at
+/// the end of each of the protector's turns.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Guard {
+    pub protector: Entity,
+    pub target: Entity,
+    pub remaining_turns: u8,
+}
+
+/// Fraction of an intercepted attack the guarding protector actually takes.
+pub const GUARD_DAMAGE_TAKEN: f32 = 0.75;
+
 /// Temporary **polarity flip** — while present, the combatant's In/Yō is
 /// inverted on every channel, until `expiry`. Presence = flipped (re-applying
 /// just refreshes the timer; it does not double-flip).
@@ -249,6 +307,8 @@ pub struct CombatStats {
     pub lethality: StatPool<i32>,
     pub hit: StatPool<i32>,
     pub armor: StatPool<i32>,
+    /// Soaks fire and ice the way `armor` soaks physical blows.
+    pub resist: StatPool<i32>,
     /// Drives turn-order accumulation and movement points per turn.
     pub speed: StatPool<i32>,
     /// Reduces the attacker's hit chance against this character.
@@ -278,6 +338,7 @@ impl Default for CombatStats {
             lethality: <StatPool<i32>>::new(0),
             hit: <StatPool<i32>>::new(0),
             armor: <StatPool<i32>>::new(0),
+            resist: <StatPool<i32>>::new(0),
             speed: <StatPool<i32>>::new(0),
             evasion: <StatPool<i32>>::new(0),
             mind: <StatPool<i32>>::new(0),
@@ -389,11 +450,22 @@ pub enum Stat {
     Speed,
     Evasion,
     Armor,
+    /// Magic resist: the elemental counterpart to `Armor`.
+    Resist,
     Mind,
     Morale,
 }
 
-fn get_stat_value(stat: Stat, combat_stats: Option<&CombatStats>) -> i32 {
+impl Stat {
+    /// Icon the HUD status bar draws for a timed modifier on this stat;
+    /// `raised` picks the up or down arrow variant.
+    pub fn icon_id(self, raised: bool) -> String {
+        let dir = if raised { "up" } else { "down" };
+        format!("icons/stat/{self:?}_{dir}")
+    }
+}
+
+pub(crate) fn get_stat_value(stat: Stat, combat_stats: Option<&CombatStats>) -> i32 {
     let Some(c) = combat_stats else { return 0 };
     match stat {
         Stat::Lethality => c.lethality.current,
@@ -401,6 +473,7 @@ fn get_stat_value(stat: Stat, combat_stats: Option<&CombatStats>) -> i32 {
         Stat::Speed => c.speed.current,
         Stat::Evasion => c.evasion.current,
         Stat::Armor => c.armor.current,
+        Stat::Resist => c.resist.current,
         Stat::Mind => c.mind.current,
         Stat::Morale => c.morale.current,
         Stat::Health => c.health.current,
@@ -451,6 +524,37 @@ pub struct GrowthAttributes {
     pub magic_distribution: MagicDistribution,
 }
 
+/// One of the nine [`GrowthAttributes`] a point can be spent on (the
+/// spirit-derived magic distribution is allocated separately).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Attribute {
+    Vitality,
+    Endurance,
+    Spirit,
+    Power,
+    Control,
+    Celerity,
+    Reflex,
+    Insight,
+    Resolve,
+}
+
+impl GrowthAttributes {
+    pub fn get_mut(&mut self, attribute: Attribute) -> &mut u8 {
+        match attribute {
+            Attribute::Vitality => &mut self.vitality,
+            Attribute::Endurance => &mut self.endurance,
+            Attribute::Spirit => &mut self.spirit,
+            Attribute::Power => &mut self.power,
+            Attribute::Control => &mut self.control,
+            Attribute::Celerity => &mut self.celerity,
+            Attribute::Reflex => &mut self.reflex,
+            Attribute::Insight => &mut self.insight,
+            Attribute::Resolve => &mut self.resolve,
+        }
+    }
+}
+
 /// Sub-allocation of spirit's derived distribution points, one count per
 /// magic school.
 #[derive(Debug, Default, Clone, Copy)]
@@ -722,7 +826,10 @@ impl DamageWeaknesses {
 
 #[derive(Debug, Clone)]
 pub struct QueuedDamage {
-    pub attacker: Entity,
+    /// `None` for damage with no one behind it (traps, hazards, falls); see
+    /// [`deal_environmental_damage`]. Carried through to
+    /// [`DeathEvent::killer`].
+    pub attacker: Option<Entity>,
     pub target: Entity,
     pub amount: i32,                 // Pre-defense damage (>= 0). Negative reserved for signals.
     pub damage_type: DamageType,
@@ -739,6 +846,8 @@ pub struct QueuedDamage {
 
     /// Defender-side stats to be used to reduce damage (stat, multiplier).
     /// e.g. vec![(Stat::Armor, 1.0)] means subtract defender.armor * 1.0 (scaled).
+    /// `Armor` and `Resist` both stand for "the defense this damage type
+    /// meets" and are swapped for [`DamageType::mitigated_by`] when applied.
     pub defended_with: Vec<(Stat, f32)>,
 
     /// Optional override: force accuracy (0.0..1.0)
@@ -756,11 +865,108 @@ pub struct QueuedDamage {
     /// `AfterHitEvent` so listeners (status reactors, equipment procs) can react
     /// based on origin and skip self-feedback.
     pub cause: ActionCause,
+
+    /// Resolution order within a frame; see [`DamagePriority`].
+    pub priority: DamagePriority,
+}
+
+/// Order in which `process_damage_queue_system` resolves a frame's entries.
+/// Lower resolves first; entries of equal priority keep their push order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DamagePriority {
+    /// A one-volley ward: `amount` is soaked off the target's later hits this
+    /// frame rather than dealt. Queue with [`queue_absorb`].
+    Absorb,
+    /// An ordinary hit.
+    #[default]
+    Raw,
 }
 
 #[derive(Resource, Default, Debug)]
 pub struct DamageQueue(pub Vec<QueuedDamage>);
 
+/// Ward `target` against up to `amount` of the damage queued for it this
+/// frame — an interposed guard, a last-moment barrier. Resolves before every
+/// raw hit in the frame whatever order they were pushed in; whatever the ward
+/// doesn't soak is lost at the end of the frame.
+pub fn queue_absorb(dq: &mut DamageQueue, source: Option<Entity>, target: Entity, amount: i32) {
+    dq.0.push(QueuedDamage {
+        attacker: source,
+        target,
+        amount: amount.max(0),
+        damage_type: DamageType::True,
+        element: None,
+        scaled_with: vec![],
+        defended_with: vec![],
+        accuracy_override: None,
+        crit_multiplier: 1.0,
+        tags: vec![],
+        cause: ActionCause::World,
+        priority: DamagePriority::Absorb,
+    });
+}
+
+/// Queue `amount` of `damage_type` against every target with no attacker —
+/// traps, burning ground, falls. The hits skip the hit roll and attacker
+/// scaling and ignore armor, but still go through incoming modifiers and
+/// weaknesses in `process_damage_queue_system`. A kill from one of these
+/// reports `DeathEvent::killer == None`, so nobody is credited with it.
+pub fn deal_environmental_damage(
+    dq: &mut DamageQueue,
+    targets: &[Entity],
+    amount: i32,
+    damage_type: DamageType,
+) {
+    for &target in targets {
+        dq.0.push(QueuedDamage {
+            attacker: None,
+            target,
+            amount: amount.max(0),
+            damage_type,
+            element: None,
+            scaled_with: vec![],
+            defended_with: vec![],
+            accuracy_override: None,
+            crit_multiplier: 1.0,
+            tags: vec![],
+            cause: ActionCause::World,
+            priority: DamagePriority::Raw,
+        });
+    }
+}
+
+/// Shared, seedable RNG for combat rolls (ability damage/heal ranges, ...).
+/// Seeded from the OS by default; tests and replays insert
+/// [`CombatRng::seeded`] so the same inputs produce the same outcomes.
+#[derive(Resource, Debug, Clone)]
+pub struct CombatRng(pub rand::rngs::StdRng);
+
+impl CombatRng {
+    pub fn seeded(seed: u64) -> Self {
+        Self(rand::SeedableRng::seed_from_u64(seed))
+    }
+}
+
+impl Default for CombatRng {
+    fn default() -> Self {
+        Self(rand::SeedableRng::from_os_rng())
+    }
+}
+
+/// `rng.gen_range(lo..hi)` that tolerates an empty or inverted range by
+/// returning `lo` instead of panicking. Every combat roll whose bounds come
+/// from data or tuning (ability ranges, turn-order jitter, weighted AI picks)
+/// goes through this, so a misconfigured `0` can't crash a fight.
+pub fn safe_range<T>(rng: &mut impl Rng, lo: T, hi: T) -> T
+where
+    T: rand::distr::uniform::SampleUniform + PartialOrd + Copy,
+{
+    if hi <= lo {
+        return lo;
+    }
+    rng.gen_range(lo..hi)
+}
+
 /// Abilities placeholder (extend later)
 #[derive(Component, Debug, Default)]
 pub struct Abilities(pub Vec<u16>);
@@ -1161,6 +1367,17 @@ pub struct BikuniBehavior; // Yuna — pilgrim's serenity: steadies resolve each
 #[derive(Component, Debug)]
 pub struct NecromancerBehavior; // Magatsu — grave-hunger: drains life from his blows
 
+/// Grief-rage: when a teammate (same `BattleSide`) dies, the bearer's `stat`
+/// is multiplied by `multiplier` for `turns` turns from the moment of the
+/// death (`None` = lasts until removed). Each death grants its own modifier,
+/// so losing two allies stacks twice.
+#[derive(Component, Debug, Clone)]
+pub struct Rage {
+    pub stat: Stat,
+    pub multiplier: f32,
+    pub turns: Option<u32>,
+}
+
 /// Equipment entity
 
 #[derive(Component, Debug, Clone, Serialize, Deserialize)]
@@ -1272,15 +1489,33 @@ pub struct StatModifier {
     pub multiplier: f32, // multiplicative (e.g., 1.2 => +20%)
     pub expires_at_timestamp: Option<u32>, // None => permanent until explicitly removed
     pub source: Option<Entity>,
+    /// Which grant this is, for modifiers that ride an attack's
+    /// `AttackContext::multipliers` as well as being persisted here.
+    pub tag: Option<ModifierTag>,
+}
+
+/// Identity of one modifier grant, so the damage pipeline can tell an
+/// in-flight modifier it was handed from the same grant already persisted
+/// onto the attacker, rather than from any modifier on the same stat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModifierTag {
+    /// The `effect`-th effect of ability `ability`, cast at timestamp `at`.
+    Cast { ability: u16, effect: usize, at: u32 },
+    /// A before-attack hook on `item`, fired at timestamp `at`.
+    Equipment { item: Entity, at: u32 },
 }
 
 /// Simple experience / level component (placeholder)
 #[derive(Component, Debug)]
 pub struct Experience(pub u32);
 
+/// A character's level, never above [`MAX_CHARACTER_LEVEL`].
 #[derive(Component, Debug)]
 pub struct Level(pub u32);
 
+/// Highest level a character can reach; the growth tuning tables run to 500.
+pub const MAX_CHARACTER_LEVEL: u16 = 500;
+
 #[derive(Component, Debug)]
 pub struct AccumulatedSpeed(pub u32);
 
@@ -1346,6 +1581,10 @@ pub enum TargetFocus {
     HighestHp,
     Closest,
     Furthest,
+    /// Coordinate with packmates: focus wounded targets (weighted by
+    /// aggressiveness), flank targets packmates are already engaging, and
+    /// avoid bunching up on the same side of a target.
+    Pack,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -1449,7 +1688,7 @@ pub struct BeforeHitEvent {
 
 #[derive(Debug, Clone, Message)]
 pub struct DamageEvent {
-    pub attacker: Entity,
+    pub attacker: Option<Entity>,
     pub target: Entity,
     pub amount: i32,
     pub damage_type: DamageType,
@@ -1485,6 +1724,25 @@ pub struct DrainMoraleEvent {
     pub cause: ActionCause,
 }
 
+/// Request to burn a target's magic or morale. Emitted by
+/// [`crate::combat_ability::handle_ability`] for
+/// [`crate::combat_ability::AbilityEffect::ResourceDrain`] and applied by
+/// `apply_resource_drain_system`: magic comes out of the target's `school`
+/// pool, and with `transfer` whatever was actually taken (never more than the
+/// target had) is restored to the drainer's matching pool.
+#[derive(Debug, Clone, Message)]
+pub struct DrainResourceEvent {
+    pub drainer: Entity,
+    pub target: Entity,
+    pub resource: crate::status_effects::ResourceKind,
+    /// School of the casting ability; picks the pool a magic drain burns.
+    pub school: MagicSchool,
+    /// Rolled from the effect's `floor..ceiling`.
+    pub amount: u32,
+    pub transfer: bool,
+    pub cause: ActionCause,
+}
+
 #[derive(Debug, Clone, Message)]
 pub struct ApplyBuffEvent {
     pub applier: Entity,
@@ -1499,6 +1757,8 @@ pub struct ApplyBuffEvent {
     /// generates the target's effective phase. `None` = neutral.
     pub element: Option<crate::gogyo::Element>,
     pub cause: ActionCause,
+    /// Carried onto the persisted [`StatModifier`]; see [`ModifierTag`].
+    pub tag: Option<ModifierTag>,
 }
 
 /// Request to temporarily **attune** a target to a phase on the Gogyō wheel
@@ -1524,9 +1784,76 @@ pub struct ApplyPolarityFlipEvent {
     pub source: Option<Entity>,
 }
 
+/// Request for `protector` to stand [`Guard`] over `target` for `turns` of
+/// the protector's turns. Applied by `apply_guard_system`; replaces any guard
+/// the protector already holds.
+#[derive(Debug, Clone, Message)]
+pub struct ApplyGuardEvent {
+    pub protector: Entity,
+    pub target: Entity,
+    pub turns: u8,
+}
+
+/// Request to lay down an [`AbilityEffect::Zone`]: `shape` anchored between
+/// `caster` and `target`, doing `effect` for `turns` rounds. Spawned by
+/// `crate::battle::spawn_zone_system`.
+#[derive(Debug, Clone, Message)]
+pub struct SpawnZoneEvent {
+    pub caster: Entity,
+    pub target: Entity,
+    pub shape: AbilityShape,
+    pub effect: ZoneEffect,
+    pub turns: u8,
+}
+
+/// Request to bring `target` back from `Dead` with `hp_fraction` of their
+/// max health; see [`AbilityEffect::Revive`]. Applied by
+/// `apply_revive_system`.
+#[derive(Debug, Clone, Message)]
+pub struct ReviveEvent {
+    pub reviver: Entity,
+    pub target: Entity,
+    pub hp_fraction: f32,
+}
+
+/// Why a [`ReviveEvent`] didn't take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviveRejection {
+    /// The target is still standing.
+    NotDead,
+    /// The target is `PermanentlyDead`; nothing brings them back.
+    BeyondSaving,
+}
+
+/// Whether a revive may land on a target in this state.
+pub fn check_revive_target(dead: bool, permanently_dead: bool) -> Result<(), ReviveRejection> {
+    if permanently_dead {
+        Err(ReviveRejection::BeyondSaving)
+    } else if !dead {
+        Err(ReviveRejection::NotDead)
+    } else {
+        Ok(())
+    }
+}
+
+/// [`check_revive_target`] for casting `ability` on a target in this state, so
+/// a misaimed revive is refused before its cost is paid. Abilities without an
+/// [`AbilityEffect::Revive`] take any target.
+pub fn check_ability_target(
+    ability: &Ability,
+    dead: bool,
+    permanently_dead: bool,
+) -> Result<(), ReviveRejection> {
+    if ability.effects.iter().any(|e| matches!(e, AbilityEffect::Revive { .. })) {
+        check_revive_target(dead, permanently_dead)
+    } else {
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Message)]
 pub struct AfterHitEvent {
-    pub attacker: Entity,
+    pub attacker: Option<Entity>,
     pub target: Entity,
     pub amount: i32,
     pub damage_type: DamageType,
@@ -1553,8 +1880,8 @@ pub struct IncomingDamageEvent {
 #[derive(Debug, Clone, Message)]
 pub struct LevelUpEvent {
     pub who: Entity,
-    pub old_level: u8,
-    pub new_level: u8,
+    pub old_level: u16,
+    pub new_level: u16,
 }
 
 /// Turn & timeline events
@@ -1584,6 +1911,22 @@ pub struct RespecEvent {
     pub refund_all_points: bool, // if true: gives player all their spent points back
 }
 
+/// Request to put one of `who`'s available attribute points into `attribute`.
+#[derive(Debug, Clone, Message)]
+pub struct SpendAttributePointEvent {
+    pub who: Entity,
+    pub attribute: Attribute,
+}
+
+/// `who`'s [`AttributePointPool`] after a spend, refund or level-up grant, so
+/// UI can repaint without polling the component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Message)]
+pub struct AttributePointsChangedEvent {
+    pub who: Entity,
+    pub available: u32,
+    pub spent: u32,
+}
+
 #[derive(Debug, Clone, Component)]
 pub struct InCombat;
 
@@ -1855,7 +2198,7 @@ fn evaluate_when_ally_damaged_reactions_system(
                     reactor,
                     trigger: r.trigger,
                     ability_id: r.ability_id,
-                    catalyst: Some(ev.attacker),
+                    catalyst: ev.attacker,
                 });
                 break;
             }
@@ -1942,6 +2285,10 @@ pub enum ItemUseTrigger {
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum ConsumableEffect {
     Heal { amount: u32 },
+    /// Health over time: grants a
+    /// [`RegenBuff`](crate::status_effects::RegenBuff) instead of an
+    /// immediate heal.
+    Regen { amount_per_turn: u32, turns: u8 },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1991,6 +2338,21 @@ impl Default for InventoryItemCatalog {
                 },
             },
         );
+        items.insert(
+            1003,
+            InventoryItemDefinition {
+                id: 1003,
+                name: "Kampo Draught".to_string(),
+                kind: InventoryItemKind::Consumable {
+                    effect: ConsumableEffect::Regen {
+                        amount_per_turn: 8,
+                        turns: 3,
+                    },
+                    usable_on_others: true,
+                    usable_pre_death: false,
+                },
+            },
+        );
         Self(items)
     }
 }
@@ -2069,6 +2431,59 @@ pub struct PlayerActionEvent {
     pub action: PlayerAction,
 }
 
+/// Optional confirm step for player turns. While `enabled`, the HUD stages
+/// its choice here (via [`StageActionEvent`]) instead of sending it straight
+/// to [`PlayerActionEvent`]; nothing is spent or applied until a
+/// [`ConfirmStagedActionEvent`], and an [`UndoStagedActionEvent`] drops the
+/// choice so the player can pick again. A staged action never outlives the
+/// turn it was chosen in.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct ActionStaging {
+    pub enabled: bool,
+    /// The turn owner and the action they picked, awaiting confirmation.
+    pub staged: Option<(Entity, PlayerAction)>,
+}
+
+impl ActionStaging {
+    pub fn staged_action(&self) -> Option<&PlayerAction> {
+        self.staged.as_ref().map(|(_, action)| action)
+    }
+}
+
+/// Stage `action` for the current player turn, replacing any earlier pick.
+#[derive(Debug, Clone, Message)]
+pub struct StageActionEvent {
+    pub action: PlayerAction,
+}
+
+/// Commit the staged action: it is sent on as a [`PlayerActionEvent`].
+#[derive(Debug, Clone, Message)]
+pub struct ConfirmStagedActionEvent;
+
+/// Drop the staged action without applying it; the turn stays open.
+#[derive(Debug, Clone, Message)]
+pub struct UndoStagedActionEvent;
+
+/// Why a `UseAbility` was refused before any cost was paid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbilityFailReason {
+    /// The id isn't in the [`Ability_Tree`] (or the tree isn't loaded).
+    Unknown,
+    /// The ability exists but isn't in the actor's [`Abilities`].
+    NotLearned,
+    /// A revive aimed at someone it can't raise.
+    BadReviveTarget(ReviveRejection),
+}
+
+/// Emitted when [`PlayerAction::UseAbility`] names an ability the actor can't
+/// use. The turn stays with the actor so the UI can report it and re-prompt.
+#[derive(Debug, Clone, Message)]
+pub struct AbilityFailedEvent {
+    pub actor: Entity,
+    pub ability_id: u32,
+    pub reason: AbilityFailReason,
+}
+
 #[derive(Debug, Clone, Message)]
 pub struct DeathEvent {
     pub entity: Entity,
@@ -2098,12 +2513,12 @@ pub trait DeathBehavior: Send + Sync + 'static {
         commands: &mut Commands,
         loot_writer: &mut MessageWriter<LootEvent>,
         xp_writer: &mut MessageWriter<AwardXpEvent>,
+        experience: &Query<&Experience>,
         tm: &mut TurnManager,
     );
 }
 
 pub struct EnemyDeathBehavior {
-    pub xp_reward: u32,
     pub loot_table: Vec<LootItem>,
 }
 
@@ -2115,6 +2530,7 @@ impl DeathBehavior for EnemyDeathBehavior {
         commands: &mut Commands,
         loot_writer: &mut MessageWriter<LootEvent>,
         xp_writer: &mut MessageWriter<AwardXpEvent>,
+        experience: &Query<&Experience>,
         tm: &mut TurnManager,
     ) {
         // Remove from combat
@@ -2126,12 +2542,9 @@ impl DeathBehavior for EnemyDeathBehavior {
             dropped_by: entity,
         });
 
-        // Award XP to killer if exists
+        // Award XP to killer if exists, weighed by how seasoned each side is
         if let Some(killer) = killer {
-            xp_writer.write(AwardXpEvent {
-                recipient: killer,
-                amount: self.xp_reward,
-            });
+            xp_writer.write(kill_xp_award(killer, entity, experience));
         }
 
         // Optional: despawn corpse or mark dead
@@ -2147,6 +2560,7 @@ impl DeathBehavior for AllyDeathBehavior {
         commands: &mut Commands,
         _loot_writer: &mut MessageWriter<LootEvent>,
         _xp_writer: &mut MessageWriter<AwardXpEvent>,
+        _experience: &Query<&Experience>,
         tm: &mut TurnManager,
     ) {
         // Remove from turn order
@@ -2160,20 +2574,81 @@ impl DeathBehavior for AllyDeathBehavior {
     }
 }
 
-fn award_xp_system(
+/// XP for a kill against an evenly matched foe.
+pub const BASE_KILL_XP: u32 = 100;
+/// Added to both sides of the experience ratio so fresh characters (0 XP)
+/// don't divide by zero or swing the award wildly.
+const XP_RATIO_FLOOR: f32 = 1.0 + (1 << 16) as f32;
+
+/// `Experience` of a character who has just reached `level`: the level lives
+/// in the high bits, which is how `award_xp_system` reads it back.
+pub fn experience_at_level(level: u32) -> u32 {
+    level << 16
+}
+
+/// The level `xp` has earned, capped at [`MAX_CHARACTER_LEVEL`].
+pub fn level_from_experience(xp: u32) -> u16 {
+    (xp >> 16).min(u32::from(MAX_CHARACTER_LEVEL)) as u16
+}
+
+/// XP a character with `receiver_xp` earns for felling a foe with `enemy_xp`:
+/// [`BASE_KILL_XP`] scaled by how much more (or less) seasoned the foe is,
+/// between a quarter and four times the base.
+pub fn calculate_xp_award(receiver_xp: u32, enemy_xp: u32) -> u32 {
+    let ratio = (enemy_xp as f32 + XP_RATIO_FLOOR) / (receiver_xp as f32 + XP_RATIO_FLOOR);
+    (BASE_KILL_XP as f32 * ratio.clamp(0.25, 4.0)).round() as u32
+}
+
+/// The [`AwardXpEvent`] `killer` earns for felling `victim`, from the
+/// `Experience` each carries (none counts as a fresh character).
+fn kill_xp_award(killer: Entity, victim: Entity, experience: &Query<&Experience>) -> AwardXpEvent {
+    let xp_of = |e: Entity| experience.get(e).map_or(0, |xp| xp.0);
+    AwardXpEvent { recipient: killer, amount: calculate_xp_award(xp_of(killer), xp_of(victim)) }
+}
+
+/// Credits the party member who landed a killing blow on an enemy. Runs right
+/// after `apply_damage_system` writes the [`DeathEvent`], while the victim is
+/// still around to read its `Experience` (`end_battle_on_death` despawns it
+/// through deferred commands). Deaths with no killer (bleed-out, hazards) and
+/// enemies felled by their own side pay nothing.
+pub(crate) fn award_kill_xp_system(
+    mut deaths: MessageReader<DeathEvent>,
+    sides: Query<&crate::battle::BattleSide>,
+    experience: Query<&Experience>,
+    mut xp_writer: MessageWriter<AwardXpEvent>,
+) {
+    for ev in deaths.read() {
+        let Some(killer) = ev.killer else {
+            continue;
+        };
+        let enemy_felled = matches!(sides.get(ev.entity), Ok(crate::battle::BattleSide::Enemy));
+        let by_the_party = matches!(sides.get(killer), Ok(crate::battle::BattleSide::Ally));
+        if !enemy_felled || !by_the_party {
+            continue;
+        }
+        xp_writer.write(kill_xp_award(killer, ev.entity, &experience));
+    }
+}
+
+pub(crate) fn award_xp_system(
     mut events: MessageReader<AwardXpEvent>,
     mut events_level: MessageWriter<LevelUpEvent>,
     mut query: Query<(&mut Experience, &mut Level)>,
 ) {
     for evt in events.read() {
-        if let Ok((mut xp, lvl)) = query.get_mut(evt.recipient) {
-            xp.0 += evt.amount;
-            // Levels are capped at MAX_LEVEL (30); the high bits of `xp` encode
-            // the raw level, so clamp before it ever leaves this system.
-            let new_level = ((xp.0 >> 16) as u8).min(crate::combat_ability::MAX_LEVEL);
+        if let Ok((mut xp, mut lvl)) = query.get_mut(evt.recipient) {
+            xp.0 = xp.0.saturating_add(evt.amount);
+            // The high bits of `xp` encode the raw level; clamp it to the cap
+            // before it ever leaves this system.
+            let new_level = level_from_experience(xp.0);
+            let old_level = lvl.0.min(u32::from(MAX_CHARACTER_LEVEL)) as u16;
+            if new_level <= old_level {
+                continue;
+            }
+            lvl.0 = u32::from(new_level);
             events_level.write(LevelUpEvent {
                 who: evt.recipient,
-                old_level: lvl.0 as u8,
+                old_level,
                 new_level,
             });
         }
@@ -2302,6 +2777,50 @@ fn cleric_blessing_system(
     }
 }
 
+/// Grant every living [`Rage`] bearer on a fallen combatant's side their
+/// grief-rage modifier.
+fn rage_on_ally_death_system(
+    mut commands: Commands,
+    mut deaths: MessageReader<DeathEvent>,
+    timestamp: Res<Timestamp>,
+    sides_q: Query<&crate::battle::BattleSide>,
+    mut ragers: Query<(
+        Entity,
+        &crate::battle::BattleSide,
+        &Rage,
+        &CombatStats,
+        Option<&mut StatModifiers>,
+    )>,
+) {
+    // Bearers without a `StatModifiers` yet get one insert with every modifier
+    // this frame's deaths granted, so a second death can't overwrite the first.
+    let mut fresh: HashMap<Entity, Vec<StatModifier>> = HashMap::new();
+    for ev in deaths.read() {
+        let Ok(fallen_side) = sides_q.get(ev.entity) else {
+            continue;
+        };
+        for (e, side, rage, stats, modifiers) in ragers.iter_mut() {
+            if e == ev.entity || side != fallen_side || stats.health.current <= 0 {
+                continue;
+            }
+            let modifier = StatModifier {
+                stat: rage.stat,
+                multiplier: rage.multiplier,
+                expires_at_timestamp: rage.turns.map(|turns| timestamp.0.saturating_add(turns)),
+                source: Some(ev.entity),
+                tag: None,
+            };
+            match modifiers {
+                Some(mut modifiers) => modifiers.0.push(modifier),
+                None => fresh.entry(e).or_default().push(modifier),
+            }
+        }
+    }
+    for (e, modifiers) in fresh {
+        commands.entity(e).insert(StatModifiers(modifiers));
+    }
+}
+
 /// Per-turn sustain passives: Renjiro's breath control restores Kiho, Suzuka's
 /// ritual craft restores Onmyodo, and Yuna's pilgrim serenity steadies resolve
 /// (morale). One marker each → one distinct reserve.
@@ -2338,16 +2857,19 @@ fn necromancer_lifesteal_system(
     mut heal_writer: MessageWriter<HealEvent>,
 ) {
     for ev in hits.read() {
-        if ev.amount <= 0 || necromancers.get(ev.attacker).is_err() {
+        let Some(attacker) = ev.attacker else {
+            continue;
+        };
+        if ev.amount <= 0 || necromancers.get(attacker).is_err() {
             continue;
         }
         let drained = (ev.amount / 3).max(1) as u32;
         heal_writer.write(HealEvent {
-            healer: ev.attacker,
-            target: ev.attacker,
+            healer: attacker,
+            target: attacker,
             amount: drained,
             element: None,
-            cause: ActionCause::Passive { source: ev.attacker },
+            cause: ActionCause::Passive { source: attacker },
         });
     }
 }
@@ -2358,15 +2880,17 @@ fn necromancer_lifesteal_system(
 /// -----------------------------
 
 /// Generic equipment system: reacts to BeforeAttackEvent and applies stat modifiers when equipment has matching hooks.
+/// The modifier also goes on the event's context, so it counts for the attack
+/// that triggered it even while the `StatModifiers` insert is still deferred.
 fn equipment_before_attack_listener(
-    mut befores: MessageReader<BeforeAttackEvent>,
+    mut befores: MessageMutator<BeforeAttackEvent>,
     equipment_q: Query<(Entity, &Equipment, &EquipmentHooks)>,
     loadout_q: Query<&EquipmentLoadout>,
     mut commands: Commands,
     mut modifiers_q: Query<&mut StatModifiers>,
     timestamp: Res<Timestamp>,
 ) {
-    for ev in befores.iter() {
+    for ev in befores.read() {
         if let Ok(loadout) = loadout_q.get(ev.attacker) {
             for equipped_item in loadout.equipped_items() {
                 if let Ok((equip_entity, _equip, hooks)) = equipment_q.get(equipped_item) {
@@ -2384,7 +2908,12 @@ fn equipment_before_attack_listener(
                                         timestamp.0.saturating_add(*duration_turns),
                                     ),
                                     source: Some(equip_entity),
+                                    tag: Some(ModifierTag::Equipment {
+                                        item: equip_entity,
+                                        at: timestamp.0,
+                                    }),
                                 };
+                                ev.context.multipliers.push(modifier.clone());
 
                                 if let Ok(mut modifiers) = modifiers_q.get_mut(ev.attacker) {
                                     modifiers.0.push(modifier);
@@ -2454,6 +2983,10 @@ fn weapon_before_attack_effect_system(
                                 timestamp.0.saturating_add(*duration_turns),
                             ),
                             source: Some(weapon_entity),
+                            tag: Some(ModifierTag::Equipment {
+                                item: weapon_entity,
+                                at: timestamp.0,
+                            }),
                         };
 
                         if let Some(existing) = ev
@@ -2584,17 +3117,32 @@ fn before_hit_listeners(
 //     }
 // }
 
-/// Process AttackIntentEvent -> send BeforeAttackEvent
+/// Process AttackIntentEvent -> send BeforeAttackEvent.
+///
+/// Seeds an unset `base_lethality` / `base_hit` from the attacker's current
+/// stats, so the before-attack listeners (paladin, rogue, samurai, gear) add
+/// to and scale the real numbers, and `queue_damage_from_before_attack` then
+/// takes the context as the single source for the hit.
 fn process_attack_intent(
     mut intents: MessageReader<AttackIntentEvent>,
     mut before_attacks: MessageWriter<BeforeAttackEvent>,
+    stats_q: Query<&CombatStats>,
 ) {
     for intent in intents.iter() {
+        let mut context = intent.context.clone();
+        if let Ok(stats) = stats_q.get(intent.attacker) {
+            if context.base_lethality == 0 {
+                context.base_lethality = stats.lethality.current;
+            }
+            if context.base_hit == 0 {
+                context.base_hit = stats.hit.current;
+            }
+        }
         before_attacks.send(BeforeAttackEvent {
             attacker: intent.attacker,
             target: intent.target,
             ability: intent.ability.clone(),
-            context: intent.context.clone(),
+            context,
             cause: intent.cause.clone(),
         });
     }
@@ -2625,55 +3173,79 @@ fn forfeit_turn_on_status_system(
     }
 }
 
+/// Roll a confused actor's retarget. With probability `chance` returns a
+/// uniformly random entry of `candidates` other than the actor and its
+/// `intended` target (so an ally can be hit); `None` keeps the original target.
+pub fn roll_confused_retarget(
+    actor: Entity,
+    intended: Entity,
+    chance: f32,
+    candidates: &[Entity],
+    rng: &mut impl Rng,
+) -> Option<Entity> {
+    if chance <= 0.0 || safe_range(rng, 0.0_f32, 1.0) >= chance {
+        return None;
+    }
+    let pool: Vec<Entity> = candidates
+        .iter()
+        .copied()
+        .filter(|&e| e != actor && e != intended)
+        .collect();
+    if pool.is_empty() {
+        return None;
+    }
+    Some(pool[safe_range(rng, 0, pool.len())])
+}
+
+/// Every living battle participant, in a stable order so a seeded
+/// [`CombatRng`] picks the same retarget on replay.
+fn confusion_candidates<'a>(
+    participants: impl Iterator<Item = (Entity, &'a CombatStats)>,
+) -> Vec<Entity> {
+    let mut out: Vec<Entity> = participants
+        .filter(|(_, stats)| stats.health.current > 0)
+        .map(|(e, _)| e)
+        .collect();
+    out.sort();
+    out
+}
+
 /// `BeforeAttackEvent` mutator that applies any `ActionGates`-driven retarget
-/// to the attack before damage is queued. Today only Confused triggers
-/// retargeting (rolled probability picks the attacker's nearest ally as the
-/// new target); future overrides that change *who* gets hit can plug into
-/// `ActionGates` and reuse this system without forking it.
+/// to the attack before damage is queued. Today only confusion (the tiered
+/// status or an explicit [`crate::status_effects::Confused`]) triggers
+/// retargeting: the rolled probability swaps the target for a random other
+/// combatant. Ability attacks were already retargeted at cast time, so they are
+/// skipped here rather than rolled twice. Future overrides that change *who*
+/// gets hit can plug into `ActionGates` and reuse this system without forking it.
 fn apply_retarget_overrides_system(
     mut events: MessageMutator<BeforeAttackEvent>,
     status_q: Query<&crate::status_effects::StatusEffects>,
-    transform_q: Query<&Transform>,
-    sides_iter_q: Query<(Entity, &crate::battle::BattleSide)>,
+    confused_q: Query<&crate::status_effects::Confused>,
+    participants_q: Query<(Entity, &CombatStats), With<crate::battle::BattleParticipant>>,
+    mut rng: ResMut<CombatRng>,
 ) {
     for ev in events.iter_mut() {
-        let gates = crate::status_effects::action_gates(status_q.get(ev.attacker).ok());
-        if gates.confused_retarget_chance <= 0.0
-            || rand::random::<f32>() >= gates.confused_retarget_chance
-        {
+        if ev.ability.is_some() {
             continue;
         }
-
-        let Ok(my_tf) = transform_q.get(ev.attacker) else {
-            continue;
-        };
-        let Ok((_, my_side)) = sides_iter_q.get(ev.attacker) else {
+        let gates = crate::status_effects::action_gates(status_q.get(ev.attacker).ok());
+        let chance =
+            crate::status_effects::retarget_chance(&gates, confused_q.get(ev.attacker).ok());
+        if chance <= 0.0 {
             continue;
-        };
-        let mut best: Option<(Entity, f32)> = None;
-        for (other, side) in sides_iter_q.iter() {
-            if other == ev.attacker || side != my_side {
-                continue;
-            }
-            let Ok(tf) = transform_q.get(other) else {
-                continue;
-            };
-            let d = tf.translation.distance_squared(my_tf.translation);
-            match best {
-                None => best = Some((other, d)),
-                Some((_, b)) if d < b => best = Some((other, d)),
-                _ => {}
-            }
         }
-        if let Some((ally, _)) = best {
+        let candidates = confusion_candidates(participants_q.iter());
+        if let Some(new_target) =
+            roll_confused_retarget(ev.attacker, ev.target, chance, &candidates, &mut rng.0)
+        {
             info!(
-                "Confused retarget ({}%): {:?} now attacks ally {:?} instead of {:?}",
-                (gates.confused_retarget_chance * 100.0) as u8,
+                "Confused retarget ({}%): {:?} now attacks {:?} instead of {:?}",
+                (chance * 100.0) as u8,
                 ev.attacker,
-                ally,
+                new_target,
                 ev.target,
             );
-            ev.target = ally;
+            ev.target = new_target;
         }
     }
 }
@@ -2689,14 +3261,18 @@ fn queue_damage_from_before_attack(
     sharpness_q: Query<&WeaponSharpness>,
     status_q: Query<&crate::status_effects::StatusEffects>,
     sides_q: Query<(Entity, &crate::battle::BattleSide)>,
+    mut rng: ResMut<CombatRng>,
+    morale_swing: Option<Res<MoraleSwing>>,
 ) {
+    let swing = morale_swing.map(|s| *s).unwrap_or_default();
     for ev in befores.iter() {
         let attacker = ev.attacker;
         let target = ev.target;
 
         let att_stats = stats_q.get(attacker).ok();
-        // Read `current` rather than `base`: status effects and (future) buffs
-        // already feed into `current` via the recompute pass.
+        // `process_attack_intent` seeded these from the attacker's `current`
+        // stats and the before-attack listeners have adjusted them since; the
+        // fallback only covers events written straight onto this stage.
         let mut base_leth = ev.context.base_lethality;
         if base_leth == 0 {
             base_leth = att_stats.map(|s| s.lethality.current).unwrap_or(0);
@@ -2720,6 +3296,27 @@ fn queue_damage_from_before_attack(
                 }
             }
         }
+        // Buffs granted earlier in the same cast (see `CastContext`) that
+        // `apply_buff_system` hasn't persisted onto the attacker yet. Only the
+        // same grant counts as persisted; an untagged one always applies.
+        for m in &ev.context.multipliers {
+            let persisted = m.tag.is_some()
+                && modifiers_q
+                    .get(attacker)
+                    .is_ok_and(|mods| mods.0.iter().any(|p| p.tag == m.tag));
+            if persisted {
+                continue;
+            }
+            match m.stat {
+                Stat::Lethality => {
+                    base_leth = ((base_leth as f32) * m.multiplier).round() as i32;
+                }
+                Stat::Hit => {
+                    base_hit = ((base_hit as f32) * m.multiplier).round() as i32;
+                }
+                _ => {}
+            }
+        }
 
         // Hit-chance shifts from status (Unfocused on attacker; Unlucky and
         // Crippled Defense on target). Lethality/hit multipliers are already
@@ -2732,17 +3329,23 @@ fn queue_damage_from_before_attack(
 
         let mut scaled_with: Vec<(Stat, f32)> = Vec::new();
         let mut defended_with: Vec<(Stat, f32)> = Vec::new();
+        let mut ability_tags: Vec<DamageTag> = Vec::new();
 
         if let Some(ability) = ev.ability.as_ref() {
+            ability_tags.push(DamageTag::FromAbility(ability.id));
             for eff in &ability.effects {
                 match eff {
                     AbilityEffect::Damage {
                         scaled_with: sw,
                         defended_with: dw,
+                        amplify_low_morale,
                         ..
                     } => {
                         scaled_with.push((*sw, 1.0));
                         defended_with.push((*dw, 1.0));
+                        if *amplify_low_morale > 0.0 {
+                            ability_tags.push(DamageTag::AmplifyLowMorale(*amplify_low_morale));
+                        }
                     }
                     AbilityEffect::Heal { .. }
                     | AbilityEffect::DrainMorale { .. }
@@ -2751,7 +3354,15 @@ fn queue_damage_from_before_attack(
                     | AbilityEffect::RemoveStatus { .. }
                     | AbilityEffect::Summon { .. }
                     | AbilityEffect::Attune { .. }
-                    | AbilityEffect::FlipPolarity { .. } => {}
+                    | AbilityEffect::FlipPolarity { .. }
+                    | AbilityEffect::Regen { .. }
+                    | AbilityEffect::ResourceDrain { .. }
+                    | AbilityEffect::Guard { .. }
+                    | AbilityEffect::Zone { .. }
+                    | AbilityEffect::Revive { .. }
+                    | AbilityEffect::SkillCheck { .. }
+                    | AbilityEffect::Reveal { .. }
+                    | AbilityEffect::SetFlag { .. } => {}
                 }
             }
         }
@@ -2818,16 +3429,25 @@ fn queue_damage_from_before_attack(
         let luck_shift =
             crate::status_effects::lucky_unlucky_shift(attacker, target, &sides_q, &status_q);
 
+        // Morale cuts both ways: a confident attacker lands more and crits
+        // more, a shaken one fumbles.
+        let morale_lean = att_stats.map(|s| MoraleSwing::lean(&s.morale)).unwrap_or(0.0);
+
         chance = (chance
             + outgoing.hit_chance_shift
             + incoming_for_hit.attacker_hit_chance_shift
-            + luck_shift)
+            + luck_shift
+            + swing.hit_shift * morale_lean)
             .clamp(0.0, 1.0);
+        let crit_fraction =
+            (CRITICAL_HIT_FRACTION + swing.crit_shift * morale_lean).clamp(0.0, 1.0);
 
-        let roll = rand::random::<f32>();
+        // One roll per BeforeAttackEvent — i.e. per target — so an area cast
+        // resolves each victim's hit/dodge/crit independently.
+        let roll = rng.0.random::<f32>();
         if roll > chance {
             dq.0.push(QueuedDamage {
-                attacker,
+                attacker: Some(attacker),
                 target,
                 amount: DamageSignal::Miss as i32,
                 damage_type: ev.context.damage_type.unwrap_or(DamageType::Physical),
@@ -2838,6 +3458,7 @@ fn queue_damage_from_before_attack(
                 crit_multiplier: 1.0,
                 tags: vec![],
                 cause: ev.cause.clone(),
+                priority: DamagePriority::Raw,
             });
             continue;
         }
@@ -2845,14 +3466,15 @@ fn queue_damage_from_before_attack(
         // Critical hit: roll landed in the top fraction of the hit window —
         // a "barely landed" lucky shot. Crit damage stacks multiplicatively
         // with weakness in `process_damage_queue_system`.
-        let (crit_multiplier, tags) = if roll >= chance * (1.0 - CRITICAL_HIT_FRACTION) {
+        let (crit_multiplier, mut tags) = if roll >= chance * (1.0 - crit_fraction) {
             (CRITICAL_HIT_DAMAGE_MULTIPLIER, vec![DamageTag::Critical])
         } else {
             (1.0, Vec::new())
         };
+        tags.extend(ability_tags);
 
         dq.0.push(QueuedDamage {
-            attacker,
+            attacker: Some(attacker),
             target,
             amount: pre_def_damage,
             damage_type: ev.context.damage_type.unwrap_or(DamageType::Physical),
@@ -2865,6 +3487,7 @@ fn queue_damage_from_before_attack(
             crit_multiplier,
             tags,
             cause: ev.cause.clone(),
+            priority: DamagePriority::Raw,
         });
     }
 }
@@ -2918,10 +3541,21 @@ fn process_damage_queue_system(
     affinity_q: Query<&ElementalAffinity>,
     attune_q: Query<&Attunement>,
     flip_q: Query<(), With<PolarityFlip>>,
+    guards_q: Query<&Guard>,
     mut damage_writer: MessageWriter<DamageEvent>,
     mut status_writer: MessageWriter<crate::status_effects::ApplyStatusEvent>,
 ) {
+    // Wards resolve before raw hits regardless of push order. The sort is
+    // stable, so entries of equal priority still resolve in push order.
+    dq.0.sort_by_key(|entry| entry.priority);
+    let mut wards: HashMap<Entity, i32> = HashMap::new();
+
     for mut entry in dq.0.drain(..) {
+        if entry.priority == DamagePriority::Absorb {
+            *wards.entry(entry.target).or_default() += entry.amount.max(0);
+            continue;
+        }
+
         // SPECIAL NEGATIVE VALUES -------------------------------------------
         match entry.amount {
             -1 => continue, // MISS
@@ -2940,8 +3574,26 @@ fn process_damage_queue_system(
             _ => {}
         }
 
+        // GUARD ---------------------------------------------------------------
+        // An attack on a guarded ally lands on a standing protector instead,
+        // and is resolved against the protector's defenses from here on.
+        // Hazards (no attacker) aren't attacks and can't be intercepted.
+        let mut intercepted = false;
+        if entry.attacker.is_some() {
+            let protector = guards_q.iter().find_map(|g| {
+                let standing = stats_q.get(g.protector).is_ok_and(|s| s.health.current > 0);
+                (g.target == entry.target && standing && Some(g.protector) != entry.attacker)
+                    .then_some(g.protector)
+            });
+            if let Some(protector) = protector {
+                info!("{:?} intercepts the attack on {:?}", protector, entry.target);
+                entry.target = protector;
+                intercepted = true;
+            }
+        }
+
         // FETCH STATS --------------------------------------------------------
-        let atk = stats_q.get(entry.attacker).ok();
+        let atk = entry.attacker.and_then(|a| stats_q.get(a).ok());
         let tgt = stats_q.get(entry.target).ok();
 
         // Target-side status modifiers (Fragile / Broken Body / Crippled
@@ -2961,8 +3613,17 @@ fn process_damage_queue_system(
 
         // DEFENSE -------------------------------------------------------------
         if let Some(t) = tgt {
-            for (stat, mult) in &entry.defended_with {
-                let raw = get_stat_value(*stat, Some(t)) as f32 * mult;
+            for &(stat, mult) in &entry.defended_with {
+                // Armor soaks blows, resist soaks elements; whichever the
+                // ability named, the damage type decides which one applies.
+                let stat = match stat {
+                    Stat::Armor | Stat::Resist => match entry.damage_type.mitigated_by() {
+                        Some(defense) => defense,
+                        None => continue,
+                    },
+                    other => other,
+                };
+                let raw = get_stat_value(stat, Some(t)) as f32 * mult;
                 let scaled = if matches!(stat, Stat::Armor) {
                     raw * inc.armor_mult
                 } else {
@@ -3062,18 +3723,36 @@ fn process_damage_queue_system(
                 if let Some((kind, tier)) =
                     crate::status_effects::phase_proc_status(atk_el.phase, atk_el.polarity)
                 {
+                    // A proc'd debuff belongs to this fight, not to the
+                    // lasting kind only a cleanse lifts.
+                    let expiry_override =
+                        matches!(kind, crate::status_effects::StatusKind::Debuff(_))
+                            .then_some(crate::status_effects::Expiry::EndOfCombat);
                     status_writer.write(crate::status_effects::ApplyStatusEvent {
                         target: entry.target,
                         kind,
                         tier,
-                        source: Some(entry.attacker),
-                        expiry_override: None,
+                        source: entry.attacker,
+                        expiry_override,
                         resource_focus: None,
                     });
                 }
             }
         }
 
+        if intercepted && entry.amount > 0 {
+            entry.amount = ((entry.amount as f32) * GUARD_DAMAGE_TAKEN).round() as i32;
+        }
+
+        // WARDS ---------------------------------------------------------------
+        if entry.amount > 0 {
+            if let Some(ward) = wards.get_mut(&entry.target) {
+                let soaked = (*ward).min(entry.amount);
+                *ward -= soaked;
+                entry.amount -= soaked;
+            }
+        }
+
         // FINAL DAMAGE --------------------------------------------------------
         damage_writer.send(DamageEvent {
             attacker: entry.attacker,
@@ -3099,6 +3778,9 @@ fn apply_consumable_effect_to_health(
             stats.health.restore_to_base(amount as i32);
             true
         }
+        // Over-time effects are granted by the caller, which owns the
+        // `ApplyRegenBuffEvent` writer; they can't stop a killing blow.
+        ConsumableEffect::Regen { .. } => false,
     }
 }
 
@@ -3116,7 +3798,7 @@ fn find_pre_death_item(inventory: &Inventory, item_catalog: &InventoryItemCatalo
 
 fn try_use_pre_death_item(
     target: Entity,
-    killer: Entity,
+    killer: Option<Entity>,
     inventory_q: &mut Query<&mut Inventory>,
     stats_q: &mut Query<&mut CombatStats>,
     item_catalog: &InventoryItemCatalog,
@@ -3186,6 +3868,7 @@ pub fn apply_damage_system(
     mut after_writer: MessageWriter<AfterHitEvent>,
     mut item_used_writer: MessageWriter<ItemUsedEvent>,
     mut death_writer: MessageWriter<DeathEvent>,
+    mut rng: ResMut<CombatRng>,
 ) {
     for ev in reader.iter() {
         // --- Class defensive passives (only a positive hit can be mitigated) ---
@@ -3201,7 +3884,7 @@ pub fn apply_damage_system(
                 if rogue.is_some() {
                     // Rina slips the blow on an evasion-scaled roll (cap 50%).
                     let dodge_chance = (evasion as f32 / 100.0).clamp(0.0, 0.5);
-                    if rand::rng().gen_range(0.0..1.0) < dodge_chance {
+                    if rng.0.random::<f32>() < dodge_chance {
                         amount = 0;
                     }
                 }
@@ -3250,7 +3933,7 @@ pub fn apply_damage_system(
                 if stats.health.current == 0 {
                     death_writer.send(DeathEvent {
                         entity: ev.target,
-                        killer: Some(ev.attacker),
+                        killer: ev.attacker,
                     });
                 }
             }
@@ -3315,6 +3998,48 @@ fn apply_morale_drain_system(
     }
 }
 
+/// Applies [`DrainResourceEvent`]: takes up to `amount` of the target's magic
+/// (from the event's school pool) or morale, then, in transfer mode, restores
+/// what was taken to the drainer, capped at their base.
+fn apply_resource_drain_system(
+    mut reader: MessageReader<DrainResourceEvent>,
+    mut stats_q: Query<&mut CombatStats>,
+) {
+    use crate::status_effects::ResourceKind;
+
+    for ev in reader.read() {
+        let Ok(mut target) = stats_q.get_mut(ev.target) else {
+            continue;
+        };
+        let taken = match ev.resource {
+            ResourceKind::Magic => {
+                let pool = target.pool_mut(ev.school);
+                let taken = pool.current.min(ev.amount as f32).max(0.0);
+                pool.current -= taken;
+                taken
+            }
+            ResourceKind::Morale => {
+                let taken = target.morale.current.min(ev.amount as i32).max(0);
+                target.morale.current -= taken;
+                taken as f32
+            }
+            // `Ability::validate` rejects health drains; health only falls
+            // through the damage pipeline.
+            ResourceKind::Health => continue,
+        };
+        if !ev.transfer || taken <= 0.0 || ev.drainer == ev.target {
+            continue;
+        }
+        if let Ok(mut drainer) = stats_q.get_mut(ev.drainer) {
+            match ev.resource {
+                ResourceKind::Magic => drainer.pool_mut(ev.school).restore_to_base(taken),
+                ResourceKind::Morale => drainer.morale.restore_to_base(taken as i32),
+                ResourceKind::Health => {}
+            }
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Resurrection
 // ---------------------------------------------------------------------------
@@ -3597,6 +4322,7 @@ fn resolve_use_item_intent_system(
     mut inventory_q: Query<&mut Inventory>,
     mut stats_q: Query<&mut CombatStats>,
     mut used_writer: MessageWriter<ItemUsedEvent>,
+    mut regen_writer: MessageWriter<crate::status_effects::ApplyRegenBuffEvent>,
 ) {
     for intent in intents.iter() {
         let target = intent.target.unwrap_or(intent.user);
@@ -3643,7 +4369,25 @@ fn resolve_use_item_intent_system(
             continue;
         }
 
-        if !apply_consumable_effect_to_health(target, effect, &mut stats_q) {
+        let applied = match effect {
+            ConsumableEffect::Regen {
+                amount_per_turn,
+                turns,
+            } if stats_q.contains(target) => {
+                regen_writer.write(crate::status_effects::ApplyRegenBuffEvent {
+                    target,
+                    buff: crate::status_effects::RegenBuff {
+                        amount_per_turn,
+                        remaining_turns: turns,
+                        resource: crate::status_effects::ResourceKind::Health,
+                    },
+                    source: Some(intent.user),
+                });
+                true
+            }
+            _ => apply_consumable_effect_to_health(target, effect, &mut stats_q),
+        };
+        if !applied {
             inventory.add_item(intent.item_id);
             warn!("Failed to apply item {} to target {:?}", intent.item_id, target);
             continue;
@@ -3747,6 +4491,7 @@ fn apply_buff_system(
             multiplier,
             expires_at_timestamp: Some(ev.applied_at.saturating_add(ev.duration_in_ticks)),
             source: None,
+            tag: ev.tag,
         };
 
         if let Ok(mut modifiers) = modifiers_q.get_mut(ev.target) {
@@ -3779,15 +4524,85 @@ fn apply_attunement_system(
     }
 }
 
-/// Apply (or refresh) a temporary [`PolarityFlip`] from an [`ApplyPolarityFlipEvent`].
-fn apply_polarity_flip_system(
+/// Raise each [`ReviveEvent`] target that [`check_revive_target`] allows:
+/// clear `Dead`, restore the rolled share of max health (at least 1) and put
+/// them back in the [`TurnManager`] so they act again from the next rotation.
+/// A downed party member's world entity is stood back up too, so the
+/// resurrection it was queued for on falling no longer applies.
+fn apply_revive_system(
     mut commands: Commands,
-    mut reader: MessageReader<ApplyPolarityFlipEvent>,
-    timestamp: Res<Timestamp>,
+    mut reader: MessageReader<ReviveEvent>,
+    mut stats_q: Query<(
+        &mut CombatStats,
+        Has<Dead>,
+        Has<PermanentlyDead>,
+        Option<&crate::battle::BattleWorldLink>,
+    )>,
+    mut tm: ResMut<TurnManager>,
 ) {
     for ev in reader.read() {
-        commands.entity(ev.target).insert(PolarityFlip {
-            expiry: timestamp.0.saturating_add(ev.duration),
+        let Ok((mut stats, dead, permanently_dead, link)) = stats_q.get_mut(ev.target) else {
+            continue;
+        };
+        if let Err(reason) = check_revive_target(dead, permanently_dead) {
+            info!("{:?} can't revive {:?}: {:?}", ev.reviver, ev.target, reason);
+            continue;
+        }
+        let restored = ((stats.health.base as f32) * ev.hp_fraction).round() as i32;
+        stats.health.current = restored.clamp(1, stats.health.base.max(1));
+        commands.entity(ev.target).remove::<Dead>();
+        if let Some(link) = link {
+            commands.entity(link.world_entity).remove::<(Dead, AwaitingResurrection)>();
+        }
+        if !tm.participants.contains(&ev.target) {
+            tm.participants.push(ev.target);
+        }
+        info!("{:?} revived {:?} at {} HP", ev.reviver, ev.target, stats.health.current);
+    }
+}
+
+/// Put a [`Guard`] on each protector from an [`ApplyGuardEvent`]. Guarding
+/// yourself is meaningless, so those are dropped.
+fn apply_guard_system(mut commands: Commands, mut reader: MessageReader<ApplyGuardEvent>) {
+    for ev in reader.read() {
+        if ev.protector == ev.target {
+            continue;
+        }
+        commands.entity(ev.protector).insert(Guard {
+            protector: ev.protector,
+            target: ev.target,
+            remaining_turns: ev.turns.max(1),
+        });
+    }
+}
+
+/// At the end of a protector's own turn, count their [`Guard`] down and drop
+/// it once it runs out.
+fn tick_guard_system(
+    mut commands: Commands,
+    mut turn_ends: MessageReader<TurnEndEvent>,
+    mut guards: Query<&mut Guard>,
+) {
+    for ev in turn_ends.read() {
+        let Ok(mut guard) = guards.get_mut(ev.who) else {
+            continue;
+        };
+        guard.remaining_turns = guard.remaining_turns.saturating_sub(1);
+        if guard.remaining_turns == 0 {
+            commands.entity(ev.who).remove::<Guard>();
+        }
+    }
+}
+
+/// Apply (or refresh) a temporary [`PolarityFlip`] from an [`ApplyPolarityFlipEvent`].
+fn apply_polarity_flip_system(
+    mut commands: Commands,
+    mut reader: MessageReader<ApplyPolarityFlipEvent>,
+    timestamp: Res<Timestamp>,
+) {
+    for ev in reader.read() {
+        commands.entity(ev.target).insert(PolarityFlip {
+            expiry: timestamp.0.saturating_add(ev.duration),
         });
     }
 }
@@ -3820,9 +4635,13 @@ fn after_hit_listeners(
     mut after_attack_writer: MessageWriter<AfterAttackEvent>,
 ) {
     for ev in after_hits.iter() {
+        // Environmental hits have no attack to follow up on.
+        let Some(attacker) = ev.attacker else {
+            continue;
+        };
         // Could apply on-hit effects here
         after_attack_writer.send(AfterAttackEvent {
-            attacker: ev.attacker,
+            attacker,
             target: ev.target,
             context: AttackContext::default(),
             cause: ev.cause.clone(),
@@ -3927,6 +4746,17 @@ pub struct TurnOrder {
 #[derive(Resource, Default)]
 pub struct TurnInProgress(pub bool);
 
+/// Pause between one turn ending and the next starting, in seconds at
+/// [`CombatSpeed`](crate::settings::CombatSpeed) 1.0.
+pub const TURN_TRANSITION_SECONDS: f32 = 0.6;
+
+/// What's left of the current between-turns pause. The turn-order systems
+/// hold until it runs out (see [`turn_pacing_ready`]).
+#[derive(Resource, Default)]
+pub struct TurnPacing {
+    pub remaining: f32,
+}
+
 #[derive(Resource, Default)]
 pub struct MagicRegenTracker {
     pub last_processed_timestamp: u32,
@@ -3988,11 +4818,7 @@ impl TurnManager {
                     .get(entity)
                     .map(|s| s.speed.current.max(0) as u32)
                     .unwrap_or(0);
-                let jitter: u32 = if self.maximum_value > 0 {
-                    rng.gen_range(0..self.maximum_value)
-                } else {
-                    0
-                };
+                let jitter: u32 = safe_range(&mut rng, 0, self.maximum_value);
 
                 let mut current = acc.0;
                 // add speed + random jitter
@@ -4099,7 +4925,7 @@ fn growth_curve_multiplier(target: GrowthTarget, curve: Option<&GrowthCurve>) ->
 
 /// --------------- Level up system using your confirmed parameters ---------------
 
-/// Event: LevelUpEvent { who: Entity, old_level: u8, new_level: u8 }
+/// Event: LevelUpEvent { who: Entity, old_level: u16, new_level: u16 }
 /// (assumes you already defined LevelUpEvent elsewhere and registered it)
 pub fn level_up_system(
     mut level_up_events: MessageReader<LevelUpEvent>,
@@ -4123,8 +4949,10 @@ pub fn level_up_system(
 
     for ev in level_up_events.iter() {
         if let Ok((mut stats, growth_attr, curve_opt)) = q_stats.get_mut(ev.who) {
-            let level_gained = (ev.new_level as i32) - (ev.old_level as i32);
-            if level_gained <= 0 {
+            // A stale or out-of-order event can report no gain (or a loss);
+            // there's nothing to grow then.
+            let level_gained = ev.new_level.saturating_sub(ev.old_level);
+            if level_gained == 0 {
                 continue;
             }
 
@@ -4159,6 +4987,55 @@ pub fn level_up_system(
     }
 }
 
+/// Attribute points granted per level gained, like skill points.
+const ATTRIBUTE_POINTS_PER_LEVEL: u32 = 1;
+
+pub fn award_attribute_points_on_levelup_system(
+    mut events: MessageReader<LevelUpEvent>,
+    mut q: Query<&mut AttributePointPool>,
+    mut changed: MessageWriter<AttributePointsChangedEvent>,
+) {
+    for ev in events.read() {
+        if ev.new_level <= ev.old_level {
+            continue;
+        }
+        let gained = (ev.new_level as u32 - ev.old_level as u32) * ATTRIBUTE_POINTS_PER_LEVEL;
+        if let Ok(mut pool) = q.get_mut(ev.who) {
+            pool.available = pool.available.saturating_add(gained);
+            changed.write(AttributePointsChangedEvent {
+                who: ev.who,
+                available: pool.available,
+                spent: pool.spent,
+            });
+        }
+    }
+}
+
+pub fn spend_attribute_point_system(
+    mut events: MessageReader<SpendAttributePointEvent>,
+    mut q: Query<(&mut GrowthAttributes, &mut AttributePointPool)>,
+    mut changed: MessageWriter<AttributePointsChangedEvent>,
+) {
+    for ev in events.read() {
+        let Ok((mut attributes, mut pool)) = q.get_mut(ev.who) else {
+            continue;
+        };
+        let value = attributes.get_mut(ev.attribute);
+        if pool.available == 0 || *value == u8::MAX {
+            info!("{:?} can't raise {:?}", ev.who, ev.attribute);
+            continue;
+        }
+        *value += 1;
+        pool.available -= 1;
+        pool.spent += 1;
+        changed.write(AttributePointsChangedEvent {
+            who: ev.who,
+            available: pool.available,
+            spent: pool.spent,
+        });
+    }
+}
+
 pub fn respec_system(
     mut ev_respec: MessageReader<RespecEvent>,
     mut q: Query<(
@@ -4166,6 +5043,7 @@ pub fn respec_system(
         &mut AttributePointPool,
         Option<&GrowthCurve>,
     )>,
+    mut changed: MessageWriter<AttributePointsChangedEvent>,
 ) {
     for ev in ev_respec.read() {
         if let Ok((mut attributes, mut pool, _curve)) = q.get_mut(ev.who) {
@@ -4202,6 +5080,11 @@ pub fn respec_system(
                 total_spent,
                 pool.available
             );
+            changed.write(AttributePointsChangedEvent {
+                who: ev.who,
+                available: pool.available,
+                spent: pool.spent,
+            });
         }
     }
 }
@@ -4214,9 +5097,10 @@ pub fn respec_system(
 /// Call this whenever you spawn or despawn participants.
 fn register_participants_system(
     mut tm: ResMut<TurnManager>,
-    query_chars: Query<Entity, With<CombatStats>>,
+    query_chars: Query<Entity, (With<CombatStats>, Without<Dead>)>,
 ) {
-    // simple strategy: replace participants with all entities that have CombatStats
+    // simple strategy: replace participants with all entities that have CombatStats,
+    // leaving out the downed (they sit the rotation out until revived)
     tm.participants = query_chars.iter().collect();
 }
 
@@ -4249,7 +5133,7 @@ fn compute_turn_order_system(
     for &entity in &tm.participants {
         if let Ok(mut acc) = acc_q.get_mut(entity) {
             let speed = stats_q.get(entity).map(|s| s.speed.current.max(0) as u32).unwrap_or(0);
-            let jitter: u32 = if tm.maximum_value > 0 { rng.gen_range(0..tm.maximum_value) } else { 0 };
+            let jitter: u32 = safe_range(&mut rng, 0, tm.maximum_value);
             let mut current = acc.0;
             current = current.saturating_add(speed).saturating_add(jitter);
             while current >= tm.turn_threshold && tm.turn_threshold > 0 {
@@ -4269,6 +5153,27 @@ fn compute_turn_order_system(
     ev_writer.send(TurnOrderCalculatedEvent);
 }
 
+/// Start the between-turns pause on every `TurnEndEvent` and run it down on
+/// the combat presentation clock, so `CombatSpeed` shortens it without
+/// touching turn logic.
+fn turn_pacing_system(
+    time: Res<Time>,
+    speed: Option<Res<crate::settings::CombatSpeed>>,
+    mut pacing: ResMut<TurnPacing>,
+    mut turn_ends: MessageReader<TurnEndEvent>,
+) {
+    if turn_ends.read().count() > 0 {
+        pacing.remaining = TURN_TRANSITION_SECONDS;
+    }
+    let dt = speed.as_deref().copied().unwrap_or_default().scale(time.delta_secs());
+    pacing.remaining = (pacing.remaining - dt).max(0.0);
+}
+
+/// Run condition for the systems that start a turn.
+pub fn turn_pacing_ready(pacing: Res<TurnPacing>) -> bool {
+    pacing.remaining <= 0.0
+}
+
 /// Splits out the next entity from TurnOrder and emits a TurnStartEvent
 fn advance_turn_system(
     mut turn_order: ResMut<TurnOrder>,
@@ -4355,22 +5260,105 @@ fn finish_turn_if_needed(
 }
 
 /// Bundles every event writer the player-action handler emits to. Without
-/// this bundle the system param count exceeds Bevy's 16-arg ceiling.
+/// this bundle the system param count exceeds Bevy's 16-arg ceiling; it is
+/// also what [`handle_ability`] writes an ability's effects through.
 #[derive(bevy::ecs::system::SystemParam)]
-struct PlayerActionWriters<'w> {
-    intent: MessageWriter<'w, AttackIntentEvent>,
-    use_item: MessageWriter<'w, UseItemIntentEvent>,
-    heal: MessageWriter<'w, HealEvent>,
-    drain_morale: MessageWriter<'w, DrainMoraleEvent>,
-    buff: MessageWriter<'w, ApplyBuffEvent>,
-    apply_status: MessageWriter<'w, crate::status_effects::ApplyStatusEvent>,
-    remove_status: MessageWriter<'w, crate::status_effects::RemoveStatusEvent>,
-    defend: MessageWriter<'w, DefendIntentEvent>,
-    wait: MessageWriter<'w, WaitIntentEvent>,
-    turn_end: MessageWriter<'w, TurnEndEvent>,
-    summon: MessageWriter<'w, SummonEvent>,
-    attune: MessageWriter<'w, ApplyAttunementEvent>,
-    flip: MessageWriter<'w, ApplyPolarityFlipEvent>,
+pub(crate) struct PlayerActionWriters<'w> {
+    pub(crate) intent: MessageWriter<'w, AttackIntentEvent>,
+    pub(crate) use_item: MessageWriter<'w, UseItemIntentEvent>,
+    pub(crate) heal: MessageWriter<'w, HealEvent>,
+    pub(crate) drain_morale: MessageWriter<'w, DrainMoraleEvent>,
+    pub(crate) buff: MessageWriter<'w, ApplyBuffEvent>,
+    pub(crate) apply_status: MessageWriter<'w, crate::status_effects::ApplyStatusEvent>,
+    pub(crate) remove_status: MessageWriter<'w, crate::status_effects::RemoveStatusEvent>,
+    pub(crate) defend: MessageWriter<'w, DefendIntentEvent>,
+    pub(crate) wait: MessageWriter<'w, WaitIntentEvent>,
+    pub(crate) turn_end: MessageWriter<'w, TurnEndEvent>,
+    pub(crate) summon: MessageWriter<'w, SummonEvent>,
+    pub(crate) attune: MessageWriter<'w, ApplyAttunementEvent>,
+    pub(crate) flip: MessageWriter<'w, ApplyPolarityFlipEvent>,
+    pub(crate) regen: MessageWriter<'w, crate::status_effects::ApplyRegenBuffEvent>,
+    pub(crate) drain_resource: MessageWriter<'w, DrainResourceEvent>,
+    pub(crate) guard: MessageWriter<'w, ApplyGuardEvent>,
+    pub(crate) zone: MessageWriter<'w, SpawnZoneEvent>,
+    pub(crate) revive: MessageWriter<'w, ReviveEvent>,
+    pub(crate) ability_failed: MessageWriter<'w, AbilityFailedEvent>,
+}
+
+/// Register every message [`PlayerActionWriters`] writes, for tests that run
+/// the action handlers without the full [`CombatPlugin`].
+#[cfg(test)]
+fn add_player_action_messages(app: &mut App) -> &mut App {
+    app.add_message::<AttackIntentEvent>()
+        .add_message::<UseItemIntentEvent>()
+        .add_message::<HealEvent>()
+        .add_message::<DrainMoraleEvent>()
+        .add_message::<ApplyBuffEvent>()
+        .add_message::<crate::status_effects::ApplyStatusEvent>()
+        .add_message::<crate::status_effects::RemoveStatusEvent>()
+        .add_message::<DefendIntentEvent>()
+        .add_message::<WaitIntentEvent>()
+        .add_message::<TurnEndEvent>()
+        .add_message::<SummonEvent>()
+        .add_message::<ApplyAttunementEvent>()
+        .add_message::<ApplyPolarityFlipEvent>()
+        .add_message::<crate::status_effects::ApplyRegenBuffEvent>()
+        .add_message::<DrainResourceEvent>()
+        .add_message::<ApplyGuardEvent>()
+        .add_message::<SpawnZoneEvent>()
+        .add_message::<ReviveEvent>()
+        .add_message::<AbilityFailedEvent>()
+}
+
+/// Turn the confirm step on or off with the player's
+/// [`ConfirmActions`](crate::settings::ConfirmActions) setting. Switching it
+/// off drops anything still staged; the turn stays open for a fresh pick.
+fn follow_confirm_actions_setting(
+    setting: Option<Res<crate::settings::ConfirmActions>>,
+    mut staging: ResMut<ActionStaging>,
+) {
+    let Some(setting) = setting else { return };
+    if staging.enabled != setting.0 {
+        staging.enabled = setting.0;
+        staging.staged = None;
+    }
+}
+
+/// Holds, swaps, drops or commits the staged player action. Runs ahead of
+/// [`process_player_action_system`] so a confirmed action resolves the same
+/// frame. Within a frame, new picks land first, then an undo, then a confirm.
+fn stage_player_action_system(
+    mut stage: MessageReader<StageActionEvent>,
+    mut undo: MessageReader<UndoStagedActionEvent>,
+    mut confirm: MessageReader<ConfirmStagedActionEvent>,
+    pending: Res<PendingPlayerAction>,
+    mut staging: ResMut<ActionStaging>,
+    mut actions: MessageWriter<PlayerActionEvent>,
+) {
+    // The turn moved on (or ended) since the pick: it no longer applies.
+    if staging.staged.as_ref().is_some_and(|(actor, _)| Some(*actor) != pending.entity) {
+        staging.staged = None;
+    }
+    let Some(actor) = pending.entity else {
+        stage.clear();
+        undo.clear();
+        confirm.clear();
+        return;
+    };
+
+    if let Some(ev) = stage.read().last() {
+        staging.staged = Some((actor, ev.action.clone()));
+    }
+    if undo.read().count() > 0 {
+        if let Some((_, action)) = staging.staged.take() {
+            info!("Actor {:?} took back {:?}", actor, action);
+        }
+    }
+    if confirm.read().count() > 0 {
+        if let Some((_, action)) = staging.staged.take() {
+            actions.write(PlayerActionEvent { action });
+        }
+    }
 }
 
 fn process_player_action_system(
@@ -4378,12 +5366,18 @@ fn process_player_action_system(
     mut pending: ResMut<PendingPlayerAction>,
     ability_tree: Option<Res<Ability_Tree>>,
     timestamp: Res<Timestamp>,
-    mut dq: ResMut<DamageQueue>,
     mut stats_q: Query<&mut CombatStats>,
     status_q: Query<&crate::status_effects::StatusEffects>,
     defilement_q: Query<&crate::kegare::Defilement>,
     mut writers: PlayerActionWriters,
     mut turn_in_progress: ResMut<TurnInProgress>,
+    mut rng: ResMut<CombatRng>,
+    confused_q: Query<&crate::status_effects::Confused>,
+    participants_q: Query<Entity, With<crate::battle::BattleParticipant>>,
+    abilities_q: Query<&Abilities>,
+    downed_q: Query<(Has<Dead>, Has<PermanentlyDead>)>,
+    tutorial: Option<Res<crate::combat_tutorial::CombatTutorial>>,
+    cast_area: CastArea,
 ) {
     if pending.entity.is_none() {
         return; // no player turn pending
@@ -4405,6 +5399,12 @@ fn process_player_action_system(
         // status checks per action.
         let gates = crate::status_effects::action_gates(status_q.get(actor).ok());
 
+        // A scripted tutorial battle only takes the step it's teaching.
+        if tutorial.as_ref().is_some_and(|t| !t.allows(&e.action)) {
+            info!("Actor {:?}: {:?} is off the tutorial script", actor, e.action);
+            continue;
+        }
+
         match &e.action {
             PlayerAction::Attack(target) => {
                 if gates.block_attacks {
@@ -4436,14 +5436,43 @@ fn process_player_action_system(
                     info!("Actor {:?}: ability use blocked by ActionGates", actor);
                     continue;
                 }
-                let Some(tree) = ability_tree.as_ref() else {
-                    warn!("Ability tree resource is not available");
-                    continue;
-                };
-                let Some(ability) = tree.0.find(*ability_id as u16) else {
+                let Some(ability) = ability_tree
+                    .as_ref()
+                    .and_then(|tree| tree.0.find(*ability_id as u16))
+                else {
                     warn!("Ability {} not found", ability_id);
+                    writers.ability_failed.write(AbilityFailedEvent {
+                        actor,
+                        ability_id: *ability_id,
+                        reason: AbilityFailReason::Unknown,
+                    });
                     continue;
                 };
+                let learned = abilities_q
+                    .get(actor)
+                    .is_ok_and(|known| known.0.contains(&ability.id));
+                if !learned {
+                    info!("Actor {:?} has not learned {}", actor, ability.name);
+                    writers.ability_failed.write(AbilityFailedEvent {
+                        actor,
+                        ability_id: *ability_id,
+                        reason: AbilityFailReason::NotLearned,
+                    });
+                    continue;
+                }
+                let (dead, permanently_dead) = downed_q.get(*target).unwrap_or_default();
+                if let Err(rejection) = check_ability_target(&ability, dead, permanently_dead) {
+                    info!(
+                        "Actor {:?} can't cast {} on {:?}: {:?}",
+                        actor, ability.name, target, rejection
+                    );
+                    writers.ability_failed.write(AbilityFailedEvent {
+                        actor,
+                        ability_id: *ability_id,
+                        reason: AbilityFailReason::BadReviveTarget(rejection),
+                    });
+                    continue;
+                }
 
                 if gates.block_magic_abilities && ability.magic_cost > 0.0 {
                     info!(
@@ -4494,22 +5523,22 @@ fn process_player_action_system(
                 stats.pool_mut(ability.magic_school).spend(scaled_magic_cost);
                 drop(stats);
 
-                handle_ability(
+                // A confused caster may loose the ability on someone else.
+                let chance =
+                    crate::status_effects::retarget_chance(&gates, confused_q.get(actor).ok());
+                let target = roll_confused_retarget(
                     actor,
-                    &ability,
-                    &[*target],
-                    timestamp.0,
-                    &mut dq,
-                    &mut writers.intent,
-                    &mut writers.heal,
-                    &mut writers.buff,
-                    &mut writers.apply_status,
-                    &mut writers.remove_status,
-                    &mut writers.summon,
-                    &mut writers.attune,
-                    &mut writers.flip,
-                    &mut writers.drain_morale,
-                );
+                    *target,
+                    chance,
+                    &confusion_candidates(
+                        participants_q.iter().filter_map(|e| stats_q.get(e).ok().map(|s| (e, s))),
+                    ),
+                    &mut rng.0,
+                )
+                .unwrap_or(*target);
+
+                let affected = cast_area.affected(&ability, actor, target);
+                handle_ability(actor, &ability, &affected, timestamp.0, &mut rng.0, &mut writers);
             }
 
             PlayerAction::UseItem(_item_id, _target) => {
@@ -4571,11 +5600,15 @@ fn resolve_ai_ability_intent_system(
     mut ev: MessageReader<AbilityIntentEvent>,
     ability_tree: Option<Res<Ability_Tree>>,
     timestamp: Res<Timestamp>,
-    mut dq: ResMut<DamageQueue>,
     mut stats_q: Query<&mut CombatStats>,
     status_q: Query<&crate::status_effects::StatusEffects>,
     defilement_q: Query<&crate::kegare::Defilement>,
     mut writers: PlayerActionWriters,
+    mut rng: ResMut<CombatRng>,
+    confused_q: Query<&crate::status_effects::Confused>,
+    participants_q: Query<Entity, With<crate::battle::BattleParticipant>>,
+    downed_q: Query<(Has<Dead>, Has<PermanentlyDead>)>,
+    cast_area: CastArea,
 ) {
     let Some(tree) = ability_tree.as_ref() else {
         return;
@@ -4596,6 +5629,10 @@ fn resolve_ai_ability_intent_system(
         if gates.block_magic_abilities && ability.magic_cost > 0.0 {
             continue;
         }
+        let (dead, permanently_dead) = downed_q.get(e.target).unwrap_or_default();
+        if check_ability_target(&ability, dead, permanently_dead).is_err() {
+            continue;
+        }
 
         // Same cost shaping as the player: status multiplier × kegare tilt.
         let cost_mult = crate::status_effects::magic_cost_multiplier(status_q.get(actor).ok());
@@ -4619,22 +5656,20 @@ fn resolve_ai_ability_intent_system(
         stats.pool_mut(ability.magic_school).spend(scaled_magic_cost);
         drop(stats);
 
-        handle_ability(
+        let chance = crate::status_effects::retarget_chance(&gates, confused_q.get(actor).ok());
+        let target = roll_confused_retarget(
             actor,
-            &ability,
-            &[e.target],
-            timestamp.0,
-            &mut dq,
-            &mut writers.intent,
-            &mut writers.heal,
-            &mut writers.buff,
-            &mut writers.apply_status,
-            &mut writers.remove_status,
-            &mut writers.summon,
-            &mut writers.attune,
-            &mut writers.flip,
-            &mut writers.drain_morale,
-        );
+            e.target,
+            chance,
+            &confusion_candidates(
+                participants_q.iter().filter_map(|e| stats_q.get(e).ok().map(|s| (e, s))),
+            ),
+            &mut rng.0,
+        )
+        .unwrap_or(e.target);
+
+        let affected = cast_area.affected(&ability, actor, target);
+        handle_ability(actor, &ability, &affected, timestamp.0, &mut rng.0, &mut writers);
     }
 }
 
@@ -4745,35 +5780,118 @@ fn debug_print_system(
     }
 }
 
+/// Where everyone in the fight stands and what blocks sight between them, so
+/// a live cast can lay its ability's shape over the field.
+#[derive(bevy::ecs::system::SystemParam)]
+pub(crate) struct CastArea<'w, 's> {
+    participants: Query<'w, 's, Entity, (With<crate::battle::BattleParticipant>, Without<Dead>)>,
+    links: Query<'w, 's, &'static crate::battle::BattleWorldLink>,
+    transforms: Query<'w, 's, &'static Transform>,
+    quad_tree: Option<Res<'w, crate::quadtree::QuadTree>>,
+}
+
+impl CastArea<'_, '_> {
+    /// Who `caster`'s cast of `ability` at `target` lands on, each with its
+    /// falloff multiplier. `Select` stays on the chosen target; an area shape
+    /// is aimed at where the target stands (see [`get_affected_characters`]).
+    pub(crate) fn affected(
+        &self,
+        ability: &Ability,
+        caster: Entity,
+        target: Entity,
+    ) -> Vec<(Entity, f32)> {
+        if matches!(ability.shape, AbilityShape::Select) {
+            return vec![(target, 1.0)];
+        }
+        let position = |e| crate::battle::combat_position(e, &self.links, &self.transforms);
+        let (Some(from), Some(aim)) = (position(caster), position(target)) else {
+            warn!("Cast of {} by {:?} has no position to aim from", ability.name, caster);
+            return vec![(target, 1.0)];
+        };
+        get_affected_characters(
+            ability,
+            caster,
+            from,
+            aim,
+            self.participants.iter().filter_map(|e| Some((e, position(e)?))),
+            self.quad_tree.as_deref(),
+        )
+    }
+}
+
+/// Which `candidates` `ability` catches when `caster`, standing at
+/// `caster_position`, aims it at `cursor_position`, with their falloff.
+/// Walls (arena obstacles, wards) in `quad_tree` shield whoever stands behind
+/// them.
 pub fn get_affected_characters(
     ability: &Ability,
-    player_entity: Entity,
+    caster: Entity,
+    caster_position: Vec2,
+    cursor_position: Vec2,
+    candidates: impl Iterator<Item = (Entity, Vec2)>,
+    quad_tree: Option<&crate::quadtree::QuadTree>,
+) -> Vec<(Entity, f32)> {
+    affected_with_falloff(
+        ability,
+        caster,
+        (caster_position.x, caster_position.y),
+        (cursor_position.x, cursor_position.y),
+        candidates
+            .filter(|&(e, at)| {
+                e == caster
+                    || quad_tree.is_none_or(|tree| {
+                        crate::pathfinding::line_of_sight(tree, caster_position, at)
+                    })
+            })
+            .map(|(e, at)| (e, (at.x, at.y))),
+    )
+}
+
+/// Geometry half of [`get_affected_characters`]: which `candidates` fall inside
+/// `ability.shape` cast from `caster_position` towards `cursor_position`. The
+/// caster itself is dropped unless [`Ability::includes_self`] allows it.
+pub fn affected_by_shape(
+    ability: &Ability,
+    caster: Entity,
+    caster_position: (f32, f32),
     cursor_position: (f32, f32),
-    query: &Query<(Entity, &Transform)>,
-    player_position_query: &Query<&Transform>,
+    candidates: impl Iterator<Item = (Entity, (f32, f32))>,
 ) -> Vec<Entity> {
-    let mut affected = Vec::new();
+    affected_with_falloff(ability, caster, caster_position, cursor_position, candidates)
+        .into_iter()
+        .map(|(entity, _)| entity)
+        .collect()
+}
 
-    let Ok(player_pos) = player_position_query.get(player_entity) else {
-        warn!("Could not fetch player position for targeting");
-        return affected;
-    };
-    let player_position = (player_pos.translation.x, player_pos.translation.y);
+/// [`affected_by_shape`], with each hit paired with its damage multiplier
+/// from [`Ability::falloff_multiplier`]: how far along the line, or how far
+/// out towards the cone's rim, the target stands.
+pub fn affected_with_falloff(
+    ability: &Ability,
+    caster: Entity,
+    caster_position: (f32, f32),
+    cursor_position: (f32, f32),
+    candidates: impl Iterator<Item = (Entity, (f32, f32))>,
+) -> Vec<(Entity, f32)> {
+    let includes_self = ability.includes_self();
+    let mut affected = Vec::new();
 
-    for (entity, transform) in query.iter() {
-        let target_position = (transform.translation.x, transform.translation.y);
+    for (entity, target_position) in candidates {
+        if entity == caster && !includes_self {
+            continue;
+        }
 
         let is_affected = match &ability.shape {
             AbilityShape::Radius(radius) => {
-                is_in_radius(*radius, player_position, target_position)
+                is_in_radius(*radius, caster_position, target_position)
             }
 
             AbilityShape::Line { length, thickness } => {
-                is_in_line(*length, *thickness, player_position, cursor_position, target_position)
+                is_in_line(*length, *thickness, caster_position, cursor_position, target_position)
             }
 
             AbilityShape::Cone { angle, radius } => {
-                is_in_cone(*angle, *radius, player_position, cursor_position, target_position)
+                is_in_cone(*angle, *radius, caster_position, cursor_position, target_position)
             }
 
             AbilityShape::Select => {
@@ -4781,9 +5899,28 @@ pub fn get_affected_characters(
             }
         };
 
-        if is_affected {
-            affected.push(entity);
+        if !is_affected {
+            continue;
         }
+
+        let progress = match &ability.shape {
+            AbilityShape::Line { length, .. } => {
+                let dir = normalize((
+                    cursor_position.0 - caster_position.0,
+                    cursor_position.1 - caster_position.1,
+                ));
+                let to_target = (
+                    target_position.0 - caster_position.0,
+                    target_position.1 - caster_position.1,
+                );
+                dot(to_target, dir) / length
+            }
+            AbilityShape::Cone { radius, .. } => {
+                distance(caster_position, target_position) / radius
+            }
+            AbilityShape::Radius(_) | AbilityShape::Select => 0.0,
+        };
+        affected.push((entity, ability.falloff_multiplier(progress)));
     }
 
     affected
@@ -4794,6 +5931,21 @@ pub fn get_affected_characters(
 // === Geometry Helpers ===
 //
 
+/// Whether `point` lies inside `shape` as laid down for a persistent zone: a
+/// radius is centred on `target`, a line or cone runs from `caster` towards
+/// it. `Select` covers nothing beyond the anchor tile the caller adds.
+pub fn zone_shape_covers(shape: &AbilityShape, caster: Vec2, target: Vec2, point: Vec2) -> bool {
+    let (caster, target, point) = ((caster.x, caster.y), (target.x, target.y), (point.x, point.y));
+    match shape {
+        AbilityShape::Radius(radius) => is_in_radius(*radius, target, point),
+        AbilityShape::Line { length, thickness } => {
+            is_in_line(*length, *thickness, caster, target, point)
+        }
+        AbilityShape::Cone { angle, radius } => is_in_cone(*angle, *radius, caster, target, point),
+        AbilityShape::Select => false,
+    }
+}
+
 fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
     ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
 }
@@ -4948,6 +6100,10 @@ fn load_ability_tree_system(mut ability_tree: ResMut<Ability_Tree>) {
         Ok(abilities) => {
             ability_tree.0 = AbilityTree::new();
             for ability in abilities {
+                if let Err(err) = ability.validate() {
+                    warn!("Skipping ability {} ('{}'): {err}", ability.id, ability.name);
+                    continue;
+                }
                 ability_tree.0.insert(ability);
             }
         }
@@ -4961,27 +6117,37 @@ impl Plugin for CombatPlugin {
         app.insert_resource(TurnOrder::default())
             .insert_resource(TurnManager::default())
             .insert_resource(TurnInProgress::default())
+            .init_resource::<TurnPacing>()
             .insert_resource(InventoryItemCatalog::default())
+            .init_resource::<MoraleSwing>()
             .insert_resource(Ability_Tree(AbilityTree::new()))
             .insert_resource(PendingPlayerAction::default())
+            .init_resource::<CombatRng>()
             // events
             .add_message::<RestEvent>()
             .add_message::<BeforeRestEvent>()
             .add_message::<AfterRestEvent>()
             .add_message::<AwardXpEvent>()
+            .add_message::<LootEvent>()
             .add_message::<AttackIntentEvent>()
             .add_message::<AbilityIntentEvent>()
             .add_message::<DefendIntentEvent>()
             .add_message::<WaitIntentEvent>()
             .add_message::<PlayerActionEvent>()
+            .add_message::<AbilityFailedEvent>()
             .add_message::<BeforeAttackEvent>()
             .add_message::<AttackExecuteEvent>()
             .add_message::<BeforeHitEvent>()
             .add_message::<HealEvent>()
             .add_message::<DrainMoraleEvent>()
+            .add_message::<DrainResourceEvent>()
             .add_message::<ApplyBuffEvent>()
             .add_message::<ApplyAttunementEvent>()
             .add_message::<ApplyPolarityFlipEvent>()
+            .add_message::<ApplyGuardEvent>()
+            .add_message::<SpawnZoneEvent>()
+            .add_message::<ReviveEvent>()
+            .add_message::<UseFieldAbilityEvent>()
             .add_message::<DamageEvent>()
             .add_message::<UseItemIntentEvent>()
             .add_message::<GiveItemIntentEvent>()
@@ -4999,24 +6165,61 @@ impl Plugin for CombatPlugin {
             .add_message::<TurnStartEvent>()
             .add_message::<TurnEndEvent>()
             .add_message::<RoundEndEvent>()
+            .add_message::<RespecEvent>()
+            .add_message::<SpendAttributePointEvent>()
+            .add_message::<AttributePointsChangedEvent>()
+            .init_resource::<ActionStaging>()
+            .add_message::<StageActionEvent>()
+            .add_message::<ConfirmStagedActionEvent>()
+            .add_message::<UndoStagedActionEvent>()
             // startup
             // Disable the demo auto-battle spawns so the game starts in exploration without combat noise.
             .add_systems(Startup, init_messages)
             .add_systems(Startup, load_ability_tree_system.after(init_messages))
             // xp / leveling systems
-            .add_systems(Update, award_xp_system)
+            .add_systems(Update, award_kill_xp_system.after(apply_damage_system))
+            .add_systems(Update, award_xp_system.after(award_kill_xp_system))
             .add_systems(Update, level_up_system.after(award_xp_system))
+            .add_systems(
+                Update,
+                (
+                    award_attribute_points_on_levelup_system,
+                    spend_attribute_point_system,
+                    respec_system,
+                ),
+            )
             // turn systems
             .add_systems(Update, register_participants_system)
-            .add_systems(Update, compute_turn_order_system.after(register_participants_system))
+            .add_systems(
+                Update,
+                turn_pacing_system
+                    .after(register_participants_system)
+                    .before(compute_turn_order_system),
+            )
+            .add_systems(
+                Update,
+                compute_turn_order_system
+                    .after(register_participants_system)
+                    .run_if(turn_pacing_ready),
+            )
             .add_systems(Update, auto_advance_after_order.after(compute_turn_order_system))
             .add_systems(Update, on_turn_start_system.after(auto_advance_after_order))
             .add_systems(Update, buff_tick_on_turn_start_system.after(on_turn_start_system))
             // Turn-start class sustain passives (Sayaka's heal, Renjiro/Suzuka regen).
             .add_systems(Update, cleric_blessing_system.after(on_turn_start_system))
+            .add_systems(Update, rage_on_ally_death_system)
             .add_systems(Update, class_turn_start_regen_system.after(on_turn_start_system))
-            .add_systems(Update, advance_turn_system.after(compute_turn_order_system))
+            .add_systems(
+                Update,
+                advance_turn_system.after(compute_turn_order_system).run_if(turn_pacing_ready),
+            )
             .add_systems(Update, buff_tick_system)
+            .add_systems(
+                Update,
+                (follow_confirm_actions_setting, stage_player_action_system)
+                    .chain()
+                    .before(process_player_action_system),
+            )
             .add_systems(Update, process_player_action_system)
             .add_systems(Update, resolve_ai_ability_intent_system)
             // combat pipeline (core)
@@ -5046,9 +6249,12 @@ impl Plugin for CombatPlugin {
             .add_systems(Update, before_hit_listeners.after(before_to_execute))
             .add_systems(Update, apply_heal_system)
             .add_systems(Update, apply_morale_drain_system)
+            .add_systems(Update, apply_resource_drain_system)
             .add_systems(Update, apply_buff_system)
             .add_systems(Update, apply_attunement_system)
             .add_systems(Update, apply_polarity_flip_system)
+            .add_systems(Update, (apply_guard_system, tick_guard_system))
+            .add_systems(Update, apply_revive_system)
             .add_systems(Update, expire_elemental_modifiers_system)
             .add_systems(Update, process_damage_queue_system.after(queue_damage_from_before_attack))
             .add_systems(Update, apply_damage_system.after(process_damage_queue_system))
@@ -5195,3 +6401,1654 @@ mod equipment_bonus_tests {
         assert_eq!(b.mind, 10);
     }
 }
+
+#[cfg(test)]
+mod rng_tests {
+    use super::{safe_range, CombatRng};
+
+    #[test]
+    fn empty_range_returns_lo() {
+        let mut rng = CombatRng::seeded(1);
+        assert_eq!(safe_range(&mut rng.0, 0u32, 0), 0);
+        assert_eq!(safe_range(&mut rng.0, 9i32, 9), 9);
+        assert_eq!(safe_range(&mut rng.0, 0.5f32, 0.5), 0.5);
+    }
+
+    #[test]
+    fn inverted_range_returns_lo() {
+        let mut rng = CombatRng::seeded(2);
+        assert_eq!(safe_range(&mut rng.0, 10u32, 3), 10);
+        assert_eq!(safe_range(&mut rng.0, 1.0f32, -1.0), 1.0);
+    }
+
+    #[test]
+    fn valid_range_stays_in_bounds() {
+        let mut rng = CombatRng::seeded(3);
+        for _ in 0..64 {
+            let v = safe_range(&mut rng.0, 4u32, 8);
+            assert!((4..8).contains(&v));
+        }
+    }
+}
+
+#[cfg(test)]
+mod aoe_resolution_tests {
+    use super::*;
+    use crate::combat_ability::{AbilityBuilder, AbilityShape};
+    use crate::status_effects::ApplyStatusEvent;
+
+    fn stats(hit: i32, evasion: i32, armor: i32) -> CombatStats {
+        CombatStats {
+            health: <StatPool<i32>>::new(100),
+            lethality: <StatPool<i32>>::new(10),
+            hit: <StatPool<i32>>::new(hit),
+            evasion: <StatPool<i32>>::new(evasion),
+            armor: <StatPool<i32>>::new(armor),
+            ..Default::default()
+        }
+    }
+
+    /// An area cast on three identical targets at even odds: each rolls its
+    /// own hit, so under this seed one dodges, one is struck and one is crit.
+    /// A single roll shared by the cast would treat all three alike.
+    #[test]
+    fn area_targets_resolve_hits_independently() {
+        let mut app = App::new();
+        app.add_message::<AttackIntentEvent>()
+            .add_message::<BeforeAttackEvent>()
+            .add_message::<DamageEvent>()
+            .add_message::<ApplyStatusEvent>()
+            .init_resource::<DamageQueue>()
+            .insert_resource(CombatRng::seeded(2511))
+            .add_systems(
+                Update,
+                (
+                    process_attack_intent,
+                    queue_damage_from_before_attack,
+                    process_damage_queue_system,
+                )
+                    .chain(),
+            );
+        let world = app.world_mut();
+        let caster = world.spawn(stats(0, 0, 0)).id();
+        let targets = [(); 3].map(|_| world.spawn(stats(0, 0, 0)).id());
+
+        let ability = AbilityBuilder::new(9, "Shockwave")
+            .damage(10, 10, DamageType::Physical, Stat::Lethality, Stat::Armor)
+            .shape(AbilityShape::Radius(3.0))
+            .build();
+        for target in targets {
+            world.write_message(AttackIntentEvent {
+                attacker: caster,
+                target,
+                ability: Some(ability.clone()),
+                context: AttackContext {
+                    damage_type: Some(DamageType::Physical),
+                    extra_flat_damage: 10,
+                    ..Default::default()
+                },
+                cause: ActionCause::Ability { id: ability.id },
+            });
+        }
+        app.update();
+
+        let damage: Vec<(Entity, i32)> = app
+            .world()
+            .resource::<Messages<DamageEvent>>()
+            .iter_current_update_messages()
+            .map(|d| (d.target, d.amount))
+            .collect();
+
+        assert_eq!(damage.len(), 2, "one of the three dodged: {damage:?}");
+        assert_ne!(damage[0].0, damage[1].0);
+        assert!(damage.iter().all(|(_, amount)| *amount > 0));
+        assert_ne!(damage[0].1, damage[1].1, "each hit rolled its own crit: {damage:?}");
+    }
+
+    /// A cone with falloff: the target at the caster's feet takes the full
+    /// rolled damage, the one at the rim only the minimum fraction.
+    #[test]
+    fn cone_falloff_reduces_damage_with_distance() {
+        let mut app = App::new();
+        add_player_action_messages(&mut app)
+            .add_message::<BeforeAttackEvent>()
+            .init_resource::<DamageQueue>()
+            .insert_resource(CombatRng::seeded(2501));
+        let world = app.world_mut();
+        let caster = world.spawn(stats(1000, 0, 0)).id();
+        let near = world.spawn(stats(0, 0, 0)).id();
+        let far = world.spawn(stats(0, 0, 0)).id();
+
+        let ability = AbilityBuilder::new(10, "Fox Fire")
+            .damage(20, 20, DamageType::Fire, Stat::Lethality, Stat::Armor)
+            .shape(AbilityShape::Cone { angle: 60.0, radius: 100.0 })
+            .falloff(0.5)
+            .build();
+        let affected = affected_with_falloff(
+            &ability,
+            caster,
+            (0.0, 0.0),
+            (1.0, 0.0),
+            [(caster, (0.0, 0.0)), (near, (1.0, 0.0)), (far, (100.0, 0.0))].into_iter(),
+        );
+        assert_eq!(affected.len(), 2);
+
+        app.add_systems(
+            Update,
+            (
+                move |mut writers: PlayerActionWriters, mut rng: ResMut<CombatRng>| {
+                    handle_ability(caster, &ability, &affected, 0, &mut rng.0, &mut writers);
+                },
+                process_attack_intent,
+                queue_damage_from_before_attack,
+            )
+                .chain(),
+        );
+        app.update();
+
+        let queued = &app.world().resource::<DamageQueue>().0;
+        let amount = |who: Entity| queued.iter().find(|q| q.target == who).unwrap().amount;
+        // Same attacker and stats, so the only difference is the scaled flat
+        // part: 20 at the caster's feet vs 20 * 0.5 at the rim.
+        assert_eq!(amount(near) - amount(far), 10);
+    }
+
+    /// Cast `ability` through `PlayerActionEvent` from a sure-handed caster at
+    /// the origin, aimed at the first of `foes` (by position), with `walls`
+    /// standing in the quadtree. Returns the app after one update and the foes.
+    fn live_cast(ability: Ability, foes: &[Vec2], walls: &[Rect]) -> (App, Vec<Entity>) {
+        use crate::battle::BattleParticipant;
+        use crate::combat_ability::AbilityTree;
+        use crate::quadtree::{Collider, QuadTree};
+
+        let id = ability.id;
+        let mut tree = AbilityTree::new();
+        tree.insert(ability);
+        let mut app = App::new();
+        add_player_action_messages(&mut app)
+            .add_message::<PlayerActionEvent>()
+            .add_message::<BeforeAttackEvent>()
+            .init_resource::<DamageQueue>()
+            .insert_resource(Ability_Tree(tree))
+            .insert_resource(Timestamp(0))
+            .init_resource::<TurnInProgress>()
+            .insert_resource(CombatRng::seeded(2501))
+            .add_systems(
+                Update,
+                (
+                    process_player_action_system,
+                    process_attack_intent,
+                    queue_damage_from_before_attack,
+                )
+                    .chain(),
+            );
+        let world = app.world_mut();
+        let walls = walls
+            .iter()
+            .map(|&bounds| (world.spawn_empty().id(), Collider { bounds }))
+            .collect();
+        world.insert_resource(QuadTree::enclosing(walls));
+        let caster = world
+            .spawn((
+                BattleParticipant,
+                Transform::default(),
+                stats(1000, 0, 0),
+                Abilities(vec![id]),
+            ))
+            .id();
+        let foes: Vec<Entity> = foes
+            .iter()
+            .map(|at| {
+                let place = Transform::from_translation(at.extend(0.0));
+                world.spawn((BattleParticipant, place, stats(0, 0, 0))).id()
+            })
+            .collect();
+        world.insert_resource(PendingPlayerAction { entity: Some(caster) });
+        world.write_message(PlayerActionEvent {
+            action: PlayerAction::UseAbility(id.into(), foes[0]),
+        });
+        app.update();
+        (app, foes)
+    }
+
+    /// The same cone cast through the real action pipeline: the foe it's
+    /// aimed at, close in, takes more than the one further down the cone.
+    #[test]
+    fn live_cone_cast_falls_off_with_distance() {
+        let ability = AbilityBuilder::new(11, "Fox Fire")
+            .damage(20, 20, DamageType::Fire, Stat::Lethality, Stat::Armor)
+            .shape(AbilityShape::Cone { angle: 60.0, radius: 100.0 })
+            .falloff(0.5)
+            .build();
+        let (app, foes) = live_cast(ability, &[Vec2::new(10.0, 0.0), Vec2::new(90.0, 0.0)], &[]);
+
+        let queued = &app.world().resource::<DamageQueue>().0;
+        let amount = |who: Entity| queued.iter().find(|q| q.target == who).unwrap().amount;
+        // 20 * 0.95 close in vs 20 * 0.55 near the rim.
+        assert_eq!(amount(foes[0]) - amount(foes[1]), 8);
+    }
+
+    /// An arena wall between the caster and one foe shields them from a
+    /// burst that reaches both.
+    #[test]
+    fn arena_wall_blocks_a_live_area_cast() {
+        let ability = AbilityBuilder::new(12, "Shockwave")
+            .damage(10, 10, DamageType::Physical, Stat::Lethality, Stat::Armor)
+            .shape(AbilityShape::Radius(100.0))
+            .build();
+        let wall = Rect::new(-30.0, -20.0, -20.0, 20.0);
+        let (app, foes) =
+            live_cast(ability, &[Vec2::new(50.0, 0.0), Vec2::new(-50.0, 0.0)], &[wall]);
+
+        let struck: Vec<Entity> = app
+            .world()
+            .resource::<Messages<AttackIntentEvent>>()
+            .iter_current_update_messages()
+            .map(|i| i.target)
+            .collect();
+        assert_eq!(struck, vec![foes[0]]);
+    }
+
+    /// Queue one cast of `ability` from a caster at 10 lethality, already
+    /// holding `held`, against a foe, returning the amount queued for the foe.
+    fn foe_damage_from(ability: Ability, held: &[StatModifier]) -> i32 {
+        let mut app = App::new();
+        add_player_action_messages(&mut app)
+            .add_message::<BeforeAttackEvent>()
+            .init_resource::<DamageQueue>()
+            .insert_resource(CombatRng::seeded(2513));
+        let world = app.world_mut();
+        let caster = world.spawn((stats(1000, 0, 0), StatModifiers(held.to_vec()))).id();
+        let foe = world.spawn(stats(0, 0, 0)).id();
+        app.add_systems(
+            Update,
+            (
+                move |mut writers: PlayerActionWriters, mut rng: ResMut<CombatRng>| {
+                    handle_ability(
+                        caster,
+                        &ability,
+                        &[(foe, 1.0)],
+                        0,
+                        &mut rng.0,
+                        &mut writers,
+                    );
+                },
+                process_attack_intent,
+                queue_damage_from_before_attack,
+            )
+                .chain(),
+        );
+        app.update();
+        let queued = &app.world().resource::<DamageQueue>().0;
+        queued.iter().find(|q| q.target == foe).unwrap().amount
+    }
+
+    /// A war cry that doubles the caster's lethality, then a strike.
+    fn kiai_strike(buff_first: bool) -> Ability {
+        let war_cry = AbilityEffect::Buff {
+            stat: Stat::Lethality,
+            multiplier: 2.0,
+            effects: None,
+            scaled_with: Stat::Mind,
+            on_caster: true,
+        };
+        let strike = AbilityEffect::Damage {
+            floor: 10,
+            ceiling: 10,
+            damage_type: DamageType::Physical,
+            scaled_with: Stat::Lethality,
+            defended_with: Stat::Armor,
+            amplify_low_morale: 0.0,
+        };
+        let (first, second) = if buff_first { (war_cry, strike) } else { (strike, war_cry) };
+        AbilityBuilder::new(13, "Kiai Strike")
+            .effect(first)
+            .effect(second)
+            .duration(2)
+            .build()
+    }
+
+    /// Doubling the caster's lethality and then striking the foe, in one
+    /// cast, strikes with the doubled lethality; the same effects the other
+    /// way round don't.
+    #[test]
+    fn buff_earlier_in_cast_reaches_its_damage() {
+        let buffed = foe_damage_from(kiai_strike(true), &[]);
+        let unbuffed = foe_damage_from(kiai_strike(false), &[]);
+        // Base lethality 10 doubles to 20; the rolled 10 and the lethality
+        // scaling are the same either way.
+        assert_eq!(buffed - unbuffed, 10);
+    }
+
+    /// An unrelated modifier on the same stat, expiring on the same turn,
+    /// isn't mistaken for the cast's own buff already being persisted.
+    #[test]
+    fn lookalike_modifier_does_not_swallow_the_cast_buff() {
+        let lookalike = StatModifier {
+            stat: Stat::Lethality,
+            multiplier: 1.0,
+            expires_at_timestamp: Some(2),
+            source: None,
+            tag: None,
+        };
+        let buffed = foe_damage_from(kiai_strike(true), &[lookalike.clone()]);
+        let unbuffed = foe_damage_from(kiai_strike(false), &[lookalike]);
+        assert_eq!(buffed - unbuffed, 10);
+    }
+}
+
+#[cfg(test)]
+mod confusion_tests {
+    use super::*;
+    use crate::battle::BattleParticipant;
+    use crate::status_effects::Confused;
+
+    fn living() -> CombatStats {
+        CombatStats {
+            health: <StatPool<i32>>::new(50),
+            ..Default::default()
+        }
+    }
+
+    /// Runs one confused basic attack under `seed` and returns
+    /// `(intended, landed_on, everyone_else)`.
+    fn confused_swing(seed: u64) -> (Entity, Entity, Vec<Entity>) {
+        let mut app = App::new();
+        app.add_message::<BeforeAttackEvent>()
+            .insert_resource(CombatRng::seeded(seed))
+            .add_systems(Update, apply_retarget_overrides_system);
+        let world = app.world_mut();
+        let attacker = world
+            .spawn((BattleParticipant, living(), Confused { remaining_turns: 2, chance: 1.0 }))
+            .id();
+        let intended = world.spawn((BattleParticipant, living())).id();
+        let others: Vec<Entity> = (0..3)
+            .map(|_| world.spawn((BattleParticipant, living())).id())
+            .collect();
+        world.write_message(BeforeAttackEvent {
+            attacker,
+            target: intended,
+            ability: None,
+            context: AttackContext::default(),
+            cause: ActionCause::Player,
+        });
+        app.update();
+
+        let messages = app.world().resource::<Messages<BeforeAttackEvent>>();
+        let landed = messages
+            .iter_current_update_messages()
+            .next()
+            .expect("attack still queued")
+            .target;
+        (intended, landed, others)
+    }
+
+    #[test]
+    fn fully_confused_attack_lands_on_a_random_other_target() {
+        let (intended, landed, others) = confused_swing(11);
+        assert_ne!(landed, intended);
+        assert!(others.contains(&landed));
+        // Same seed, same victim (both worlds spawn in the same order).
+        let (_, again, _) = confused_swing(11);
+        assert_eq!(landed, again);
+    }
+
+    #[test]
+    fn zero_chance_never_retargets() {
+        let mut rng = CombatRng::seeded(5);
+        let mut world = World::new();
+        let (a, b, c) = (
+            world.spawn_empty().id(),
+            world.spawn_empty().id(),
+            world.spawn_empty().id(),
+        );
+        for _ in 0..16 {
+            assert_eq!(roll_confused_retarget(a, b, 0.0, &[a, b, c], &mut rng.0), None);
+        }
+        assert_eq!(roll_confused_retarget(a, b, 1.0, &[a, b, c], &mut rng.0), Some(c));
+    }
+}
+
+#[cfg(test)]
+mod targeting_tests {
+    use super::*;
+
+    fn radius_ability(effect: AbilityEffect) -> Ability {
+        Ability {
+            id: 1,
+            next_id: None,
+            name: "Burst".to_string(),
+            health_cost: 0,
+            magic_cost: 0.0,
+            magic_school: MagicSchool::Kiho,
+            element: None,
+            action_point_cost: 0,
+            cooldown: 0,
+            description: String::new(),
+            effects: vec![effect],
+            shape: AbilityShape::Radius(64.0),
+            duration: 0,
+            targets: 0,
+            includes_self: None,
+            falloff: None,
+        }
+    }
+
+    fn field() -> (Entity, Entity, Entity, Vec<(Entity, (f32, f32))>) {
+        let mut world = World::new();
+        let caster = world.spawn_empty().id();
+        let near = world.spawn_empty().id();
+        let far = world.spawn_empty().id();
+        let positions = vec![(caster, (0.0, 0.0)), (near, (30.0, 0.0)), (far, (500.0, 0.0))];
+        (caster, near, far, positions)
+    }
+
+    #[test]
+    fn radius_damage_spares_the_caster() {
+        let (caster, near, _, positions) = field();
+        let ability = radius_ability(AbilityEffect::Damage {
+            floor: 5,
+            ceiling: 10,
+            damage_type: DamageType::Fire,
+            scaled_with: Stat::Lethality,
+            defended_with: Stat::Armor,
+            amplify_low_morale: 0.0,
+        });
+        let hit = affected_by_shape(&ability, caster, (0.0, 0.0), (0.0, 0.0), positions.into_iter());
+        assert_eq!(hit, vec![near]);
+    }
+
+    #[test]
+    fn radius_heal_includes_the_caster() {
+        let (caster, near, _, positions) = field();
+        let ability = radius_ability(AbilityEffect::Heal {
+            floor: 5,
+            ceiling: 10,
+            scaled_with: Stat::Mind,
+        });
+        let hit = affected_by_shape(&ability, caster, (0.0, 0.0), (0.0, 0.0), positions.into_iter());
+        assert_eq!(hit, vec![caster, near]);
+    }
+
+    #[test]
+    fn explicit_flag_overrides_the_derived_default() {
+        let (caster, near, _, positions) = field();
+        let mut ability = radius_ability(AbilityEffect::Heal {
+            floor: 5,
+            ceiling: 10,
+            scaled_with: Stat::Mind,
+        });
+        ability.includes_self = Some(false);
+        let hit = affected_by_shape(&ability, caster, (0.0, 0.0), (0.0, 0.0), positions.into_iter());
+        assert_eq!(hit, vec![near]);
+    }
+}
+
+#[cfg(test)]
+mod player_action_tests {
+    use super::*;
+    use crate::combat_ability::{AbilityBuilder, AbilityTree};
+
+    /// `caster` knows `known`; the tree holds a single 2-AP strike (id 7).
+    fn cast_app(known: Vec<u16>) -> (App, Entity, Entity) {
+        let mut tree = AbilityTree::new();
+        tree.insert(
+            AbilityBuilder::new(7, "Strike")
+                .damage(5, 5, DamageType::Physical, Stat::Lethality, Stat::Armor)
+                .action_points(2)
+                .build(),
+        );
+        let mut app = App::new();
+        add_player_action_messages(&mut app)
+            .add_message::<PlayerActionEvent>()
+            .insert_resource(Ability_Tree(tree))
+            .insert_resource(Timestamp(0))
+            .init_resource::<TurnInProgress>()
+            .insert_resource(CombatRng::seeded(2502))
+            .add_systems(Update, process_player_action_system);
+        let world = app.world_mut();
+        let caster = world
+            .spawn((
+                CombatStats {
+                    health: <StatPool<i32>>::new(50),
+                    ..Default::default()
+                },
+                Abilities(known),
+            ))
+            .id();
+        let target = world.spawn(CombatStats::default()).id();
+        world.insert_resource(PendingPlayerAction { entity: Some(caster) });
+        world.write_message(PlayerActionEvent {
+            action: PlayerAction::UseAbility(7, target),
+        });
+        (app, caster, target)
+    }
+
+    #[test]
+    fn unlearned_ability_is_rejected() {
+        let (mut app, caster, _) = cast_app(vec![]);
+        let ap_before = app.world().get::<CombatStats>(caster).unwrap().action_points.current;
+        app.update();
+
+        let failures: Vec<_> = app
+            .world()
+            .resource::<Messages<AbilityFailedEvent>>()
+            .iter_current_update_messages()
+            .map(|f| (f.actor, f.ability_id, f.reason))
+            .collect();
+        assert_eq!(failures, vec![(caster, 7, AbilityFailReason::NotLearned)]);
+        assert_eq!(
+            app.world()
+                .resource::<Messages<AttackIntentEvent>>()
+                .iter_current_update_messages()
+                .count(),
+            0
+        );
+        let ap_after = app.world().get::<CombatStats>(caster).unwrap().action_points.current;
+        assert_eq!(ap_after, ap_before, "a refused cast costs nothing");
+    }
+
+    #[test]
+    fn learned_ability_is_cast() {
+        let (mut app, _, target) = cast_app(vec![7]);
+        app.update();
+
+        let intents: Vec<Entity> = app
+            .world()
+            .resource::<Messages<AttackIntentEvent>>()
+            .iter_current_update_messages()
+            .map(|i| i.target)
+            .collect();
+        assert_eq!(intents, vec![target]);
+        assert!(app
+            .world()
+            .resource::<Messages<AbilityFailedEvent>>()
+            .is_empty());
+    }
+}
+
+#[cfg(test)]
+mod action_staging_tests {
+    use super::*;
+
+    fn staging_app() -> (App, Entity, Entity) {
+        let mut app = App::new();
+        add_player_action_messages(&mut app)
+            .add_message::<PlayerActionEvent>()
+            .add_message::<StageActionEvent>()
+            .add_message::<ConfirmStagedActionEvent>()
+            .add_message::<UndoStagedActionEvent>()
+            .add_message::<BeforeAttackEvent>()
+            .add_message::<DamageEvent>()
+            .add_message::<AfterHitEvent>()
+            .add_message::<ItemUsedEvent>()
+            .add_message::<DeathEvent>()
+            .insert_resource(Timestamp(0))
+            .insert_resource(ActionStaging { enabled: true, staged: None })
+            .init_resource::<TurnInProgress>()
+            .init_resource::<DamageQueue>()
+            .init_resource::<InventoryItemCatalog>()
+            .insert_resource(CombatRng::seeded(2525))
+            .add_systems(
+                Update,
+                (
+                    follow_confirm_actions_setting,
+                    stage_player_action_system,
+                    process_player_action_system,
+                    process_attack_intent,
+                    queue_damage_from_before_attack,
+                    process_damage_queue_system,
+                    apply_damage_system,
+                )
+                    .chain(),
+            );
+        let world = app.world_mut();
+        let hero = world
+            .spawn(CombatStats {
+                health: <StatPool<i32>>::new(50),
+                lethality: <StatPool<i32>>::new(20),
+                hit: <StatPool<i32>>::new(1000),
+                ..Default::default()
+            })
+            .id();
+        let foe = world
+            .spawn(CombatStats { health: <StatPool<i32>>::new(60), ..Default::default() })
+            .id();
+        world.insert_resource(PendingPlayerAction { entity: Some(hero) });
+        (app, hero, foe)
+    }
+
+    #[test]
+    fn undone_attack_leaves_the_target_untouched_and_the_turn_open() {
+        let (mut app, hero, foe) = staging_app();
+        let ap_before = app.world().get::<CombatStats>(hero).unwrap().action_points.current;
+        app.world_mut().write_message(StageActionEvent { action: PlayerAction::Attack(foe) });
+        app.update();
+        assert!(matches!(
+            app.world().resource::<ActionStaging>().staged_action(),
+            Some(PlayerAction::Attack(t)) if *t == foe
+        ));
+
+        app.world_mut().write_message(UndoStagedActionEvent);
+        app.update();
+        // A confirm with nothing staged does nothing either.
+        app.world_mut().write_message(ConfirmStagedActionEvent);
+        app.update();
+
+        let world = app.world();
+        assert_eq!(world.get::<CombatStats>(foe).unwrap().health.current, 60);
+        assert_eq!(world.get::<CombatStats>(hero).unwrap().action_points.current, ap_before);
+        assert!(world.resource::<ActionStaging>().staged.is_none());
+        assert_eq!(world.resource::<PendingPlayerAction>().entity, Some(hero));
+    }
+
+    /// Switching the setting off mid-pick drops the pick without resolving
+    /// it; the turn stays open.
+    #[test]
+    fn turning_confirm_off_drops_the_staged_pick() {
+        use crate::settings::ConfirmActions;
+
+        let (mut app, hero, foe) = staging_app();
+        app.insert_resource(ConfirmActions(true));
+        app.world_mut().write_message(StageActionEvent { action: PlayerAction::Attack(foe) });
+        app.update();
+        assert!(app.world().resource::<ActionStaging>().staged.is_some());
+
+        app.insert_resource(ConfirmActions(false));
+        app.update();
+
+        let world = app.world();
+        assert!(!world.resource::<ActionStaging>().enabled);
+        assert!(world.resource::<ActionStaging>().staged.is_none());
+        assert_eq!(world.get::<CombatStats>(foe).unwrap().health.current, 60);
+        assert_eq!(world.resource::<PendingPlayerAction>().entity, Some(hero));
+    }
+
+    #[test]
+    fn confirmed_attack_lands() {
+        let (mut app, _, foe) = staging_app();
+        app.world_mut().write_message(StageActionEvent { action: PlayerAction::Attack(foe) });
+        app.world_mut().write_message(ConfirmStagedActionEvent);
+        app.update();
+
+        assert!(app.world().get::<CombatStats>(foe).unwrap().health.current < 60);
+    }
+}
+
+#[cfg(test)]
+mod tutorial_tests {
+    use super::*;
+    use crate::combat_tutorial::{
+        advance_combat_tutorial_system, first_battle_tutorial, CombatTutorial,
+        FIRST_BATTLE_TUTORIAL_FLAG,
+    };
+    use crate::constants::BASIC_ATTACK_ACTION_POINT_COST;
+    use crate::story_flags::{FlagChangedEvent, StoryFlags};
+
+    /// The real onboarding script, with AP for exactly one attack per turn:
+    /// every step's action closes the turn it's taken in, so the step must be
+    /// credited after the pending turn has already been cleared.
+    #[test]
+    fn only_the_scripted_action_is_taken_and_finishing_sets_the_flag() {
+        let mut app = App::new();
+        add_player_action_messages(&mut app)
+            .add_message::<PlayerActionEvent>()
+            .add_message::<FlagChangedEvent>()
+            .init_resource::<StoryFlags>()
+            .insert_resource(Timestamp(0))
+            .init_resource::<TurnInProgress>()
+            .insert_resource(CombatRng::seeded(2528))
+            .insert_resource(first_battle_tutorial())
+            .add_systems(
+                Update,
+                (process_player_action_system, advance_combat_tutorial_system).chain(),
+            );
+        let world = app.world_mut();
+        let hero_stats = CombatStats {
+            health: <StatPool<i32>>::new(50),
+            action_points: <StatPool<i32>>::new(BASIC_ATTACK_ACTION_POINT_COST),
+            ..Default::default()
+        };
+        let hero = world.spawn((hero_stats, PlayerControlled)).id();
+        let foe = world
+            .spawn(CombatStats { health: <StatPool<i32>>::new(50), ..Default::default() })
+            .id();
+        let take_turn = |app: &mut App, action: PlayerAction| {
+            let world = app.world_mut();
+            world.insert_resource(PendingPlayerAction { entity: Some(hero) });
+            if let Some(mut stats) = world.get_mut::<CombatStats>(hero) {
+                stats.action_points.current = stats.action_points.base;
+            }
+            world.write_message(PlayerActionEvent { action });
+            app.update();
+        };
+        let ap = |app: &App| app.world().get::<CombatStats>(hero).unwrap().action_points.current;
+        let step = |app: &App| app.world().resource::<CombatTutorial>().current;
+
+        // Off script: refused, nothing spent, the lesson stays open.
+        take_turn(&mut app, PlayerAction::Defend);
+        assert_eq!(ap(&app), BASIC_ATTACK_ACTION_POINT_COST);
+        assert!(app.world().resource::<Messages<DefendIntentEvent>>().is_empty());
+        assert_eq!(step(&app), 0);
+
+        // On script, each step ends the turn as it lands and still counts.
+        take_turn(&mut app, PlayerAction::Attack(foe));
+        assert_eq!(ap(&app), 0);
+        assert!(app.world().resource::<PendingPlayerAction>().entity.is_none());
+        assert_eq!(step(&app), 1);
+        take_turn(&mut app, PlayerAction::Defend);
+        assert_eq!(step(&app), 2);
+        assert!(!app.world().resource::<StoryFlags>().is_set(FIRST_BATTLE_TUTORIAL_FLAG));
+        take_turn(&mut app, PlayerAction::Attack(foe));
+        assert!(app.world().resource::<StoryFlags>().is_set(FIRST_BATTLE_TUTORIAL_FLAG));
+        assert!(app.world().get_resource::<CombatTutorial>().is_none());
+    }
+}
+
+#[cfg(test)]
+mod attack_context_tests {
+    use super::*;
+    use crate::status_effects::ApplyStatusEvent;
+
+    #[derive(Resource, Default)]
+    struct Queued(i32);
+
+    fn record_queued(dq: Res<DamageQueue>, mut queued: ResMut<Queued>) {
+        queued.0 = dq.0.iter().map(|q| q.amount).sum();
+    }
+
+    /// One basic attack through the pipeline, with an extra before-attack
+    /// listener adding `flat` and `lethality` to the context. Returns the
+    /// damage as queued and as finally dealt.
+    fn attack_with(flat: i32, lethality: i32) -> (i32, i32) {
+        let mut app = App::new();
+        app.add_message::<AttackIntentEvent>()
+            .add_message::<BeforeAttackEvent>()
+            .add_message::<DamageEvent>()
+            .add_message::<ApplyStatusEvent>()
+            .init_resource::<DamageQueue>()
+            .init_resource::<Queued>()
+            .insert_resource(CombatRng::seeded(2533))
+            .add_systems(
+                Update,
+                (
+                    process_attack_intent,
+                    move |mut events: MessageMutator<BeforeAttackEvent>| {
+                        for ev in events.read() {
+                            ev.context.extra_flat_damage += flat;
+                            ev.context.base_lethality += lethality;
+                        }
+                    },
+                    queue_damage_from_before_attack,
+                    record_queued,
+                    process_damage_queue_system,
+                )
+                    .chain(),
+            );
+        let world = app.world_mut();
+        let attacker = world
+            .spawn(CombatStats {
+                lethality: <StatPool<i32>>::new(10),
+                hit: <StatPool<i32>>::new(1000),
+                ..Default::default()
+            })
+            .id();
+        let target = world
+            .spawn(CombatStats { health: <StatPool<i32>>::new(500), ..Default::default() })
+            .id();
+        world.write_message(AttackIntentEvent {
+            attacker,
+            target,
+            ability: None,
+            context: AttackContext::default(),
+            cause: ActionCause::Player,
+        });
+        app.update();
+
+        let dealt = app
+            .world()
+            .resource::<Messages<DamageEvent>>()
+            .iter_current_update_messages()
+            .find(|d| d.target == target)
+            .map(|d| d.amount)
+            .expect("the attack lands");
+        (app.world().resource::<Queued>().0, dealt)
+    }
+
+    #[test]
+    fn before_attack_flat_bonus_reaches_the_final_damage() {
+        let (queued, dealt) = attack_with(0, 0);
+        let (buffed_queued, buffed_dealt) = attack_with(20, 0);
+        assert_eq!(buffed_queued, queued + 20);
+        // Same seed, same roll: any crit scales both, so the gap is at least 20.
+        assert!(buffed_dealt >= dealt + 20, "{buffed_dealt} vs {dealt}");
+    }
+
+    /// A listener's additive lethality lands on top of the attacker's own,
+    /// rather than standing in for it.
+    #[test]
+    fn before_attack_lethality_adds_to_the_attackers_stats() {
+        let (queued, _) = attack_with(0, 0);
+        let (buffed, _) = attack_with(0, 20);
+        assert_eq!(buffed, queued + 20);
+    }
+}
+
+#[cfg(test)]
+mod combat_speed_tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::settings::CombatSpeed;
+    use crate::test_support::{advance_frames, app_with_manual_time};
+
+    #[derive(Resource, Default)]
+    struct TurnStarts(Vec<f32>);
+
+    fn record_turn_starts(
+        time: Res<Time>,
+        mut starts: MessageReader<TurnStartEvent>,
+        mut log: ResMut<TurnStarts>,
+    ) {
+        for _ in starts.read() {
+            log.0.push(time.elapsed_secs());
+        }
+    }
+
+    /// Seconds between the first two turns of an AI-only fight at `speed`.
+    fn gap_between_turns(speed: f32) -> f32 {
+        let mut app = app_with_manual_time();
+        app.add_message::<TurnStartEvent>()
+            .add_message::<TurnEndEvent>()
+            .add_message::<TurnOrderCalculatedEvent>()
+            .add_message::<RoundEndEvent>()
+            .add_message::<AttackIntentEvent>()
+            .init_resource::<TurnManager>()
+            .init_resource::<TurnOrder>()
+            .init_resource::<TurnInProgress>()
+            .init_resource::<TurnPacing>()
+            .init_resource::<TurnStarts>()
+            .insert_resource(CombatSpeed(speed))
+            .add_systems(
+                Update,
+                (
+                    register_participants_system,
+                    turn_pacing_system,
+                    compute_turn_order_system.run_if(turn_pacing_ready),
+                    auto_advance_after_order,
+                    on_turn_start_system,
+                    record_turn_starts,
+                )
+                    .chain(),
+            );
+        // The quick one earns exactly one turn per order pass; the idle one
+        // never does, and is just there to be attacked.
+        let world = app.world_mut();
+        world.spawn((
+            CombatStats { speed: <StatPool<i32>>::new(1000), ..Default::default() },
+            AccumulatedSpeed(0),
+        ));
+        world.spawn((CombatStats::default(), AccumulatedSpeed(0)));
+
+        advance_frames(&mut app, Duration::from_millis(25), 80);
+        let starts = &app.world().resource::<TurnStarts>().0;
+        assert!(starts.len() >= 2, "expected two turns, got {starts:?}");
+        starts[1] - starts[0]
+    }
+
+    #[test]
+    fn doubling_combat_speed_halves_the_pause_between_turns() {
+        let normal = gap_between_turns(1.0);
+        let doubled = gap_between_turns(2.0);
+        assert!(normal >= TURN_TRANSITION_SECONDS - 0.03, "{normal}");
+        // Frame quantisation (25 ms, doubled on the fast side) aside, half.
+        assert!((normal - 2.0 * doubled).abs() <= 0.1, "{normal} vs {doubled}");
+    }
+}
+
+#[cfg(test)]
+mod morale_swing_tests {
+    use super::*;
+
+    /// Crits landed by `morale`/100 attacker over `swings` basic attacks.
+    fn crits(morale: i32, swings: usize) -> usize {
+        let mut app = App::new();
+        app.add_message::<BeforeAttackEvent>()
+            .init_resource::<DamageQueue>()
+            .init_resource::<MoraleSwing>()
+            .insert_resource(CombatRng::seeded(2503))
+            .add_systems(Update, queue_damage_from_before_attack);
+        let world = app.world_mut();
+        let attacker = world
+            .spawn(CombatStats {
+                morale: StatPool { current: morale, base: 100 },
+                ..Default::default()
+            })
+            .id();
+        let target = world.spawn(CombatStats::default()).id();
+        for _ in 0..swings {
+            world.write_message(BeforeAttackEvent {
+                attacker,
+                target,
+                ability: None,
+                context: AttackContext::default(),
+                cause: ActionCause::Player,
+            });
+        }
+        app.update();
+        app.world()
+            .resource::<DamageQueue>()
+            .0
+            .iter()
+            .filter(|q| q.tags.iter().any(|t| matches!(t, DamageTag::Critical)))
+            .count()
+    }
+
+    #[test]
+    fn high_morale_crits_more_than_low_morale() {
+        let (high, low) = (crits(100, 1000), crits(0, 1000));
+        assert!(high > low * 2, "high morale {high} crits vs low morale {low}");
+    }
+
+    #[test]
+    fn morale_lean_is_neutral_at_half() {
+        assert_eq!(MoraleSwing::lean(&StatPool { current: 50, base: 100 }), 0.0);
+        assert_eq!(MoraleSwing::lean(&StatPool { current: 100, base: 100 }), 1.0);
+        assert_eq!(MoraleSwing::lean(&StatPool { current: 0, base: 100 }), -1.0);
+        assert_eq!(MoraleSwing::lean(&StatPool { current: 0, base: 0 }), 0.0);
+    }
+}
+
+#[cfg(test)]
+mod environmental_damage_tests {
+    use super::*;
+    use crate::status_effects::ApplyStatusEvent;
+
+    #[test]
+    fn environmental_kill_has_no_killer() {
+        let mut app = App::new();
+        app.add_message::<DamageEvent>()
+            .add_message::<AfterHitEvent>()
+            .add_message::<ItemUsedEvent>()
+            .add_message::<DeathEvent>()
+            .add_message::<ApplyStatusEvent>()
+            .init_resource::<DamageQueue>()
+            .init_resource::<InventoryItemCatalog>()
+            .init_resource::<CombatRng>()
+            .add_systems(Update, (process_damage_queue_system, apply_damage_system).chain());
+        let world = app.world_mut();
+        let victim = world
+            .spawn(CombatStats {
+                health: <StatPool<i32>>::new(10),
+                armor: <StatPool<i32>>::new(50),
+                ..Default::default()
+            })
+            .id();
+        let bystander = world
+            .spawn(CombatStats {
+                health: <StatPool<i32>>::new(100),
+                ..Default::default()
+            })
+            .id();
+        deal_environmental_damage(
+            &mut world.resource_mut::<DamageQueue>(),
+            &[victim, bystander],
+            25,
+            DamageType::Fire,
+        );
+        app.update();
+
+        let deaths: Vec<(Entity, Option<Entity>)> = app
+            .world()
+            .resource::<Messages<DeathEvent>>()
+            .iter_current_update_messages()
+            .map(|d| (d.entity, d.killer))
+            .collect();
+        assert_eq!(deaths, vec![(victim, None)]);
+        let bystander_hp = app.world().get::<CombatStats>(bystander).unwrap().health.current;
+        assert_eq!(bystander_hp, 75);
+    }
+
+    #[test]
+    fn hazard_kill_has_no_killer() {
+        use crate::battle::{
+            obstacle_aura_tick_system, AuraEffect, AuraTargets, BattleParticipant, BattleSide,
+            ObstacleAura,
+        };
+
+        let mut app = App::new();
+        app.add_message::<DamageEvent>()
+            .add_message::<AfterHitEvent>()
+            .add_message::<ItemUsedEvent>()
+            .add_message::<DeathEvent>()
+            .add_message::<ApplyStatusEvent>()
+            .add_message::<RoundEndEvent>()
+            .init_resource::<InventoryItemCatalog>()
+            .init_resource::<CombatRng>()
+            .add_systems(Update, (obstacle_aura_tick_system, apply_damage_system).chain());
+        let world = app.world_mut();
+        world.spawn((
+            Transform::default(),
+            ObstacleAura {
+                radius: 64.0,
+                effect: AuraEffect::Damage { amount: 25, damage_type: DamageType::Fire },
+                affects: AuraTargets::All,
+            },
+        ));
+        let victim = world
+            .spawn((
+                BattleParticipant,
+                BattleSide::Ally,
+                Transform::from_xyz(32.0, 0.0, 0.0),
+                CombatStats {
+                    health: <StatPool<i32>>::new(10),
+                    ..Default::default()
+                },
+            ))
+            .id();
+        world.write_message(RoundEndEvent);
+        app.update();
+
+        let deaths: Vec<(Entity, Option<Entity>)> = app
+            .world()
+            .resource::<Messages<DeathEvent>>()
+            .iter_current_update_messages()
+            .map(|d| (d.entity, d.killer))
+            .collect();
+        assert_eq!(deaths, vec![(victim, None)]);
+    }
+}
+
+#[cfg(test)]
+mod damage_priority_tests {
+    use super::*;
+    use crate::status_effects::ApplyStatusEvent;
+
+    /// Raw hit pushed first, ward second: the ward still resolves first.
+    #[test]
+    fn ward_soaks_a_hit_queued_before_it() {
+        let mut app = App::new();
+        app.add_message::<DamageEvent>()
+            .add_message::<ApplyStatusEvent>()
+            .init_resource::<DamageQueue>()
+            .add_systems(Update, process_damage_queue_system);
+        let world = app.world_mut();
+        let target = world.spawn(CombatStats::default()).id();
+        let mut dq = world.resource_mut::<DamageQueue>();
+        deal_environmental_damage(&mut dq, &[target], 20, DamageType::True);
+        queue_absorb(&mut dq, None, target, 15);
+        app.update();
+
+        let dealt: Vec<i32> = app
+            .world()
+            .resource::<Messages<DamageEvent>>()
+            .iter_current_update_messages()
+            .map(|d| d.amount)
+            .collect();
+        assert_eq!(dealt, vec![5]);
+    }
+}
+
+#[cfg(test)]
+mod mitigation_tests {
+    use super::*;
+    use crate::status_effects::ApplyStatusEvent;
+
+    /// A plate-clad target: 30 armor turns a 40-point blow into 10, but with
+    /// no resist the same 40 as fire lands in full.
+    #[test]
+    fn armor_stops_blows_but_not_fire() {
+        let mut app = App::new();
+        app.add_message::<DamageEvent>()
+            .add_message::<ApplyStatusEvent>()
+            .init_resource::<DamageQueue>()
+            .add_systems(Update, process_damage_queue_system);
+        let plated = || CombatStats {
+            health: <StatPool<i32>>::new(100),
+            armor: <StatPool<i32>>::new(30),
+            resist: <StatPool<i32>>::new(0),
+            ..Default::default()
+        };
+        let world = app.world_mut();
+        let attacker = world.spawn(CombatStats::default()).id();
+        let struck = world.spawn(plated()).id();
+        let burned = world.spawn(plated()).id();
+        for (target, damage_type) in [(struck, DamageType::Physical), (burned, DamageType::Fire)] {
+            world.resource_mut::<DamageQueue>().0.push(QueuedDamage {
+                attacker: Some(attacker),
+                target,
+                amount: 40,
+                damage_type,
+                element: None,
+                scaled_with: vec![],
+                defended_with: vec![(Stat::Armor, 1.0)],
+                accuracy_override: None,
+                crit_multiplier: 1.0,
+                tags: vec![],
+                cause: ActionCause::Ai,
+                priority: DamagePriority::Raw,
+            });
+        }
+        app.update();
+
+        let dealt = |who: Entity| {
+            app.world()
+                .resource::<Messages<DamageEvent>>()
+                .iter_current_update_messages()
+                .find(|d| d.target == who)
+                .map(|d| d.amount)
+        };
+        assert_eq!(dealt(struck), Some(10));
+        assert_eq!(dealt(burned), Some(40));
+    }
+}
+
+#[cfg(test)]
+mod resource_drain_tests {
+    use super::*;
+    use crate::status_effects::ResourceKind;
+
+    fn caster_stats(kiho: f32) -> CombatStats {
+        let mut stats = CombatStats { kiho: <StatPool<f32>>::new(50.0), ..Default::default() };
+        stats.kiho.current = kiho;
+        stats
+    }
+
+    /// Burn 5..15 of the target's Ki; in transfer mode the caster gains
+    /// exactly what the target lost.
+    #[test]
+    fn transfer_drain_moves_rolled_magic_to_caster() {
+        let mut app = App::new();
+        add_player_action_messages(&mut app).insert_resource(CombatRng::seeded(2508));
+        let world = app.world_mut();
+        let caster = world.spawn(caster_stats(10.0)).id();
+        let target = world.spawn(caster_stats(40.0)).id();
+        let ability = AbilityBuilder::new(11, "Ki Siphon")
+            .magic(MagicSchool::Kiho, 0.0)
+            .resource_drain(ResourceKind::Magic, 5, 15, true)
+            .build();
+        assert!(ability.validate().is_ok());
+
+        app.add_systems(
+            Update,
+            (
+                move |mut writers: PlayerActionWriters, mut rng: ResMut<CombatRng>| {
+                    handle_ability(caster, &ability, &[(target, 1.0)], 0, &mut rng.0, &mut writers);
+                },
+                apply_resource_drain_system,
+            )
+                .chain(),
+        );
+        app.update();
+
+        let kiho = |who: Entity| app.world().get::<CombatStats>(who).unwrap().kiho.current;
+        let drained = 40.0 - kiho(target);
+        assert!((5.0..=15.0).contains(&drained), "rolled drain {drained}");
+        assert_eq!(kiho(caster), 10.0 + drained);
+    }
+
+    #[test]
+    fn health_drain_fails_validation() {
+        let ability = AbilityBuilder::new(12, "Bloodletting")
+            .resource_drain(ResourceKind::Health, 1, 2, false)
+            .build();
+        assert_eq!(ability.validate(), Err(AbilityValidationError::DrainsHealth { index: 0 }));
+    }
+}
+
+#[cfg(test)]
+mod wait_until_tests {
+    use super::*;
+    use crate::status_effects::ApplyStatusEvent;
+    use crate::test_support::{enemy_deaths, run_until};
+
+    #[test]
+    fn waits_for_the_target_of_a_lethal_blow_to_die() {
+        let mut app = App::new();
+        app.add_message::<DamageEvent>()
+            .add_message::<AfterHitEvent>()
+            .add_message::<ItemUsedEvent>()
+            .add_message::<DeathEvent>()
+            .add_message::<ApplyStatusEvent>()
+            .add_message::<LootEvent>()
+            .add_message::<AwardXpEvent>()
+            .init_resource::<DamageQueue>()
+            .init_resource::<InventoryItemCatalog>()
+            .init_resource::<TurnManager>()
+            .init_resource::<CombatRng>()
+            .add_systems(
+                Update,
+                (process_damage_queue_system, apply_damage_system, enemy_deaths).chain(),
+            );
+        let world = app.world_mut();
+        let attacker = world.spawn(CombatStats::default()).id();
+        let victim = world
+            .spawn(CombatStats { health: <StatPool<i32>>::new(12), ..Default::default() })
+            .id();
+        world.resource_mut::<DamageQueue>().0.push(QueuedDamage {
+            attacker: Some(attacker),
+            target: victim,
+            amount: 40,
+            damage_type: DamageType::True,
+            element: None,
+            scaled_with: vec![],
+            defended_with: vec![],
+            accuracy_override: None,
+            crit_multiplier: 1.0,
+            tags: vec![],
+            cause: ActionCause::Ai,
+            priority: DamagePriority::Raw,
+        });
+
+        assert!(run_until(&mut app, |w| w.get::<Dead>(victim).is_some(), 10));
+        // Nobody hits the attacker, so the budget runs out.
+        assert!(!run_until(&mut app, |w| w.get::<Dead>(attacker).is_some(), 5));
+    }
+}
+
+#[cfg(test)]
+mod level_cap_tests {
+    use super::*;
+
+    #[test]
+    fn xp_past_the_cap_stops_at_the_max_level() {
+        let mut app = App::new();
+        app.add_message::<AwardXpEvent>()
+            .add_message::<LevelUpEvent>()
+            .add_systems(Update, award_xp_system);
+        let near_cap = u32::from(MAX_CHARACTER_LEVEL) - 1;
+        let veteran = app
+            .world_mut()
+            .spawn((Experience(experience_at_level(near_cap)), Level(near_cap)))
+            .id();
+
+        let level_ups = |app: &App| -> Vec<(u16, u16)> {
+            app.world()
+                .resource::<Messages<LevelUpEvent>>()
+                .iter_current_update_messages()
+                .map(|ev| (ev.old_level, ev.new_level))
+                .collect()
+        };
+        for _ in 0..2 {
+            app.world_mut().write_message(AwardXpEvent { recipient: veteran, amount: u32::MAX });
+        }
+        app.update();
+        assert_eq!(level_ups(&app), vec![(MAX_CHARACTER_LEVEL - 1, MAX_CHARACTER_LEVEL)]);
+        assert_eq!(app.world().get::<Experience>(veteran).unwrap().0, u32::MAX);
+        assert_eq!(app.world().get::<Level>(veteran).unwrap().0, u32::from(MAX_CHARACTER_LEVEL));
+
+        app.world_mut().write_message(AwardXpEvent { recipient: veteran, amount: 1 });
+        app.update();
+        assert!(level_ups(&app).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod xp_award_tests {
+    use super::*;
+    use crate::battle::BattleSide;
+
+    fn kill_xp_app() -> App {
+        let mut app = App::new();
+        app.add_message::<DeathEvent>()
+            .add_message::<AwardXpEvent>()
+            .add_systems(Update, award_kill_xp_system);
+        app
+    }
+
+    /// XP awarded to `killer_level` for felling a foe of `foe_level`.
+    fn award(killer_level: u32, foe_level: u32) -> u32 {
+        let mut app = kill_xp_app();
+        let world = app.world_mut();
+        let killer =
+            world.spawn((BattleSide::Ally, Experience(experience_at_level(killer_level)))).id();
+        let foe = world.spawn((BattleSide::Enemy, Experience(experience_at_level(foe_level)))).id();
+        world.write_message(DeathEvent { entity: foe, killer: Some(killer) });
+        app.update();
+
+        let awards: Vec<(Entity, u32)> = app
+            .world()
+            .resource::<Messages<AwardXpEvent>>()
+            .iter_current_update_messages()
+            .map(|a| (a.recipient, a.amount))
+            .collect();
+        assert_eq!(awards.len(), 1);
+        assert_eq!(awards[0].0, killer);
+        awards[0].1
+    }
+
+    #[test]
+    fn felling_a_stronger_foe_pays_more_than_the_reverse() {
+        let underdog = award(1, 10);
+        let bully = award(10, 1);
+        assert!(underdog > bully, "underdog {underdog} vs bully {bully}");
+        assert!(underdog > BASE_KILL_XP && bully < BASE_KILL_XP);
+        assert_eq!(award(5, 5), BASE_KILL_XP);
+    }
+
+    #[test]
+    fn only_the_party_felling_an_enemy_earns_xp() {
+        let mut app = kill_xp_app();
+        let world = app.world_mut();
+        let hero = world.spawn((BattleSide::Ally, Experience(0))).id();
+        let foe = world.spawn((BattleSide::Enemy, Experience(0))).id();
+        let rival = world.spawn((BattleSide::Enemy, Experience(0))).id();
+        world.write_message(DeathEvent { entity: hero, killer: Some(foe) });
+        world.write_message(DeathEvent { entity: foe, killer: Some(rival) });
+        world.write_message(DeathEvent { entity: rival, killer: None });
+        app.update();
+
+        assert!(app.world().resource::<Messages<AwardXpEvent>>().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod revive_tests {
+    use super::*;
+
+    fn downed(base: i32) -> CombatStats {
+        let mut stats = CombatStats { health: <StatPool<i32>>::new(base), ..Default::default() };
+        stats.health.current = 0;
+        stats
+    }
+
+    /// A cleric's prayer raises the fallen ally at half health and back into
+    /// the rotation; the same prayer does nothing for the permanently dead.
+    #[test]
+    fn revived_ally_rejoins_the_turn_order() {
+        let mut app = App::new();
+        add_player_action_messages(&mut app)
+            .init_resource::<TurnManager>()
+            .insert_resource(CombatRng::seeded(2526));
+        let world = app.world_mut();
+        let cleric = world
+            .spawn(CombatStats { health: <StatPool<i32>>::new(60), ..Default::default() })
+            .id();
+        let fallen = world.spawn((downed(80), Dead)).id();
+        let lost = world.spawn((downed(80), Dead, PermanentlyDead)).id();
+        world.resource_mut::<TurnManager>().participants = vec![cleric];
+        let ability = AbilityBuilder::new(13, "Call the Soul Back")
+            .effect(AbilityEffect::Revive { hp_fraction: 0.5 })
+            .build();
+        assert!(ability.validate().is_ok());
+
+        app.add_systems(
+            Update,
+            (
+                move |mut writers: PlayerActionWriters, mut rng: ResMut<CombatRng>| {
+                    handle_ability(
+                        cleric,
+                        &ability,
+                        &[(fallen, 1.0), (lost, 1.0)],
+                        0,
+                        &mut rng.0,
+                        &mut writers,
+                    );
+                },
+                apply_revive_system,
+            )
+                .chain(),
+        );
+        app.update();
+
+        let world = app.world();
+        assert!(world.get::<Dead>(fallen).is_none());
+        assert_eq!(world.get::<CombatStats>(fallen).unwrap().health.current, 40);
+        assert!(world.get::<Dead>(lost).is_some());
+        assert_eq!(world.get::<CombatStats>(lost).unwrap().health.current, 0);
+        assert_eq!(world.resource::<TurnManager>().participants, vec![cleric, fallen]);
+    }
+
+    /// The whole path a battle runs: a blow fells Kaito, the battle bridge lays
+    /// him down on the field, a prayer aimed at the standing cleric is refused
+    /// before it costs anything, and the one aimed at Kaito raises him on both
+    /// his combat and world entities.
+    #[test]
+    fn ally_felled_in_battle_is_revived_on_the_field() {
+        use crate::battle::{bridge_player_death_to_world, BattleEndEvent, BattleParticipant};
+        use crate::battle::{BattleSide, BattleState, BattleWorldLink};
+        use crate::combat_ability::AbilityTree;
+        use crate::core::{GameState, Game_State};
+
+        let mut tree = AbilityTree::new();
+        tree.insert(
+            AbilityBuilder::new(13, "Call the Soul Back")
+                .effect(AbilityEffect::Revive { hp_fraction: 0.5 })
+                .action_points(2)
+                .build(),
+        );
+        let mut app = App::new();
+        add_player_action_messages(&mut app)
+            .add_message::<PlayerActionEvent>()
+            .add_message::<DamageEvent>()
+            .add_message::<AfterHitEvent>()
+            .add_message::<ItemUsedEvent>()
+            .add_message::<DeathEvent>()
+            .add_message::<BattleEndEvent>()
+            .insert_resource(Ability_Tree(tree))
+            .insert_resource(Timestamp(0))
+            .insert_resource(CombatRng::seeded(2526))
+            .insert_resource(GameState(Game_State::Battle))
+            .init_resource::<InventoryItemCatalog>()
+            .init_resource::<TurnInProgress>()
+            .init_resource::<TurnManager>()
+            .init_resource::<TurnOrder>()
+            .init_resource::<PendingPlayerAction>()
+            .add_systems(
+                Update,
+                (
+                    apply_damage_system,
+                    bridge_player_death_to_world,
+                    process_player_action_system,
+                    apply_revive_system,
+                )
+                    .chain(),
+            );
+        let world = app.world_mut();
+        let party = |world_entity| {
+            let link = BattleWorldLink { world_entity };
+            (BattleParticipant, BattleSide::Ally, PlayerControlled, link)
+        };
+        let cleric_world = world.spawn_empty().id();
+        let kaito_world = world.spawn_empty().id();
+        let cleric_stats = CombatStats {
+            health: <StatPool<i32>>::new(60),
+            action_points: <StatPool<i32>>::new(4),
+            ..Default::default()
+        };
+        let cleric = world.spawn((party(cleric_world), cleric_stats, Abilities(vec![13]))).id();
+        let kaito_stats = CombatStats { health: <StatPool<i32>>::new(80), ..Default::default() };
+        let kaito = world.spawn((party(kaito_world), kaito_stats)).id();
+        let foe_stats = CombatStats { health: <StatPool<i32>>::new(50), ..Default::default() };
+        let foe = world.spawn((BattleParticipant, BattleSide::Enemy, foe_stats)).id();
+        world.insert_resource(BattleState {
+            active: true,
+            participants: vec![cleric, kaito, foe],
+            enemy_id: None,
+        });
+        world.resource_mut::<TurnManager>().participants = vec![cleric, kaito, foe];
+        world.write_message(DamageEvent {
+            attacker: Some(foe),
+            target: kaito,
+            amount: 80,
+            damage_type: DamageType::Physical,
+            cause: ActionCause::Ai,
+        });
+        app.update();
+
+        let world = app.world_mut();
+        assert!(world.get::<Dead>(kaito).is_some(), "Kaito lies where he fell");
+        assert!(world.get::<Dead>(kaito_world).is_some());
+        assert!(world.resource::<BattleState>().participants.contains(&kaito));
+        assert_eq!(world.resource::<TurnManager>().participants, vec![cleric, foe]);
+
+        world.resource_mut::<PendingPlayerAction>().entity = Some(cleric);
+        world.write_message(PlayerActionEvent { action: PlayerAction::UseAbility(13, cleric) });
+        world.write_message(PlayerActionEvent { action: PlayerAction::UseAbility(13, kaito) });
+        app.update();
+
+        let world = app.world();
+        let failures: Vec<_> = world
+            .resource::<Messages<AbilityFailedEvent>>()
+            .iter_current_update_messages()
+            .map(|f| (f.actor, f.reason))
+            .collect();
+        let not_dead = AbilityFailReason::BadReviveTarget(ReviveRejection::NotDead);
+        assert_eq!(failures, vec![(cleric, not_dead)]);
+        assert_eq!(world.get::<CombatStats>(cleric).unwrap().action_points.current, 2);
+        assert!(world.get::<Dead>(kaito).is_none());
+        assert!(world.get::<Dead>(kaito_world).is_none());
+        assert_eq!(world.get::<CombatStats>(kaito).unwrap().health.current, 40);
+        assert_eq!(world.resource::<TurnManager>().participants, vec![cleric, foe, kaito]);
+    }
+
+    #[test]
+    fn revive_targets_must_be_dead_but_not_lost() {
+        assert_eq!(check_revive_target(true, false), Ok(()));
+        assert_eq!(check_revive_target(false, false), Err(ReviveRejection::NotDead));
+        assert_eq!(check_revive_target(true, true), Err(ReviveRejection::BeyondSaving));
+        let broken = AbilityBuilder::new(14, "Half-Hearted Rite")
+            .effect(AbilityEffect::Revive { hp_fraction: 0.0 })
+            .build();
+        assert_eq!(broken.validate(), Err(AbilityValidationError::BadReviveFraction { index: 0 }));
+    }
+}
+
+#[cfg(test)]
+mod guard_tests {
+    use super::*;
+    use crate::status_effects::ApplyStatusEvent;
+
+    /// A foe's 20-damage hit on the guarded ally lands on the paladin
+    /// instead, reduced to 15.
+    #[test]
+    fn attack_on_guarded_ally_hits_the_paladin() {
+        let mut app = App::new();
+        app.add_message::<DamageEvent>()
+            .add_message::<ApplyStatusEvent>()
+            .init_resource::<DamageQueue>()
+            .add_systems(Update, process_damage_queue_system);
+        let alive = || CombatStats { health: <StatPool<i32>>::new(100), ..Default::default() };
+        let world = app.world_mut();
+        let foe = world.spawn(alive()).id();
+        let ally = world.spawn(alive()).id();
+        let paladin = world.spawn(alive()).id();
+        world.entity_mut(paladin).insert(Guard {
+            protector: paladin,
+            target: ally,
+            remaining_turns: 2,
+        });
+        world.resource_mut::<DamageQueue>().0.push(QueuedDamage {
+            attacker: Some(foe),
+            target: ally,
+            amount: 20,
+            damage_type: DamageType::Physical,
+            element: None,
+            scaled_with: vec![],
+            defended_with: vec![],
+            accuracy_override: None,
+            crit_multiplier: 1.0,
+            tags: vec![],
+            cause: ActionCause::Ai,
+            priority: DamagePriority::Raw,
+        });
+        app.update();
+
+        let hits: Vec<(Entity, i32)> = app
+            .world()
+            .resource::<Messages<DamageEvent>>()
+            .iter_current_update_messages()
+            .map(|d| (d.target, d.amount))
+            .collect();
+        assert_eq!(hits, vec![(paladin, 15)]);
+    }
+}
+
+#[cfg(test)]
+mod rage_tests {
+    use super::*;
+    use crate::battle::BattleSide;
+
+    /// A samurai falls: the surviving ally with Rage gains +50% lethality for
+    /// three turns; the enemy with Rage and the ally without it gain nothing.
+    #[test]
+    fn ally_death_enrages_surviving_teammates() {
+        let mut app = App::new();
+        app.add_message::<DeathEvent>()
+            .insert_resource(Timestamp(20))
+            .add_systems(Update, rage_on_ally_death_system);
+        let rage = Rage { stat: Stat::Lethality, multiplier: 1.5, turns: Some(3) };
+        let alive = || CombatStats { health: <StatPool<i32>>::new(50), ..Default::default() };
+        let world = app.world_mut();
+        let fallen = world
+            .spawn((BattleSide::Ally, CombatStats::default(), rage.clone()))
+            .id();
+        let mourner = world.spawn((BattleSide::Ally, alive(), rage.clone())).id();
+        let stoic = world.spawn((BattleSide::Ally, alive())).id();
+        let foe = world.spawn((BattleSide::Enemy, alive(), rage)).id();
+        world.write_message(DeathEvent { entity: fallen, killer: Some(foe) });
+        app.update();
+
+        let mods = app.world().get::<StatModifiers>(mourner).expect("mourner enraged");
+        assert_eq!(mods.0.len(), 1);
+        assert_eq!(mods.0[0].stat, Stat::Lethality);
+        assert_eq!(mods.0[0].multiplier, 1.5);
+        assert_eq!(mods.0[0].expires_at_timestamp, Some(23));
+        assert!(app.world().get::<StatModifiers>(stoic).is_none());
+        assert!(app.world().get::<StatModifiers>(foe).is_none());
+    }
+
+    #[test]
+    fn two_deaths_in_one_frame_both_enrage() {
+        let mut app = App::new();
+        app.add_message::<DeathEvent>()
+            .insert_resource(Timestamp(20))
+            .add_systems(Update, rage_on_ally_death_system);
+        let rage = Rage { stat: Stat::Lethality, multiplier: 1.5, turns: Some(3) };
+        let alive = || CombatStats { health: <StatPool<i32>>::new(50), ..Default::default() };
+        let world = app.world_mut();
+        let first = world.spawn((BattleSide::Ally, CombatStats::default())).id();
+        let second = world.spawn((BattleSide::Ally, CombatStats::default())).id();
+        let mourner = world.spawn((BattleSide::Ally, alive(), rage)).id();
+        world.write_message(DeathEvent { entity: first, killer: None });
+        world.write_message(DeathEvent { entity: second, killer: None });
+        app.update();
+
+        let mods = app.world().get::<StatModifiers>(mourner).expect("mourner enraged");
+        let sources: Vec<_> = mods.0.iter().map(|m| m.source).collect();
+        assert_eq!(sources, vec![Some(first), Some(second)]);
+    }
+}
+
+#[cfg(test)]
+mod attribute_points_tests {
+    use super::*;
+
+    #[test]
+    fn spending_a_point_reports_the_new_pool() {
+        let mut app = App::new();
+        app.add_message::<SpendAttributePointEvent>()
+            .add_message::<AttributePointsChangedEvent>()
+            .add_systems(Update, spend_attribute_point_system);
+        let who = app
+            .world_mut()
+            .spawn((GrowthAttributes::default(), AttributePointPool { available: 2, spent: 0 }))
+            .id();
+
+        app.world_mut()
+            .write_message(SpendAttributePointEvent { who, attribute: Attribute::Power });
+        app.update();
+
+        let changes: Vec<_> = app
+            .world()
+            .resource::<Messages<AttributePointsChangedEvent>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(
+            changes,
+            vec![AttributePointsChangedEvent { who, available: 1, spent: 1 }]
+        );
+        assert_eq!(app.world().get::<GrowthAttributes>(who).unwrap().power, 1);
+    }
+}
@@ -0,0 +1,219 @@
+//! Scripted first battle: the onboarding fight walks the player through a
+//! fixed list of actions, one at a time.
+//!
+//! While a [`CombatTutorial`] resource exists, the combat pipeline only takes
+//! the player action the current step asks for (`process_player_action_system`
+//! drops the rest) and the combat HUD dims every other option, which leaves the
+//! recommended one highlighted, with the step's hint on the hint line. A step
+//! completes when the pipeline actually produces its intent (an attack that
+//! cost its AP, a defend, ...), not merely when it's chosen. After the last
+//! step the tutorial sets its story flag and removes itself, and the rest of
+//! the fight plays normally.
+//!
+//! The first battle of a run starts [`first_battle_tutorial`] unless
+//! [`FIRST_BATTLE_TUTORIAL_FLAG`] is already set.
+
+use bevy::prelude::*;
+
+use crate::battle::{BattleEndEvent, BattleState};
+use crate::combat_plugin::{
+    ActionCause, AttackIntentEvent, DefendIntentEvent, ItemUseTrigger, PlayerAction,
+    PlayerControlled, UseItemIntentEvent, WaitIntentEvent,
+};
+use crate::story_flags::{FlagChangedEvent, StoryFlags};
+
+/// Set once the onboarding battle's steps are all done.
+pub const FIRST_BATTLE_TUTORIAL_FLAG: &str = "tutorial_first_battle_complete";
+
+/// A kind of player action, without its target: what a step asks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TutorialAction {
+    Attack,
+    UseAbility(u32),
+    UseItem(u16),
+    Defend,
+    Wait,
+}
+
+impl TutorialAction {
+    pub fn of(action: &PlayerAction) -> Self {
+        match action {
+            PlayerAction::Attack(_) => Self::Attack,
+            PlayerAction::UseAbility(id, _) => Self::UseAbility(*id),
+            PlayerAction::UseItem(id, _) => Self::UseItem(*id),
+            PlayerAction::Defend => Self::Defend,
+            PlayerAction::Wait => Self::Wait,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TutorialStep {
+    pub action: TutorialAction,
+    /// Shown on the combat HUD's hint line while this step is current.
+    pub hint: String,
+}
+
+impl TutorialStep {
+    pub fn new(action: TutorialAction, hint: impl Into<String>) -> Self {
+        Self { action, hint: hint.into() }
+    }
+}
+
+/// The running tutorial script. Insert it to start one; it removes itself
+/// when done or when the battle ends.
+#[derive(Resource, Debug, Clone)]
+pub struct CombatTutorial {
+    pub steps: Vec<TutorialStep>,
+    pub current: usize,
+    /// Story flag set when the last step completes.
+    pub complete_flag: String,
+}
+
+impl CombatTutorial {
+    pub fn new(complete_flag: impl Into<String>, steps: Vec<TutorialStep>) -> Self {
+        Self { steps, current: 0, complete_flag: complete_flag.into() }
+    }
+
+    pub fn current_step(&self) -> Option<&TutorialStep> {
+        self.steps.get(self.current)
+    }
+
+    /// Whether the player may take `action` right now.
+    pub fn allows(&self, action: &PlayerAction) -> bool {
+        self.allows_kind(TutorialAction::of(action))
+    }
+
+    pub fn allows_kind(&self, action: TutorialAction) -> bool {
+        self.current_step().is_none_or(|step| step.action == action)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current >= self.steps.len()
+    }
+}
+
+/// Strike, brace, strike again: the three verbs every fight is built on.
+pub fn first_battle_tutorial() -> CombatTutorial {
+    CombatTutorial::new(
+        FIRST_BATTLE_TUTORIAL_FLAG,
+        vec![
+            TutorialStep::new(
+                TutorialAction::Attack,
+                "Tutorial: choose Attack, then pick the enemy to strike it.",
+            ),
+            TutorialStep::new(
+                TutorialAction::Defend,
+                "Tutorial: choose Defend to brace for the enemy's answer.",
+            ),
+            TutorialStep::new(
+                TutorialAction::Attack,
+                "Tutorial: Attack again to finish the lesson.",
+            ),
+        ],
+    )
+}
+
+/// Start the onboarding script when the first battle begins.
+fn start_first_battle_tutorial_system(
+    mut commands: Commands,
+    battle_state: Res<BattleState>,
+    flags: Option<Res<StoryFlags>>,
+    tutorial: Option<Res<CombatTutorial>>,
+) {
+    if !battle_state.is_changed() || !battle_state.active || tutorial.is_some() {
+        return;
+    }
+    if flags.is_some_and(|f| f.is_set(FIRST_BATTLE_TUTORIAL_FLAG)) {
+        return;
+    }
+    commands.insert_resource(first_battle_tutorial());
+}
+
+/// Complete the current step when the player's turn produces its intent, and
+/// wrap up after the last one. Intents are matched on their actor being
+/// [`PlayerControlled`] rather than on the pending turn, which has already
+/// closed by now when the action ended it (a defend, a wait, or spending the
+/// last AP).
+#[allow(clippy::too_many_arguments)]
+pub fn advance_combat_tutorial_system(
+    mut commands: Commands,
+    tutorial: Option<ResMut<CombatTutorial>>,
+    player_controlled: Query<(), With<PlayerControlled>>,
+    mut attacks: MessageReader<AttackIntentEvent>,
+    mut defends: MessageReader<DefendIntentEvent>,
+    mut waits: MessageReader<WaitIntentEvent>,
+    mut items: MessageReader<UseItemIntentEvent>,
+    mut flags: ResMut<StoryFlags>,
+    mut flag_events: MessageWriter<FlagChangedEvent>,
+) {
+    let Some(mut tutorial) = tutorial else {
+        attacks.clear();
+        defends.clear();
+        waits.clear();
+        items.clear();
+        return;
+    };
+    let is_player = |actor: Entity| player_controlled.contains(actor);
+
+    let mut done: Vec<TutorialAction> = Vec::new();
+    done.extend(attacks.read().filter(|i| is_player(i.attacker)).filter_map(|i| {
+        match (&i.cause, &i.ability) {
+            (ActionCause::Player, None) => Some(TutorialAction::Attack),
+            (ActionCause::Ability { id }, Some(_)) => {
+                Some(TutorialAction::UseAbility(u32::from(*id)))
+            }
+            _ => None,
+        }
+    }));
+    done.extend(defends.read().filter(|d| is_player(d.defender)).map(|_| TutorialAction::Defend));
+    done.extend(waits.read().filter(|w| is_player(w.waiter)).map(|_| TutorialAction::Wait));
+    done.extend(
+        items
+            .read()
+            .filter(|u| is_player(u.user) && matches!(u.trigger, ItemUseTrigger::Manual))
+            .map(|u| TutorialAction::UseItem(u.item_id)),
+    );
+
+    for action in done {
+        if tutorial.current_step().is_some_and(|step| step.action == action) {
+            tutorial.current += 1;
+        }
+    }
+    if tutorial.is_finished() {
+        let flag = tutorial.complete_flag.clone();
+        if flags.set(flag.clone()) {
+            flag_events.write(FlagChangedEvent { name: flag, set: true });
+        }
+        info!("combat tutorial complete");
+        commands.remove_resource::<CombatTutorial>();
+    }
+}
+
+/// An abandoned tutorial (fled, lost, won early) doesn't follow the player
+/// into the next fight; the flag stays unset, so the next battle retries it.
+fn end_tutorial_with_battle_system(
+    mut commands: Commands,
+    mut battle_ends: MessageReader<BattleEndEvent>,
+    tutorial: Option<Res<CombatTutorial>>,
+) {
+    if battle_ends.read().count() > 0 && tutorial.is_some() {
+        commands.remove_resource::<CombatTutorial>();
+    }
+}
+
+pub struct CombatTutorialPlugin;
+
+impl Plugin for CombatTutorialPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                start_first_battle_tutorial_system,
+                advance_combat_tutorial_system,
+                end_tutorial_with_battle_system,
+            )
+                .chain(),
+        );
+    }
+}
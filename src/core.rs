@@ -46,6 +46,41 @@ impl Default for GameState {
     }
 }
 
+/// Game difficulty. Sets how well the combat AI plays: Easy fumbles some of
+/// its decisions (see [`Difficulty::ai_mistake_chance`]), Normal plays its
+/// scoring straight, and Hard also goes for the kill (see
+/// [`Difficulty::ai_finisher_threshold`]).
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// Chance (0..1) that an AI actor ignores its scoring for one choice —
+    /// strikes a random enemy instead of its preferred target, or casts a
+    /// random affordable damage ability instead of the strongest. Only Easy
+    /// blunders.
+    pub fn ai_mistake_chance(self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.35,
+            Difficulty::Normal | Difficulty::Hard => 0.0,
+        }
+    }
+
+    /// Health percent at or below which an enemy draws every AI attack and
+    /// ability regardless of the actor's focus preference, so wounded party
+    /// members get finished off. Only Hard hunts for kills.
+    pub fn ai_finisher_threshold(self) -> Option<u8> {
+        match self {
+            Difficulty::Hard => Some(35),
+            Difficulty::Easy | Difficulty::Normal => None,
+        }
+    }
+}
+
 /// Run-condition factory: gate a system so the scheduler skips it entirely
 /// unless the game is in `state`. Use as `system.run_if(in_game_state(X))`.
 /// Cheaper than an in-body `if game_state.0 != X { return }` because the
@@ -57,10 +92,12 @@ pub fn in_game_state(state: Game_State) -> impl Fn(bevy::prelude::Res<GameState>
 #[derive(Resource, Default)]
 pub struct Global_Variables(pub GlobalVariables);
 
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Serialize, Deserialize)]
 pub struct PlayerMapPosition(pub Position);
 
-#[derive(Resource)]
+/// The world clock. There's no separate day-cycle resource; this is the
+/// time state a save needs.
+#[derive(Resource, Serialize, Deserialize)]
 pub struct Timestamp(pub u32);
 
 pub struct GlobalVariables {
@@ -94,3 +131,18 @@ impl Default for Position {
         Position { x: 0, y: 0 }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_survives_a_save_round_trip() {
+        let pos = Position { x: -12, y: 345 };
+        let saved = ron::to_string(&pos).unwrap();
+        assert_eq!(ron::from_str::<Position>(&saved).unwrap(), pos);
+
+        let saved = ron::to_string(&PlayerMapPosition(pos)).unwrap();
+        assert_eq!(ron::from_str::<PlayerMapPosition>(&saved).unwrap().0, pos);
+    }
+}
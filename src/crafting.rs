@@ -0,0 +1,166 @@
+//! Crafting: turning items the player already carries into something else.
+//!
+//! A [`Recipe`] lists input stacks and one output stack, all by item id in the
+//! [`crate::economy::ItemCatalog`]. [`RecipeBook::craft`] is all-or-nothing
+//! against the [`PlayerInventory`]: either every input is there and gets
+//! consumed and the output added, or the inventory is left untouched.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::economy::{add_to_inventory, take_from_inventory, ItemId, PlayerInventory, StackQty};
+
+type RecipeId = u16;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Recipe {
+    pub inputs: Vec<(ItemId, StackQty)>,
+    pub output: (ItemId, StackQty),
+}
+
+/// Why [`RecipeBook::craft`] refused. Nothing has been consumed in either case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CraftError {
+    UnknownRecipe(RecipeId),
+    /// The first input the inventory can't cover.
+    MissingMaterials { item_id: ItemId, needed: StackQty, held: StackQty },
+}
+
+impl fmt::Display for CraftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownRecipe(id) => write!(f, "no recipe {id}"),
+            Self::MissingMaterials { item_id, needed, held } => {
+                write!(f, "needs {needed} of item {item_id}, have {held}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CraftError {}
+
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct RecipeBook(pub HashMap<RecipeId, Recipe>);
+
+impl Default for RecipeBook {
+    fn default() -> Self {
+        let mut map = HashMap::new();
+        // Two Iron Daggers (5005) reforged into a Wakizashi (5016).
+        map.insert(
+            1,
+            Recipe {
+                inputs: vec![(5005, 2)],
+                output: (5016, 1),
+            },
+        );
+        // Buckler (5002) boards stitched into a Traveler Cloak (5003) lining
+        // make a Kikkō Brigandine (5019).
+        map.insert(
+            2,
+            Recipe {
+                inputs: vec![(5002, 1), (5003, 1)],
+                output: (5019, 1),
+            },
+        );
+        Self(map)
+    }
+}
+
+impl RecipeBook {
+    /// Consume `recipe_id`'s inputs from `inventory` and add its output.
+    /// Works on a copy and only writes it back once every input has been
+    /// taken, so a failed craft leaves the inventory exactly as it was.
+    pub fn craft(
+        &self,
+        recipe_id: RecipeId,
+        inventory: &mut PlayerInventory,
+    ) -> Result<(), CraftError> {
+        let recipe = self.0.get(&recipe_id).ok_or(CraftError::UnknownRecipe(recipe_id))?;
+
+        // Sum per item first so a recipe listing the same input twice reports
+        // the full amount it needs, not just the second listing.
+        let mut needed: Vec<(ItemId, StackQty)> = Vec::new();
+        for &(item_id, qty) in &recipe.inputs {
+            match needed.iter_mut().find(|(id, _)| *id == item_id) {
+                Some((_, total)) => *total = total.saturating_add(qty),
+                None => needed.push((item_id, qty)),
+            }
+        }
+
+        let mut staged = inventory.0.clone();
+        for &(item_id, qty) in &needed {
+            take_from_inventory(&mut staged, item_id, qty)
+                .map_err(|held| CraftError::MissingMaterials { item_id, needed: qty, held })?;
+        }
+        let (output_id, output_qty) = recipe.output;
+        add_to_inventory(&mut staged, output_id, output_qty);
+        inventory.0 = staged;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::economy::InventoryStack;
+
+    fn inventory(stacks: &[(ItemId, StackQty)]) -> PlayerInventory {
+        PlayerInventory(
+            stacks
+                .iter()
+                .map(|&(item_id, quantity)| InventoryStack { item_id, quantity })
+                .collect(),
+        )
+    }
+
+    fn quantities(inventory: &PlayerInventory) -> Vec<(ItemId, StackQty)> {
+        let mut out: Vec<_> = inventory.0.iter().map(|s| (s.item_id, s.quantity)).collect();
+        out.sort();
+        out
+    }
+
+    #[test]
+    fn craft_consumes_inputs_and_adds_output() {
+        let book = RecipeBook::default();
+        let mut inv = inventory(&[(5005, 3), (5003, 1)]);
+
+        assert_eq!(book.craft(1, &mut inv), Ok(()));
+
+        assert_eq!(quantities(&inv), vec![(5003, 1), (5005, 1), (5016, 1)]);
+    }
+
+    #[test]
+    fn craft_without_materials_changes_nothing() {
+        let book = RecipeBook::default();
+        // Has the buckler but not the cloak.
+        let mut inv = inventory(&[(5002, 1), (5005, 1)]);
+
+        assert_eq!(
+            book.craft(2, &mut inv),
+            Err(CraftError::MissingMaterials { item_id: 5003, needed: 1, held: 0 })
+        );
+        assert_eq!(quantities(&inv), vec![(5002, 1), (5005, 1)]);
+        assert_eq!(book.craft(99, &mut inv), Err(CraftError::UnknownRecipe(99)));
+    }
+
+    #[test]
+    fn craft_draws_inputs_from_split_stacks() {
+        let book = RecipeBook::default();
+        // Dialogue rewards push a fresh stack rather than merging.
+        let mut inv = inventory(&[(5005, 1), (5003, 1), (5005, 1)]);
+
+        assert_eq!(book.craft(1, &mut inv), Ok(()));
+        assert_eq!(quantities(&inv), vec![(5003, 1), (5016, 1)]);
+
+        // One dagger left is still short of the two the recipe needs.
+        let mut inv = inventory(&[(5005, 1), (5016, 1)]);
+        assert_eq!(
+            book.craft(1, &mut inv),
+            Err(CraftError::MissingMaterials { item_id: 5005, needed: 2, held: 1 })
+        );
+        assert_eq!(quantities(&inv), vec![(5005, 1), (5016, 1)]);
+    }
+}
@@ -17,10 +17,12 @@
 //!
 //! At runtime each spawned creature carries a [`Creature`] component holding
 //! its home anchor and a small state machine. [`drive_creatures`] ticks that
-//! machine every frame while exploring: Hostile creatures start a fight the
-//! moment they spot the player (within their vision cone), Territorial ones
-//! guard a home and engage intruders they see, Skittish ones flee, Passive ones
-//! wander, and Friendly ones hold their ground.
+//! machine every frame while exploring: Hostile creatures start a fight once
+//! they've had the player in sight long enough to fill their detection meter
+//! (see [`Stealth`] and [`sense_player`]), Territorial ones
+//! guard a home and engage intruders they see, Roaming ones path after the
+//! player through the shared A* and fight on contact, Skittish ones flee,
+//! Passive ones wander, and Friendly ones hold their ground.
 
 use std::collections::HashMap;
 use std::fs;
@@ -30,14 +32,35 @@ use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 use crate::battle::{
-    start_battle, BattleState, EnemyEncounter, WorldAlly, WorldYokai, YokaiKind,
+    start_battle, BattleState, EnemyEncounter, GridConfig, WorldAlly, WorldYokai, YokaiKind,
 };
+use crate::characters::CharacterKind;
 use crate::combat_plugin::{AIParameters, TurnManager, TurnOrder};
-use crate::constants::PLAYER_SPEED;
-use crate::core::{GameState, Game_State, Player};
+use crate::constants::{PATH_MOVEMENT_SPEED, PLAYER_SPEED};
+use crate::core::{GameState, Game_State, Player, Position};
+use crate::movement::{cancel_player_path, MoveAlongPath};
+use crate::pathfinding::{line_of_sight, pathfinding};
+use crate::quadtree::QuadTree;
 use crate::render3d::PlaceholderVisual;
 
 const CREATURE_CATALOG_PATH: &str = "assets/data/creatures.ron";
+/// Grid step (world units) for creature A* paths. Coarser than the player's
+/// click-to-move grid: creatures repath often and don't need pixel precision.
+const CREATURE_PATH_MARGIN: i32 = 16;
+/// Seconds between chase repaths, so a pursuer tracks a moving player without
+/// running A* every frame.
+const CHASE_REPATH_SECS: f32 = 0.5;
+/// Detection-meter fill per second while the player is in sight (a full meter
+/// is 1.0, so an unhidden player is spotted in a quarter second).
+const DETECTION_FILL_PER_SEC: f32 = 4.0;
+/// Detection-meter drain per second once the player is out of sight.
+const DETECTION_DRAIN_PER_SEC: f32 = 1.0;
+/// Fraction of a creature's perception radii each stealth level shaves off.
+const STEALTH_RADIUS_PER_LEVEL: f32 = 0.06;
+
+/// How close a patroller must get to a waypoint to count as having reached
+/// it. A* paths end within one grid step of the goal, so allow a bit more.
+const PATROL_ARRIVE_RADIUS: f32 = CREATURE_PATH_MARGIN as f32 * 1.5;
 
 // ---------------------------------------------------------------------------
 // Template data
@@ -47,8 +70,8 @@ const CREATURE_CATALOG_PATH: &str = "assets/data/creatures.ron";
 /// most important behaviour knob — everything else just tunes it.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Disposition {
-    /// Attacks on sight: starts a battle the instant the player steps into its
-    /// vision cone within detection range.
+    /// Attacks on sight: starts a battle as soon as its detection meter fills
+    /// from the player standing in its vision cone within detection range.
     Hostile,
     /// Peaceful until disturbed. Guards a home area and chases intruders who
     /// come close, but gives up and walks home if lured past its leash.
@@ -60,13 +83,20 @@ pub enum Disposition {
     Passive,
     /// Stands its ground. Friendly — never chases, never flees.
     Friendly,
+    /// Prowls its home until it spots the player, then paths after them
+    /// around obstacles (shared A*) and starts a fight on contact. Gives up
+    /// and heads home once the player escapes its detection radius.
+    Roaming,
 }
 
 impl Disposition {
     /// Whether this disposition can ever initiate (and therefore needs an
     /// [`EnemyEncounter`] tag so a battle can start).
     pub fn is_aggressive(self) -> bool {
-        matches!(self, Disposition::Hostile | Disposition::Territorial)
+        matches!(
+            self,
+            Disposition::Hostile | Disposition::Territorial | Disposition::Roaming
+        )
     }
 }
 
@@ -80,7 +110,8 @@ pub struct BehaviorParams {
     /// place); detection no longer requires walking into contact.
     pub detection_radius: f32,
     /// Range at which a Territorial creature is considered "back home" when
-    /// returning, and the adjacency window for the player's manual Space engage.
+    /// returning, the contact range at which a Roaming pursuer starts the
+    /// fight, and the adjacency window for the player's manual Space engage.
     pub engage_radius: f32,
     /// Movement speed while chasing or fleeing.
     pub move_speed: f32,
@@ -178,6 +209,11 @@ pub struct Creature {
     /// Unit vector the creature is currently looking along. Drives the frontal
     /// vision cone used for spotting; updated to its heading whenever it moves.
     pub facing: Vec2,
+    /// Seconds until a chasing creature recomputes its path to the player.
+    pub repath_cooldown: f32,
+    /// Detection meter, 0.0..=1.0. Fills while the player is in sight, drains
+    /// while they aren't; an aggressive creature acts once it's full.
+    pub detection: f32,
 }
 
 impl Creature {
@@ -191,6 +227,8 @@ impl Creature {
             // Face "south" (toward the camera) by default; movement overwrites
             // this with the live heading.
             facing: Vec2::NEG_Y,
+            repath_cooldown: 0.0,
+            detection: 0.0,
         }
     }
 }
@@ -201,14 +239,84 @@ pub enum CreatureState {
     Idle,
     /// Drifting toward a wander target (Passive).
     Wander,
-    /// Closing on the player (Hostile / Territorial).
+    /// Closing on the player (Hostile / Territorial / Roaming).
     Chase,
     /// Running away from the player (Skittish).
     Flee,
-    /// Walking back toward home after losing interest (Territorial leash).
+    /// Walking back toward home after losing interest (Territorial leash,
+    /// Roaming pursuit lost).
     Returning,
 }
 
+/// How hard the player is to notice. Each level (0..=10) shrinks every
+/// creature's detection and notice radii; a player without the component is
+/// level 0.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Stealth {
+    pub level: u8,
+}
+
+/// A fixed route walked between waypoints while nothing more pressing is
+/// going on. Works on any world entity (NPCs included); on a [`Creature`] the
+/// patrol yields whenever the creature is chasing or heading home, and picks
+/// the route back up once it settles.
+///
+/// Looping routes wrap from the last waypoint back to the first; non-looping
+/// routes walk back and forth along the line.
+#[derive(Component, Debug, Clone)]
+pub struct Patrol {
+    pub waypoints: Vec<Position>,
+    pub looping: bool,
+    /// Seconds spent standing at each waypoint before moving on.
+    pub pause_secs: f32,
+    /// Walking speed between waypoints, world units/second.
+    pub speed: f32,
+    /// Index of the waypoint currently being walked to (or paused at).
+    pub next: usize,
+    /// Remaining pause at the current waypoint; `None` while walking.
+    pub pause_left: Option<f32>,
+    /// Direction of travel along a non-looping route.
+    pub forward: bool,
+}
+
+impl Patrol {
+    pub fn new(waypoints: Vec<Position>, looping: bool) -> Self {
+        Self {
+            waypoints,
+            looping,
+            pause_secs: 1.0,
+            speed: PLAYER_SPEED * 0.4,
+            next: 0,
+            pause_left: None,
+            forward: true,
+        }
+    }
+
+    pub fn current(&self) -> Option<Vec2> {
+        self.waypoints
+            .get(self.next)
+            .map(|p| Vec2::new(p.x as f32, p.y as f32))
+    }
+
+    /// Step `next` on to the following waypoint.
+    pub fn advance(&mut self) {
+        let len = self.waypoints.len();
+        if len < 2 {
+            return;
+        }
+        if self.looping {
+            self.next = (self.next + 1) % len;
+            return;
+        }
+        if self.forward && self.next + 1 == len {
+            self.forward = false;
+        } else if !self.forward && self.next == 0 {
+            self.forward = true;
+        }
+        self.next = if self.forward { self.next + 1 } else { self.next - 1 };
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Spawning
 // ---------------------------------------------------------------------------
@@ -290,35 +398,48 @@ pub fn drive_creatures(
     mut battle_state: ResMut<BattleState>,
     mut tm: ResMut<TurnManager>,
     mut turn_order: ResMut<TurnOrder>,
+    quad_tree: Res<QuadTree>,
+    grid: Res<GridConfig>,
     player_q: Query<
-        (Entity, &Transform, Option<&crate::characters::CharacterKind>),
+        (Entity, &Transform, Option<&CharacterKind>, Option<&Stealth>),
         (With<Player>, Without<Creature>),
     >,
     ally_q: Query<
-        (Entity, &Transform, Option<&crate::characters::CharacterKind>),
+        (Entity, &Transform, Option<&CharacterKind>),
         (With<WorldAlly>, Without<Creature>),
     >,
     mut creature_q: Query<
-        (Entity, &mut Transform, &mut Creature, Option<&EnemyEncounter>, Option<&WorldYokai>),
+        (
+            Entity,
+            &mut Transform,
+            &mut Creature,
+            Option<&EnemyEncounter>,
+            Option<&WorldYokai>,
+            Option<&MoveAlongPath>,
+            Option<&Patrol>,
+        ),
         (Without<Player>, Without<WorldAlly>),
     >,
 ) {
     if game_state.0 != Game_State::Exploring || battle_state.active {
         return;
     }
-    let Ok((player_entity, player_tf, player_kind)) = player_q.single() else {
+    let Ok((player_entity, player_tf, player_kind, stealth)) = player_q.single() else {
         return;
     };
     let player_kind = player_kind.copied();
+    let stealth = stealth.map(|s| s.level).unwrap_or(0);
     let player_pos = player_tf.translation.truncate();
     let dt = time.delta_secs();
     let mut rng = rand::rng();
 
     // Collected once so the battle hand-off can pass the ally roster.
-    let allies: Vec<(Entity, Transform, Option<crate::characters::CharacterKind>)> =
+    let allies: Vec<(Entity, Transform, Option<CharacterKind>)> =
         ally_q.iter().map(|(e, t, k)| (e, *t, k.copied())).collect();
 
-    for (entity, mut transform, mut creature, encounter, yokai) in creature_q.iter_mut() {
+    for (entity, mut transform, mut creature, encounter, yokai, path, patrol) in
+        creature_q.iter_mut()
+    {
         let Some(tmpl) = catalog.0.templates.get(&creature.template) else {
             continue;
         };
@@ -326,33 +447,27 @@ pub fn drive_creatures(
         let pos = transform.translation.truncate();
         let to_player = player_pos - pos;
         let dist = to_player.length();
+        let spotted = tmpl.disposition.is_aggressive()
+            && sense_player(&mut creature, pos, player_pos, &params, stealth, &quad_tree, dt);
 
         match tmpl.disposition {
             Disposition::Hostile => {
-                // BG3-style: the instant the creature sees the player, the
-                // fight begins right where everyone is standing.
-                if spots_player(creature.facing, to_player, dist, &params) {
+                // BG3-style: once the creature has properly seen the player,
+                // the fight begins right where everyone is standing.
+                if spotted {
                     creature.state = CreatureState::Chase;
-                    game_state.0 = Game_State::Battle;
-                    start_battle(
+                    enter_battle(
                         &mut commands,
+                        &mut game_state,
                         &mut battle_state,
                         &mut tm,
                         &mut turn_order,
-                        // Untagged (id 0) when this spawn carries no
-                        // encounter identity — it still fights, just isn't
-                        // matched by any quest/hunt.
-                        encounter.map(|e| e.id).unwrap_or(0),
-                        None,
-                        None,
+                        encounter,
                         yokai.map(|y| y.kind).or(tmpl.yokai),
-                        entity,
-                        player_entity,
-                        player_tf.translation,
-                        transform.translation,
+                        (entity, transform.translation),
+                        (player_entity, player_tf.translation, player_kind),
                         allies.clone(),
-                        player_kind,
-                        false,
+                        &grid,
                     );
                     return;
                 }
@@ -370,29 +485,21 @@ pub fn drive_creatures(
                         face_along(&mut creature, dir);
                         step_toward(&mut transform, dir, params.move_speed, dt);
                     }
-                } else if !leashed && spots_player(creature.facing, to_player, dist, &params) {
+                } else if !leashed && spotted {
                     // Intruder spotted within its territory — engage in place.
                     creature.state = CreatureState::Chase;
-                    game_state.0 = Game_State::Battle;
-                    start_battle(
+                    enter_battle(
                         &mut commands,
+                        &mut game_state,
                         &mut battle_state,
                         &mut tm,
                         &mut turn_order,
-                        // Untagged (id 0) when this spawn carries no
-                        // encounter identity — it still fights, just isn't
-                        // matched by any quest/hunt.
-                        encounter.map(|e| e.id).unwrap_or(0),
-                        None,
-                        None,
+                        encounter,
                         yokai.map(|y| y.kind).or(tmpl.yokai),
-                        entity,
-                        player_entity,
-                        player_tf.translation,
-                        transform.translation,
+                        (entity, transform.translation),
+                        (player_entity, player_tf.translation, player_kind),
                         allies.clone(),
-                        player_kind,
-                        false,
+                        &grid,
                     );
                     return;
                 } else if leashed {
@@ -437,10 +544,200 @@ pub fn drive_creatures(
             Disposition::Friendly => {
                 creature.state = CreatureState::Idle;
             }
+            Disposition::Roaming => {
+                if dist <= params.engage_radius {
+                    creature.state = CreatureState::Chase;
+                    enter_battle(
+                        &mut commands,
+                        &mut game_state,
+                        &mut battle_state,
+                        &mut tm,
+                        &mut turn_order,
+                        encounter,
+                        yokai.map(|y| y.kind).or(tmpl.yokai),
+                        (entity, transform.translation),
+                        (player_entity, player_tf.translation, player_kind),
+                        allies.clone(),
+                        &grid,
+                    );
+                    return;
+                }
+                let chasing = creature.state == CreatureState::Chase;
+                let in_range = dist <= stealthed(&params, stealth).detection_radius;
+                if (chasing && in_range) || spotted {
+                    // Hunt: follow an A* path toward the player, refreshed on
+                    // a short cooldown so a moving target is tracked. Being
+                    // spotted interrupts any click-to-move walk.
+                    if !chasing {
+                        cancel_player_path(&mut commands, player_entity);
+                    }
+                    creature.state = CreatureState::Chase;
+                    face_along(&mut creature, to_player);
+                    creature.repath_cooldown -= dt;
+                    if path.is_none() || creature.repath_cooldown <= 0.0 {
+                        creature.repath_cooldown = CHASE_REPATH_SECS;
+                        match creature_path(&quad_tree, pos, player_pos, params.move_speed) {
+                            Some(route) => {
+                                commands.entity(entity).insert(route);
+                            }
+                            None => {
+                                commands.entity(entity).remove::<MoveAlongPath>();
+                            }
+                        }
+                    }
+                } else if chasing && patrol.is_some() {
+                    // Lost the player — drop the chase route and let
+                    // `patrol_system` walk back onto the patrol.
+                    creature.state = CreatureState::Idle;
+                    commands.entity(entity).remove::<MoveAlongPath>();
+                } else if chasing {
+                    // Lost the player — abandon the chase and head home.
+                    creature.state = CreatureState::Returning;
+                    let dir = creature.home - pos;
+                    face_along(&mut creature, dir);
+                    match creature_path(&quad_tree, pos, creature.home, params.move_speed) {
+                        Some(route) => {
+                            commands.entity(entity).insert(route);
+                        }
+                        None => {
+                            commands.entity(entity).remove::<MoveAlongPath>();
+                        }
+                    }
+                } else if creature.state == CreatureState::Returning {
+                    // The return path was laid when the chase broke off;
+                    // arrival is when `follow_path_system` drops it.
+                    if path.is_none() || pos.distance(creature.home) <= params.engage_radius {
+                        creature.state = CreatureState::Idle;
+                    }
+                } else {
+                    creature.state = CreatureState::Idle;
+                }
+            }
         }
     }
 }
 
+/// Hand an aggressive creature's contact off to [`start_battle`], flipping
+/// the game into battle. Untagged spawns (no [`EnemyEncounter`]) fight as id
+/// 0 — they still fight, just aren't matched by any quest/hunt.
+#[allow(clippy::too_many_arguments)]
+fn enter_battle(
+    commands: &mut Commands,
+    game_state: &mut GameState,
+    battle_state: &mut BattleState,
+    tm: &mut TurnManager,
+    turn_order: &mut TurnOrder,
+    encounter: Option<&EnemyEncounter>,
+    yokai: Option<YokaiKind>,
+    (creature_entity, creature_pos): (Entity, Vec3),
+    (player_entity, player_pos, player_kind): (Entity, Vec3, Option<CharacterKind>),
+    allies: Vec<(Entity, Transform, Option<CharacterKind>)>,
+    grid: &GridConfig,
+) {
+    game_state.0 = Game_State::Battle;
+    start_battle(
+        commands,
+        battle_state,
+        tm,
+        turn_order,
+        encounter.map(|e| e.id).unwrap_or(0),
+        None,
+        None,
+        yokai,
+        creature_entity,
+        player_entity,
+        player_pos,
+        creature_pos,
+        allies,
+        player_kind,
+        false,
+        grid,
+        None,
+    );
+}
+
+/// Walk every [`Patrol`] along its route: lay an A* path to the current
+/// waypoint, pause on arrival, then move on to the next. Stands down while
+/// the entity is already following a path or a creature is busy chasing /
+/// returning, so perception and patrols compose. Only runs while exploring.
+#[allow(clippy::type_complexity)]
+pub fn patrol_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    quad_tree: Res<QuadTree>,
+    mut patrol_q: Query<
+        (Entity, &Transform, &mut Patrol, Option<&MoveAlongPath>, Option<&Creature>),
+        Without<Player>,
+    >,
+) {
+    if game_state.0 != Game_State::Exploring {
+        return;
+    }
+    let dt = time.delta_secs();
+    for (entity, transform, mut patrol, route, creature) in patrol_q.iter_mut() {
+        if creature.is_some_and(|c| {
+            matches!(c.state, CreatureState::Chase | CreatureState::Returning)
+        }) {
+            continue;
+        }
+        if route.is_some() {
+            continue;
+        }
+        let Some(target) = patrol.current() else {
+            continue;
+        };
+        let pos = transform.translation.truncate();
+        if pos.distance(target) > PATROL_ARRIVE_RADIUS {
+            match creature_path(&quad_tree, pos, target, patrol.speed) {
+                Some(route) => {
+                    commands.entity(entity).insert(route);
+                }
+                // Unreachable waypoint — skip it rather than stall forever.
+                None => patrol.advance(),
+            }
+            continue;
+        }
+        let left = patrol.pause_left.unwrap_or(patrol.pause_secs) - dt;
+        if left > 0.0 {
+            patrol.pause_left = Some(left);
+            continue;
+        }
+        patrol.pause_left = None;
+        patrol.advance();
+        if let Some(next) = patrol.current() {
+            if let Some(route) = creature_path(&quad_tree, pos, next, patrol.speed) {
+                commands.entity(entity).insert(route);
+            }
+        }
+    }
+}
+
+/// A* route from `from` to `to` through the shared [`pathfinding`] grid,
+/// packaged for `follow_path_system`. The step timer is sized so the walker
+/// covers ground at `speed` world units/second. `None` when no step is
+/// possible (blocked, or already there).
+pub fn creature_path(
+    quad_tree: &QuadTree,
+    from: Vec2,
+    to: Vec2,
+    speed: f32,
+) -> Option<MoveAlongPath> {
+    let start = Position { x: from.x as i32, y: from.y as i32 };
+    let goal = Position { x: to.x as i32, y: to.y as i32 };
+    let path = pathfinding(quad_tree, start, goal, CREATURE_PATH_MARGIN);
+    if path.len() < 2 || speed <= 0.0 {
+        return None;
+    }
+    // `follow_path_system` ticks timers at PATH_MOVEMENT_SPEED× real time.
+    let step_secs = CREATURE_PATH_MARGIN as f32 / speed * PATH_MOVEMENT_SPEED as f32;
+    Some(MoveAlongPath {
+        path: path.iter().map(|p| IVec2::new(p.x, p.y)).collect(),
+        current_index: 1,
+        timer: Timer::from_seconds(step_secs, TimerMode::Repeating),
+    })
+}
+
 /// Whether an aggressive creature looking along `facing` can see the player.
 ///
 /// True when the player is within point-blank `notice_radius` (any direction),
@@ -463,6 +760,44 @@ fn spots_player(facing: Vec2, to_player: Vec2, dist: f32, params: &BehaviorParam
     (f.dot(to_player / dist)) >= params.vision_half_angle.cos()
 }
 
+/// `params` with the perception radii shrunk for a player at stealth `level`.
+pub fn stealthed(params: &BehaviorParams, level: u8) -> BehaviorParams {
+    let scale = 1.0 - level.min(10) as f32 * STEALTH_RADIUS_PER_LEVEL;
+    BehaviorParams {
+        detection_radius: params.detection_radius * scale,
+        notice_radius: params.notice_radius * scale,
+        ..*params
+    }
+}
+
+/// Tick `creature`'s detection meter against the player and report whether
+/// it's full. The player counts as in sight when [`spots_player`] passes at
+/// the stealth-reduced radii *and* no collider blocks the line between them.
+pub fn sense_player(
+    creature: &mut Creature,
+    pos: Vec2,
+    player_pos: Vec2,
+    params: &BehaviorParams,
+    stealth: u8,
+    quad_tree: &QuadTree,
+    dt: f32,
+) -> bool {
+    let to_player = player_pos - pos;
+    let in_sight = spots_player(
+        creature.facing,
+        to_player,
+        to_player.length(),
+        &stealthed(params, stealth),
+    ) && line_of_sight(quad_tree, pos, player_pos);
+    let delta = if in_sight {
+        DETECTION_FILL_PER_SEC * dt
+    } else {
+        -DETECTION_DRAIN_PER_SEC * dt
+    };
+    creature.detection = (creature.detection + delta).clamp(0.0, 1.0);
+    creature.detection >= 1.0
+}
+
 /// Point a creature's `facing` along `dir` (ignored when `dir` is ~zero so a
 /// stalled step doesn't blank the heading).
 fn face_along(creature: &mut Creature, dir: Vec2) {
@@ -499,13 +834,17 @@ impl Plugin for CreaturesPlugin {
             .add_systems(PreStartup, load_creature_catalog)
             // Drive creatures after the player moves so chase/flee react to the
             // current frame's player position.
-            .add_systems(Update, drive_creatures.after(crate::movement::player_movement));
+            .add_systems(Update, drive_creatures.after(crate::movement::player_movement))
+            .add_systems(Update, patrol_system.after(drive_creatures));
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use super::*;
+    use crate::test_support::{advance, advance_frames, app_with_manual_time};
 
     /// The shipped catalog must round-trip through serde or no creature
     /// templates load.
@@ -521,6 +860,182 @@ mod tests {
         );
     }
 
+    fn roaming_app(player_pos: Vec3, creature_pos: Vec3) -> (App, Entity) {
+        let mut catalog = CreatureCatalogData::default();
+        catalog.templates.insert(
+            "prowler".to_string(),
+            CreatureTemplate {
+                name: "Prowler".to_string(),
+                color: [0.5, 0.5, 0.5],
+                disposition: Disposition::Roaming,
+                behavior: BehaviorParams {
+                    detection_radius: 200.0,
+                    engage_radius: 36.0,
+                    ..Default::default()
+                },
+                ai: AIParameters::default(),
+                yokai: None,
+            },
+        );
+        let mut app = app_with_manual_time();
+        app.insert_resource(CreatureCatalog(catalog))
+            .init_resource::<GameState>()
+            .init_resource::<BattleState>()
+            .init_resource::<TurnManager>()
+            .init_resource::<TurnOrder>()
+            .init_resource::<QuadTree>()
+            .init_resource::<GridConfig>()
+            .add_systems(Update, drive_creatures);
+        app.world_mut().spawn((Player, Transform::from_translation(player_pos)));
+        let creature = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(creature_pos),
+                Creature::new("prowler", creature_pos.truncate()),
+            ))
+            .id();
+        (app, creature)
+    }
+
+    #[test]
+    fn roaming_creature_paths_toward_player_then_fights_on_contact() {
+        // The creature faces south by default; put the player in front of it,
+        // inside perception range but well outside contact range.
+        let (mut app, creature) = roaming_app(Vec3::new(0.0, -150.0, 0.0), Vec3::ZERO);
+        // A few frames in sight fill the detection meter.
+        advance_frames(&mut app, Duration::from_millis(100), 3);
+
+        let state = app.world().get::<Creature>(creature).unwrap().state;
+        assert_eq!(state, CreatureState::Chase);
+        let route = app
+            .world()
+            .get::<MoveAlongPath>(creature)
+            .expect("a chasing creature follows an A* path");
+        let last = *route.path.last().unwrap();
+        assert!(
+            Vec2::new(last.x as f32, last.y as f32).distance(Vec2::new(0.0, -150.0)) < 32.0,
+            "path should end at the player, ended at {last:?}",
+        );
+        assert!(!app.world().resource::<BattleState>().active);
+
+        // Player steps onto the creature: contact starts the fight.
+        let mut players = app.world_mut().query_filtered::<&mut Transform, With<Player>>();
+        players.single_mut(app.world_mut()).unwrap().translation = Vec3::new(0.0, -20.0, 0.0);
+        app.update();
+
+        assert!(app.world().resource::<BattleState>().active);
+        assert_eq!(app.world().resource::<GameState>().0, Game_State::Battle);
+        assert!(app.world().get_entity(creature).is_err(), "world creature is consumed");
+    }
+
+    #[test]
+    fn starting_a_battle_cancels_the_players_path() {
+        let (mut app, creature) = roaming_app(Vec3::new(0.0, -20.0, 0.0), Vec3::ZERO);
+        let mut players = app.world_mut().query_filtered::<Entity, With<Player>>();
+        let player = players.single(app.world()).unwrap();
+        app.world_mut().entity_mut(player).insert(MoveAlongPath {
+            path: vec![IVec2::new(0, -20), IVec2::new(0, -200)],
+            current_index: 1,
+            timer: Timer::from_seconds(0.3, TimerMode::Repeating),
+        });
+
+        app.update();
+
+        assert!(app.world().resource::<BattleState>().active);
+        assert!(app.world().get_entity(creature).is_err());
+        assert!(app.world().get::<MoveAlongPath>(player).is_none());
+    }
+
+    #[test]
+    fn patrol_visits_waypoints_in_order_and_loops() {
+        let waypoints = vec![
+            Position { x: 0, y: 0 },
+            Position { x: 96, y: 0 },
+            Position { x: 96, y: 96 },
+        ];
+        let mut app = app_with_manual_time();
+        app.init_resource::<GameState>()
+            .init_resource::<QuadTree>()
+            .init_resource::<crate::core::Global_Variables>()
+            .add_systems(
+                Update,
+                (patrol_system, crate::movement::follow_path_system).chain(),
+            );
+        let mut patrol = Patrol::new(waypoints.clone(), true);
+        patrol.pause_secs = 0.2;
+        patrol.speed = 160.0;
+        let guard = app
+            .world_mut()
+            .spawn((Transform::default(), patrol))
+            .id();
+
+        let mut visited: Vec<usize> = Vec::new();
+        for _ in 0..400 {
+            advance(&mut app, Duration::from_millis(100));
+            let pos = app.world().get::<Transform>(guard).unwrap().translation.truncate();
+            let at = waypoints.iter().position(|w| {
+                pos.distance(Vec2::new(w.x as f32, w.y as f32)) <= PATROL_ARRIVE_RADIUS
+            });
+            if let Some(i) = at {
+                if visited.last() != Some(&i) {
+                    visited.push(i);
+                }
+            }
+            if visited.len() >= 5 {
+                break;
+            }
+        }
+        assert_eq!(visited, vec![0, 1, 2, 0, 1]);
+    }
+
+    #[test]
+    fn non_looping_patrol_walks_back_and_forth() {
+        let waypoints = (0..3).map(|i| Position { x: i * 64, y: 0 }).collect();
+        let mut patrol = Patrol::new(waypoints, false);
+        let mut order = vec![patrol.next];
+        for _ in 0..5 {
+            patrol.advance();
+            order.push(patrol.next);
+        }
+        assert_eq!(order, vec![0, 1, 2, 1, 0, 1]);
+    }
+
+    #[test]
+    fn detection_fills_inside_stealthed_radius_and_drains_behind_cover() {
+        use crate::quadtree::{Collider, QuadtreeNode};
+
+        let params = BehaviorParams {
+            detection_radius: 200.0,
+            notice_radius: 0.0,
+            ..Default::default()
+        };
+        let mut creature = Creature::new("prowler", Vec2::ZERO);
+        let mut open = QuadTree(QuadtreeNode::new(
+            Rect::from_center_size(Vec2::ZERO, Vec2::splat(2048.0)),
+            0,
+        ));
+
+        // Stealth 5 shrinks the radius to 140: at 150 units the meter stays empty.
+        for _ in 0..10 {
+            sense_player(&mut creature, Vec2::ZERO, Vec2::new(0.0, -150.0), &params, 5, &open, 0.1);
+        }
+        assert_eq!(creature.detection, 0.0);
+
+        // Step inside the reduced radius and the meter fills until it trips.
+        let player = Vec2::new(0.0, -120.0);
+        sense_player(&mut creature, Vec2::ZERO, player, &params, 5, &open, 0.1);
+        assert!(creature.detection > 0.0 && creature.detection < 1.0);
+        creature.detection = 0.9;
+
+        // A wall between them breaks line of sight: the meter drains instead.
+        open.0.insert(Collider {
+            bounds: Rect::from_center_size(Vec2::new(0.0, -60.0), Vec2::new(64.0, 16.0)),
+        });
+        let tripped = sense_player(&mut creature, Vec2::ZERO, player, &params, 5, &open, 0.1);
+        assert!(!tripped);
+        assert!(creature.detection < 0.9);
+    }
+
     #[test]
     fn spotting_respects_vision_cone_and_radii() {
         let params = BehaviorParams {
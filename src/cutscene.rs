@@ -16,8 +16,10 @@ use bevy::prelude::*;
 use bevy_common_assets::ron::RonAssetPlugin;
 use serde::Deserialize;
 
+use crate::combat_plugin::Dead;
 use crate::core::Global_Variables;
 use crate::render3d::CameraRig;
+use crate::story_flags::StoryFlags;
 
 /// One scripted step. `at` is seconds from cutscene start.
 #[derive(Deserialize, Debug, Clone)]
@@ -44,10 +46,36 @@ pub enum CutsceneAction {
         zoom: Option<f32>,
         duration: f32,
     },
+    /// Hold the timeline here until `condition` is met, or for at most
+    /// `timeout` seconds if given. Later steps keep their spacing: their `at`
+    /// is counted from when the wait ends.
+    WaitUntil {
+        condition: WaitCondition,
+        #[serde(default)]
+        timeout: Option<f32>,
+    },
     /// Stop the cutscene and restore the previous camera-lock state.
     End,
 }
 
+/// Something a [`CutsceneAction::WaitUntil`] step waits for.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum WaitCondition {
+    /// The named story flag is set.
+    Flag(String),
+    /// The entity with this `Name` is marked dead.
+    Dead(String),
+}
+
+impl WaitCondition {
+    pub fn is_met(&self, flags: Option<&StoryFlags>, dead: &Query<&Name, With<Dead>>) -> bool {
+        match self {
+            WaitCondition::Flag(flag) => flags.is_some_and(|f| f.is_set(flag)),
+            WaitCondition::Dead(name) => dead.iter().any(|n| n.as_str() == name),
+        }
+    }
+}
+
 #[derive(Asset, TypePath, Deserialize, Debug, Clone)]
 pub struct CutsceneAsset {
     pub steps: Vec<CutsceneStep>,
@@ -75,6 +103,8 @@ pub struct CutscenePlayer {
     pub elapsed: f32,
     pub cursor: usize,
     pub tween: Option<CameraTween>,
+    /// Seconds spent blocked on the current `WaitUntil` step.
+    pub waited: f32,
     /// Camera-lock state to restore on `End`.
     saved_lock: bool,
     saved_lock_valid: bool,
@@ -89,12 +119,14 @@ impl CutscenePlayer {
         self.elapsed = 0.0;
         self.cursor = 0;
         self.tween = None;
+        self.waited = 0.0;
     }
     fn stop(&mut self) {
         self.asset = None;
         self.elapsed = 0.0;
         self.cursor = 0;
         self.tween = None;
+        self.waited = 0.0;
     }
 }
 
@@ -115,6 +147,8 @@ pub fn advance_cutscene(
     cutscenes: Res<Assets<CutsceneAsset>>,
     mut globals: ResMut<Global_Variables>,
     rig: Res<CameraRig>,
+    flags: Option<Res<StoryFlags>>,
+    dead: Query<&Name, With<Dead>>,
 ) {
     if !player.is_active() {
         return;
@@ -123,7 +157,8 @@ pub fn advance_cutscene(
         return;
     };
     let asset = asset.clone();
-    player.elapsed += time.delta_secs();
+    let dt = time.delta_secs();
+    player.elapsed += dt;
 
     while player.cursor < asset.steps.len() {
         let step = &asset.steps[player.cursor];
@@ -158,6 +193,21 @@ pub fn advance_cutscene(
                     duration: duration.max(0.001),
                 });
             }
+            CutsceneAction::WaitUntil { condition, timeout } => {
+                let met = condition.is_met(flags.as_deref(), &dead);
+                let timed_out = timeout.is_some_and(|t| player.waited >= t);
+                if !met && !timed_out {
+                    // Pin the clock to this step so nothing after it fires
+                    // early once the wait clears.
+                    player.waited += dt;
+                    player.elapsed = step.at;
+                    return;
+                }
+                if timed_out && !met {
+                    info!("cutscene: gave up waiting for {condition:?}");
+                }
+                player.waited = 0.0;
+            }
             CutsceneAction::End => {
                 if player.saved_lock_valid {
                     globals.0.camera_locked = player.saved_lock;
@@ -0,0 +1,149 @@
+//! Examine: the light-weight sibling of talking to something.
+//!
+//! An [`Examinable`] carries a single line of description. Pressing
+//! [`EXAMINE_KEY`] next to one pops that line up in a small box at the top of
+//! the screen for a few seconds. Unlike an [`super::Interactable`] there is no
+//! dialogue box, no choices, and the game stays in `Exploring` the whole time,
+//! so the player can keep walking while it's up.
+
+use bevy::prelude::*;
+
+use crate::combat_ability::Concealed;
+use crate::core::{GameState, Game_State, Player};
+use crate::quadtree::aabb_collision;
+use crate::ui_style::{palette, radius, spacing};
+
+/// The secondary interact key (`X` talks, `T` takes a look).
+pub const EXAMINE_KEY: KeyCode = KeyCode::KeyT;
+
+/// How long an examine popup stays up, in seconds.
+pub const EXAMINE_POPUP_SECONDS: f32 = 4.0;
+
+#[derive(Component, Clone, Debug)]
+pub struct Examinable {
+    pub description: String,
+}
+
+/// Root of the examine popup; despawned when `remaining` runs out.
+#[derive(Component)]
+pub struct ExaminePopup {
+    pub remaining: f32,
+}
+
+#[derive(Component)]
+pub struct ExamineText;
+
+/// Show the description of the examinable the player is standing at. A fresh
+/// examine replaces whatever popup is already up.
+pub fn examine(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    game_state: Res<GameState>,
+    player_q: Query<&Transform, With<Player>>,
+    examinable_q: Query<(&Transform, &Examinable), Without<Concealed>>,
+    popup_q: Query<Entity, With<ExaminePopup>>,
+) {
+    if game_state.0 != Game_State::Exploring || !keys.just_pressed(EXAMINE_KEY) {
+        return;
+    }
+    let Some(examinable) = player_q.iter().find_map(|player| {
+        let player_rect =
+            Rect::from_center_size(player.translation.truncate(), Vec2::new(32.0, 32.0));
+        examinable_q.iter().find_map(|(t, examinable)| {
+            let other = Rect::from_center_size(t.translation.truncate(), Vec2::new(32.0, 32.0));
+            aabb_collision(player_rect, other).then_some(examinable)
+        })
+    }) else {
+        return;
+    };
+
+    for popup in &popup_q {
+        commands.entity(popup).despawn();
+    }
+    spawn_examine_popup(&mut commands, &examinable.description);
+}
+
+fn spawn_examine_popup(commands: &mut Commands, description: &str) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(spacing::LG),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ZIndex(10),
+            ExaminePopup { remaining: EXAMINE_POPUP_SECONDS },
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        max_width: Val::Px(560.0),
+                        padding: UiRect::axes(Val::Px(spacing::MD), Val::Px(spacing::SM)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        border_radius: BorderRadius::all(Val::Px(radius::MD)),
+                        ..default()
+                    },
+                    BackgroundColor(palette::BG_PANEL),
+                    BorderColor::all(palette::BORDER_ACCENT),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new(description),
+                        TextFont {
+                            font_size: 17.0,
+                            ..Default::default()
+                        },
+                        TextColor(palette::TEXT_PRIMARY),
+                        ExamineText,
+                    ));
+                });
+        });
+}
+
+/// Count the popup down, and drop it early if the game leaves `Exploring`.
+pub fn tick_examine_popup(
+    mut commands: Commands,
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut popup_q: Query<(Entity, &mut ExaminePopup)>,
+) {
+    for (entity, mut popup) in &mut popup_q {
+        popup.remaining -= time.delta_secs();
+        if popup.remaining <= 0.0 || game_state.0 != Game_State::Exploring {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examining_shows_the_description_without_opening_dialogue() {
+        let mut app = crate::test_support::app_with_manual_time();
+        app.insert_resource(GameState(Game_State::Exploring))
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(Update, (examine, tick_examine_popup).chain());
+        app.world_mut().spawn((Player, Transform::from_xyz(100.0, 100.0, 0.0)));
+        app.world_mut().spawn((
+            Transform::from_xyz(110.0, 100.0, 0.0),
+            Examinable { description: "A weathered stone lantern.".to_string() },
+        ));
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(EXAMINE_KEY);
+        app.update();
+
+        let world = app.world_mut();
+        let shown: Vec<String> = world
+            .query_filtered::<&Text, With<ExamineText>>()
+            .iter(world)
+            .map(|t| t.0.clone())
+            .collect();
+        assert_eq!(shown, vec!["A weathered stone lantern.".to_string()]);
+        assert_eq!(world.resource::<GameState>().0, Game_State::Exploring);
+    }
+}
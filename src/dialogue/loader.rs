@@ -89,6 +89,8 @@ struct LegacyLine {
     speaker: String,
     text: String,
     #[serde(default)]
+    portrait: Option<String>,
+    #[serde(default)]
     next: Option<String>,
     #[serde(default)]
     choices: Option<Vec<LegacyChoice>>,
@@ -170,6 +172,7 @@ fn legacy_to_scene(
                             ..Default::default()
                         },
                         text: legacy.text,
+                        portrait: legacy.portrait,
                         on_enter: Vec::new(),
                         condition: None,
                         next: legacy.next,
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use bevy::prelude::Messages;
 
+mod examine;
 mod loader;
 mod runtime;
 mod scene_player;
@@ -8,14 +9,16 @@ mod schema;
 mod stage;
 mod ui;
 
+use examine::{examine, tick_examine_popup};
 use runtime::dispatch_on_enter;
 use scene_player::{tick_scene_playback, ScenePlayback};
 use stage::{
     auto_place_line_speaker, despawn_stage_when_inactive, refresh_stage_visuals, StageState,
 };
 use ui::{
-    create_first_dialogue, gui_selection, interact, redraw_when_runtime_changes,
-    spawn_dialogue_box, DialogueSet, DialogueTriggerEvent,
+    create_first_dialogue, gui_selection, interact, load_dialogue_portraits,
+    redraw_when_runtime_changes, reveal_dialogue_portraits, spawn_dialogue_box,
+    sync_dialogue_portrait, DialogueSet, DialogueTriggerEvent,
 };
 
 // Schema and runtime types are surfaced for future steps (editor schema dump,
@@ -33,7 +36,11 @@ pub use schema::{
     ChoiceNode, ChoiceOption, Condition, DialogueNode, DialogueScene, Effect, LineNode, NodeId,
     QuestStatusFilter, ReputationTargetRef, SceneAction, SceneId, SceneNode, Speaker, SpeakerSlot,
 };
-pub use ui::{CachedInteractables, DialogueBoxTriggerEvent, Interactable};
+pub use examine::Examinable;
+pub use ui::{
+    interactable_footprint, CachedInteractables, DialogueBoxTriggerEvent, Interactable,
+    InteractableIndex,
+};
 
 pub struct DialoguePlugin;
 
@@ -47,6 +54,7 @@ impl Plugin for DialoguePlugin {
             .init_resource::<ScenePlayback>()
             .init_resource::<StageState>()
             .insert_resource(CachedInteractables(Vec::new()))
+            .init_resource::<InteractableIndex>()
             .insert_resource(Messages::<DialogueBoxTriggerEvent>::default())
             .insert_resource(Messages::<DialogueTriggerEvent>::default())
             .add_systems(Update, spawn_dialogue_box.in_set(DialogueSet::Spawn))
@@ -56,6 +64,14 @@ impl Plugin for DialoguePlugin {
                     .in_set(DialogueSet::Interact)
                     .after(DialogueSet::Spawn),
             )
+            // After interact, so a conversation opened this frame wins over
+            // an examine.
+            .add_systems(
+                Update,
+                (examine, tick_examine_popup)
+                    .chain()
+                    .after(DialogueSet::Interact),
+            )
             .add_systems(Update, create_first_dialogue)
             .add_systems(Update, gui_selection)
             // Scene-action timeline runs after input so a fresh advance can
@@ -79,6 +95,12 @@ impl Plugin for DialoguePlugin {
                     .after(dispatch_on_enter)
                     .before(refresh_stage_visuals),
             )
+            .add_systems(
+                Update,
+                (sync_dialogue_portrait, load_dialogue_portraits, reveal_dialogue_portraits)
+                    .chain()
+                    .after(dispatch_on_enter),
+            )
             .add_systems(Update, despawn_stage_when_inactive);
     }
 }
@@ -4,8 +4,9 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy::prelude::Messages;
 
+use crate::characters::{CharacterKind, SelectedParty};
 use crate::city_data::CityCatalog;
-use crate::combat_plugin::Bound;
+use crate::combat_plugin::{Bound, Dead};
 use crate::core::Player;
 use crate::economy::{InventoryStack, Merchants, PlayerInventory, PlayerWallet};
 use crate::governance::{ReputationChangeEvent, ReputationLedger, ReputationTarget};
@@ -67,18 +68,33 @@ impl DialogueRuntime {
     /// at the scene's declared `start` node) or a node id (start at that node
     /// inside whatever scene contains it). The dual lookup keeps the
     /// `Interactable` data — which references node ids in the pre-schema
-    /// flat catalog — working unchanged.
-    pub fn start(&mut self, target: String, catalog: &DialogueCatalog) -> bool {
-        if let Some(scene) = catalog.scenes.get(&target) {
-            self.current_node = Some(scene.start.clone());
-            self.current_scene = Some(target);
+    /// flat catalog — working unchanged. Opens on the first line `ctx` lets
+    /// show (see [`first_visible_node`]); one with nothing visible doesn't
+    /// open at all.
+    pub fn start(
+        &mut self,
+        target: String,
+        catalog: &DialogueCatalog,
+        ctx: &ConditionView,
+    ) -> bool {
+        let (scene_id, node) = if let Some(scene) = catalog.scenes.get(&target) {
+            (target, scene.start.clone())
         } else if let Some(scene_id) = catalog.node_index.get(&target).cloned() {
-            self.current_node = Some(target);
-            self.current_scene = Some(scene_id);
+            (scene_id, target)
         } else {
             warn!("DialogueCatalog has no scene or node id '{target}'");
             return false;
-        }
+        };
+        let node = match catalog.scenes.get(&scene_id) {
+            Some(scene) => first_visible_node(scene, Some(node), ctx),
+            None => Some(node),
+        };
+        let Some(node) = node else {
+            info!("Dialogue '{scene_id}' has no line to show yet");
+            return false;
+        };
+        self.current_node = Some(node);
+        self.current_scene = Some(scene_id);
         self.active = true;
         self.just_spawned = true;
         true
@@ -98,6 +114,21 @@ impl DialogueRuntime {
             self.end();
         }
     }
+
+    /// [`goto`](Self::goto) the first line from `node` that `ctx` lets show,
+    /// passing over gated ones the way [`first_visible_node`] does.
+    pub fn goto_visible(
+        &mut self,
+        node: Option<NodeId>,
+        catalog: &DialogueCatalog,
+        ctx: &ConditionView,
+    ) {
+        let node = match self.current_scene(catalog) {
+            Some(scene) => first_visible_node(scene, node, ctx),
+            None => node,
+        };
+        self.goto(node);
+    }
 }
 
 /// Player-selected option index for the current `Choice` node, if any.
@@ -121,7 +152,7 @@ pub struct CurrentMusic(pub Option<Entity>);
 // ---------------------------------------------------------------------------
 
 #[derive(SystemParam)]
-pub struct ConditionContext<'w> {
+pub struct ConditionContext<'w, 's> {
     pub flags: Res<'w, StoryFlags>,
     pub inventory: Res<'w, PlayerInventory>,
     pub quest_log: Res<'w, QuestLog>,
@@ -129,6 +160,8 @@ pub struct ConditionContext<'w> {
     pub current_area: Res<'w, CurrentArea>,
     pub cities: Res<'w, CityCatalog>,
     pub merchants: Res<'w, Merchants>,
+    pub party: Res<'w, SelectedParty>,
+    pub downed_q: Query<'w, 's, &'static CharacterKind, With<Dead>>,
 }
 
 /// A borrowed, plain-reference view of the resources needed to evaluate a
@@ -144,9 +177,12 @@ pub struct ConditionView<'a> {
     pub current_area: &'a CurrentArea,
     pub cities: &'a CityCatalog,
     pub merchants: &'a Merchants,
+    pub party: &'a SelectedParty,
+    /// Members whose world entity is down (`Dead`); they're still in `party`.
+    pub downed: Vec<CharacterKind>,
 }
 
-impl<'w> ConditionContext<'w> {
+impl<'w, 's> ConditionContext<'w, 's> {
     pub fn view(&self) -> ConditionView<'_> {
         ConditionView {
             flags: &self.flags,
@@ -156,6 +192,8 @@ impl<'w> ConditionContext<'w> {
             current_area: &self.current_area,
             cities: &self.cities,
             merchants: &self.merchants,
+            party: &self.party,
+            downed: self.downed_q.iter().copied().collect(),
         }
     }
 }
@@ -209,9 +247,33 @@ pub fn evaluate_condition(cond: &Condition, ctx: &ConditionView) -> bool {
             };
             value >= *min
         }
+        Condition::RequiresMember { member, alive } => {
+            ctx.party.0.contains(member) && !(*alive && ctx.downed.contains(member))
+        }
     }
 }
 
+/// Follow `next` links from `id` past every Line whose `condition` fails, so a
+/// gated line drops out of the conversation instead of showing. `None` ends
+/// the dialogue, same as [`DialogueRuntime::goto`].
+pub fn first_visible_node(
+    scene: &DialogueScene,
+    mut id: Option<NodeId>,
+    ctx: &ConditionView,
+) -> Option<NodeId> {
+    // Bounded by the node count so a cycle of gated lines can't hang.
+    for _ in 0..=scene.nodes.len() {
+        let Some(DialogueNode::Line(line)) = id.as_ref().and_then(|id| scene.nodes.get(id)) else {
+            return id;
+        };
+        match &line.condition {
+            Some(cond) if !evaluate_condition(cond, ctx) => id = line.next.clone(),
+            _ => return id,
+        }
+    }
+    None
+}
+
 impl From<QuestStatus> for QuestStatusFilter {
     fn from(s: QuestStatus) -> Self {
         match s {
@@ -238,6 +300,8 @@ pub struct EffectDispatcher<'w, 's> {
     pub current_area: Res<'w, CurrentArea>,
     pub cities: Res<'w, CityCatalog>,
     pub merchants: Res<'w, Merchants>,
+    pub party: Res<'w, SelectedParty>,
+    pub downed_q: Query<'w, 's, &'static CharacterKind, With<Dead>>,
     pub reputation_events: ResMut<'w, Messages<ReputationChangeEvent>>,
     pub flag_changed_events: ResMut<'w, Messages<FlagChangedEvent>>,
     pub add_quest_events: ResMut<'w, Messages<AddQuestEvent>>,
@@ -261,6 +325,8 @@ impl<'w, 's> EffectDispatcher<'w, 's> {
             current_area: &self.current_area,
             cities: &self.cities,
             merchants: &self.merchants,
+            party: &self.party,
+            downed: self.downed_q.iter().copied().collect(),
         }
     }
 
@@ -466,7 +532,9 @@ pub fn dispatch_on_enter(
     let to_dispatch: Vec<Effect> = line.on_enter.clone();
     effects.dispatch_all(&to_dispatch);
     if let Some(target) = effects.pending_scene_change.0.take() {
-        runtime.start(target, &catalog);
+        if !runtime.start(target, &catalog, &effects.condition_view()) {
+            runtime.end();
+        }
     }
 }
 
@@ -505,3 +573,71 @@ fn resolve_reputation_target(
         }),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialogue::{LineNode, Speaker};
+
+    fn line(text: &str, condition: Option<Condition>, next: Option<&str>) -> DialogueNode {
+        DialogueNode::Line(LineNode {
+            speaker: Speaker::default(),
+            text: text.to_string(),
+            portrait: None,
+            on_enter: Vec::new(),
+            condition,
+            next: next.map(str::to_string),
+        })
+    }
+
+    #[test]
+    fn member_gated_line_shows_only_with_that_member_in_the_party() {
+        let requires_houjou =
+            Condition::RequiresMember { member: CharacterKind::Houjou, alive: true };
+        let scene = DialogueScene {
+            id: "camp".to_string(),
+            background: None,
+            music: None,
+            start: "greet".to_string(),
+            nodes: HashMap::from([
+                ("greet".to_string(), line("Evening.", None, Some("houjou"))),
+                (
+                    "houjou".to_string(),
+                    line("Houjou is here.", Some(requires_houjou), Some("bye")),
+                ),
+                ("bye".to_string(), line("Rest well.", None, None)),
+            ]),
+        };
+        let flags = StoryFlags::default();
+        let inventory = PlayerInventory::default();
+        let quest_log = QuestLog::default();
+        let reputation = ReputationLedger::default();
+        let area = CurrentArea::default();
+        let cities = CityCatalog::default();
+        let merchants = Merchants::default();
+        let view = |party: &SelectedParty, downed: Vec<CharacterKind>| {
+            first_visible_node(
+                &scene,
+                Some("houjou".to_string()),
+                &ConditionView {
+                    flags: &flags,
+                    inventory: &inventory,
+                    quest_log: &quest_log,
+                    reputation: &reputation,
+                    current_area: &area,
+                    cities: &cities,
+                    merchants: &merchants,
+                    party,
+                    downed,
+                },
+            )
+        };
+
+        let with = SelectedParty(vec![CharacterKind::Rina, CharacterKind::Houjou]);
+        let without = SelectedParty(vec![CharacterKind::Rina, CharacterKind::Sayaka]);
+        assert_eq!(view(&with, Vec::new()).as_deref(), Some("houjou"));
+        assert_eq!(view(&without, Vec::new()).as_deref(), Some("bye"));
+        // In the party but down doesn't count when the line wants him alive.
+        assert_eq!(view(&with, vec![CharacterKind::Houjou]).as_deref(), Some("bye"));
+    }
+}
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::runtime::{CurrentMusic, DialogueCatalog, DialogueRuntime};
+use super::runtime::{ConditionContext, CurrentMusic, DialogueCatalog, DialogueRuntime};
 use super::schema::{DialogueNode, NodeId, SceneAction};
 use super::stage::{FadeOverlay, StageEntry, StageState};
 
@@ -82,6 +82,7 @@ pub fn tick_scene_playback(
     mut commands: Commands,
     mut current_music: ResMut<CurrentMusic>,
     mut fade_q: Query<&mut BackgroundColor, With<FadeOverlay>>,
+    conditions: ConditionContext,
 ) {
     if !playback.active {
         return;
@@ -199,9 +200,8 @@ pub fn tick_scene_playback(
         // Timeline exhausted — hand control back to the runtime so the next
         // node (Line/Choice/another Scene) can render.
         let next = playback.next_node.take();
-        let _ = catalog;
         playback.reset();
-        runtime.goto(next);
+        runtime.goto_visible(next, &catalog, &conditions.view());
     }
 }
 
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::characters::CharacterKind;
+
 pub type NodeId = String;
 pub type SceneId = String;
 pub type ItemId = u32;
@@ -30,6 +32,10 @@ pub enum DialogueNode {
 pub struct LineNode {
     pub speaker: Speaker,
     pub text: String,
+    /// Image shown beside the text in the dialogue box, relative to
+    /// `assets/`. A missing file falls back to text only.
+    #[serde(default)]
+    pub portrait: Option<String>,
     #[serde(default)]
     pub on_enter: Vec<Effect>,
     #[serde(default)]
@@ -135,6 +141,12 @@ pub enum Condition {
     HasItem { item: ItemId, qty: u32 },
     QuestStatus { quest: QuestId, status: QuestStatusFilter },
     ReputationAtLeast { target: ReputationTargetRef, min: i32 },
+    /// `member` is in the party; with `alive`, also not downed.
+    RequiresMember {
+        member: CharacterKind,
+        #[serde(default)]
+        alive: bool,
+    },
     All(Vec<Condition>),
     Any(Vec<Condition>),
     Not(Box<Condition>),
@@ -1,10 +1,12 @@
+use bevy::asset::LoadState;
 use bevy::ecs::system::SystemParam;
 use bevy::input::keyboard::KeyCode;
 use bevy::prelude::*;
 use bevy::prelude::Messages;
 
+use crate::combat_ability::Concealed;
 use crate::core::{GameState, Game_State, Player};
-use crate::quadtree::aabb_collision;
+use crate::quadtree::QuadTree;
 use crate::quests::DialogueChoicePickedEvent;
 use crate::ui_style::{palette, radius, spacing};
 
@@ -41,17 +43,46 @@ pub struct Interactable {
 #[derive(Resource, Default)]
 pub struct CachedInteractables(pub Vec<(Transform, Interactable)>);
 
+/// Interactable footprints keyed by entity, so opening a conversation asks the
+/// index what is within reach instead of scanning every interactable.
+#[derive(Resource, Default)]
+pub struct InteractableIndex(pub QuadTree);
+
+/// The square an interactable at `center` occupies in [`InteractableIndex`].
+pub fn interactable_footprint(center: Vec2) -> Rect {
+    Rect::from_center_size(center, Vec2::new(32.0, 32.0))
+}
+
+/// How close the player's centre has to be to an interactable's footprint to
+/// talk to it: half the player's own width.
+const INTERACT_REACH: f32 = 16.0;
+
 #[derive(Component)]
 pub struct DialogueText;
 
+/// The speaker's name above the line, styled apart from the text itself.
+#[derive(Component)]
+pub struct DialogueSpeakerName;
+
+/// Holder to the left of the text that a line's portrait is spawned into.
+#[derive(Component)]
+pub struct DialoguePortraitSlot;
+
+/// A line's portrait in the dialogue box; `0` is its asset path.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct DialoguePortrait(pub String);
+
 #[derive(Component)]
 pub struct ChoiceButton;
 
 #[derive(Component)]
 pub struct DialogueBox;
 
+/// Edge length of the square line portrait in the dialogue box.
+const PORTRAIT_SIZE: f32 = 144.0;
+
 // ---------------------------------------------------------------------------
-// UI param bundle (text-only now; portraits are owned by the stage)
+// UI param bundle (text and speaker name; stage portraits live on the stage)
 // ---------------------------------------------------------------------------
 
 #[derive(SystemParam)]
@@ -59,6 +90,7 @@ pub struct DialogueUiParams<'w, 's> {
     pub commands: Commands<'w, 's>,
     pub box_query: Query<'w, 's, (Entity, &'static Children), With<DialogueBox>>,
     pub text_query: Query<'w, 's, Entity, With<DialogueText>>,
+    pub name_query: Query<'w, 's, Entity, With<DialogueSpeakerName>>,
     pub button_query: Query<'w, 's, Entity, With<ChoiceButton>>,
 }
 
@@ -114,15 +146,42 @@ pub fn spawn_dialogue_box(
                     DialogueBox,
                 ))
                 .with_children(|box_node| {
-                    box_node.spawn((
-                        TextFont {
-                            font_size: 20.0,
-                            ..Default::default()
-                        },
-                        TextColor(palette::TEXT_PRIMARY),
-                        Text::new(""),
-                        DialogueText,
-                    ));
+                    box_node
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(spacing::LG),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn((Node::default(), DialoguePortraitSlot));
+                            row.spawn(Node {
+                                flex_direction: FlexDirection::Column,
+                                flex_grow: 1.0,
+                                row_gap: Val::Px(spacing::XS),
+                                ..default()
+                            })
+                            .with_children(|column| {
+                                column.spawn((
+                                    TextFont {
+                                        font_size: 17.0,
+                                        ..Default::default()
+                                    },
+                                    TextColor(palette::TEXT_HEADING),
+                                    Text::new(""),
+                                    DialogueSpeakerName,
+                                ));
+                                column.spawn((
+                                    TextFont {
+                                        font_size: 20.0,
+                                        ..Default::default()
+                                    },
+                                    TextColor(palette::TEXT_PRIMARY),
+                                    Text::new(""),
+                                    DialogueText,
+                                ));
+                            });
+                        });
                 });
         });
     events_dialogue.write(DialogueTriggerEvent);
@@ -208,15 +267,17 @@ pub fn gui_selection(
 
 #[derive(SystemParam)]
 pub struct InteractInputs<'w, 's> {
-    pub player_q: Query<'w, 's, &'static Transform, With<Player>>,
+    pub player_q: Query<'w, 's, (Entity, &'static Transform), With<Player>>,
+    pub interactables: Query<'w, 's, &'static Interactable, Without<Concealed>>,
+    pub index: Res<'w, InteractableIndex>,
     pub keys: Res<'w, ButtonInput<KeyCode>>,
     pub mouse: Res<'w, ButtonInput<MouseButton>>,
 }
 
 pub fn interact(
+    mut commands: Commands,
     inputs: InteractInputs,
     mut game_state: ResMut<GameState>,
-    cache: Res<CachedInteractables>,
     mut runtime: ResMut<DialogueRuntime>,
     catalog: Res<DialogueCatalog>,
     mut index: ResMut<DialogueSelectedIndex>,
@@ -238,9 +299,10 @@ pub fn interact(
     match game_state.0 {
         Game_State::Exploring if open_pressed => {
             try_open_dialogue(
-                &inputs.player_q,
-                &cache,
+                &mut commands,
+                &inputs,
                 &catalog,
+                &effects.condition_view(),
                 &mut game_state,
                 &mut runtime,
                 &mut index,
@@ -264,27 +326,27 @@ pub fn interact(
 }
 
 fn try_open_dialogue(
-    player_q: &Query<&Transform, With<Player>>,
-    cache: &CachedInteractables,
+    commands: &mut Commands,
+    inputs: &InteractInputs,
     catalog: &DialogueCatalog,
+    conditions: &ConditionView,
     game_state: &mut GameState,
     runtime: &mut DialogueRuntime,
     index: &mut DialogueSelectedIndex,
     events_dialogue_box: &mut Messages<DialogueBoxTriggerEvent>,
 ) {
-    for transform in player_q.iter() {
-        let player_rect = Rect::from_center_size(
-            transform.translation.truncate(),
-            Vec2::new(32.0, 32.0),
-        );
-        let hit = cache.0.iter().find(|(t, _)| {
-            let other = Rect::from_center_size(t.translation.truncate(), Vec2::new(32.0, 32.0));
-            aabb_collision(player_rect, other)
-        });
-        if let Some((_, interactable)) = hit {
-            if !runtime.start(interactable.dialogue_id.clone(), catalog) {
+    for (player, transform) in inputs.player_q.iter() {
+        let hit = inputs
+            .index
+            .0
+            .colliders_near(transform.translation.truncate(), INTERACT_REACH)
+            .into_iter()
+            .find_map(|entity| inputs.interactables.get(entity).ok());
+        if let Some(interactable) = hit {
+            if !runtime.start(interactable.dialogue_id.clone(), catalog, conditions) {
                 continue;
             }
+            crate::movement::cancel_player_path(commands, player);
             game_state.0 = Game_State::Interacting;
             index.0 = None;
             events_dialogue_box.write(DialogueBoxTriggerEvent);
@@ -335,9 +397,11 @@ fn advance_dialogue(
     };
 
     if let Some(scene_id) = effects.pending_scene_change.0.take() {
-        runtime.start(scene_id, catalog);
+        if !runtime.start(scene_id, catalog, &effects.condition_view()) {
+            runtime.end();
+        }
     } else {
-        runtime.goto(next_id);
+        runtime.goto_visible(next_id, catalog, &effects.condition_view());
     }
     index.0 = None;
 
@@ -353,7 +417,7 @@ fn advance_dialogue(
 }
 
 // ---------------------------------------------------------------------------
-// Rendering (text + choices only; portraits live on the stage)
+// Rendering (text, speaker name, line portrait and choices)
 // ---------------------------------------------------------------------------
 
 pub fn redraw_when_runtime_changes(
@@ -421,13 +485,12 @@ fn render_text(ui: &mut DialogueUiParams, speaker: &Speaker, text: &str) {
     let Ok(text_entity) = ui.text_query.single() else {
         return;
     };
-    let label = if speaker.name.trim().is_empty() {
-        text.to_string()
-    } else {
-        format!("{}: {}", speaker.name, text)
-    };
+    // Narration leaves the name line empty.
+    if let Ok(name_entity) = ui.name_query.single() {
+        ui.commands.entity(name_entity).insert(Text::new(speaker.name.trim()));
+    }
     ui.commands.entity(text_entity).insert((
-        Text::new(label),
+        Text::new(text),
         TextFont {
             font_size: 20.0,
             ..Default::default()
@@ -488,6 +551,86 @@ fn render_choice_buttons(
     }
 }
 
+/// Keep the dialogue box's portrait in step with the current line: spawn it
+/// (collapsed until [`reveal_dialogue_portraits`] sees the image load) when
+/// the line names one, drop it otherwise. Choice and Scene nodes never show
+/// one. Only looks again when the line changes or a fresh box needs filling.
+pub fn sync_dialogue_portrait(
+    mut commands: Commands,
+    runtime: Res<DialogueRuntime>,
+    catalog: Res<DialogueCatalog>,
+    slot_q: Query<Entity, With<DialoguePortraitSlot>>,
+    new_slot_q: Query<(), Added<DialoguePortraitSlot>>,
+    portrait_q: Query<(Entity, &DialoguePortrait)>,
+) {
+    if !runtime.is_changed() && !catalog.is_changed() && new_slot_q.is_empty() {
+        return;
+    }
+    let wanted = match runtime.current_node(&catalog) {
+        Some(DialogueNode::Line(LineNode { portrait: Some(path), .. })) if runtime.active => {
+            Some(path.as_str())
+        }
+        _ => None,
+    };
+
+    let mut shown = false;
+    for (entity, portrait) in &portrait_q {
+        if Some(portrait.0.as_str()) == wanted && !shown {
+            shown = true;
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+    if shown {
+        return;
+    }
+    let Some(path) = wanted else { return };
+    let Ok(slot) = slot_q.single() else { return };
+    commands.spawn((
+        Node {
+            display: Display::None,
+            width: Val::Px(PORTRAIT_SIZE),
+            height: Val::Px(PORTRAIT_SIZE),
+            border: UiRect::all(Val::Px(1.0)),
+            border_radius: BorderRadius::all(Val::Px(radius::MD)),
+            ..default()
+        },
+        ImageNode::default(),
+        BorderColor::all(palette::BORDER_SUBTLE),
+        DialoguePortrait(path.to_string()),
+        ChildOf(slot),
+    ));
+}
+
+/// Point freshly spawned portraits at their image.
+pub fn load_dialogue_portraits(
+    asset_server: Res<AssetServer>,
+    mut portrait_q: Query<(&DialoguePortrait, &mut ImageNode), Added<DialoguePortrait>>,
+) {
+    for (portrait, mut image) in &mut portrait_q {
+        image.image = asset_server.load(portrait.0.clone());
+    }
+}
+
+/// Open up a portrait once its image has loaded. One that fails to load (a
+/// typo, an image not shipped yet) stays collapsed, so the line reads
+/// text-only rather than beside an empty frame.
+pub fn reveal_dialogue_portraits(
+    asset_server: Res<AssetServer>,
+    mut portrait_q: Query<(&ImageNode, &mut Node), With<DialoguePortrait>>,
+) {
+    for (image, mut node) in &mut portrait_q {
+        let display = match asset_server.load_state(&image.image) {
+            LoadState::Loaded => Display::Flex,
+            // Still on its way, or `LoadState::Failed`.
+            _ => Display::None,
+        };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
+
 fn despawn_box(ui: &mut DialogueUiParams) {
     for (box_entity, children) in ui.box_query.iter_mut() {
         for child in children.iter() {
@@ -526,3 +669,116 @@ fn find_visible_index(
     let s = selected_orig?;
     visible.iter().position(|(orig, _)| *orig == s)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::dialogue::DialogueScene;
+
+    fn line(text: &str, portrait: Option<&str>) -> DialogueNode {
+        DialogueNode::Line(LineNode {
+            speaker: Speaker { name: "Aoi".to_string(), ..Default::default() },
+            text: text.to_string(),
+            portrait: portrait.map(str::to_string),
+            on_enter: Vec::new(),
+            condition: None,
+            next: None,
+        })
+    }
+
+    #[test]
+    fn only_lines_with_a_portrait_spawn_one() {
+        let mut catalog = DialogueCatalog::default();
+        catalog.scenes.insert(
+            "intro".to_string(),
+            DialogueScene {
+                id: "intro".to_string(),
+                background: None,
+                music: None,
+                start: "with".to_string(),
+                nodes: HashMap::from([
+                    ("with".to_string(), line("Hello.", Some("character.png"))),
+                    ("without".to_string(), line("...", None)),
+                    ("missing".to_string(), line("Who?", Some("portraits/nobody.png"))),
+                ]),
+            },
+        );
+        let mut app = App::new();
+        app.insert_resource(catalog)
+            .insert_resource(DialogueRuntime {
+                active: true,
+                current_scene: Some("intro".to_string()),
+                current_node: Some("with".to_string()),
+                ..Default::default()
+            })
+            .add_systems(Update, sync_dialogue_portrait);
+        app.world_mut().spawn((Node::default(), DialoguePortraitSlot));
+        let portraits = |app: &mut App| {
+            let world = app.world_mut();
+            world.query::<&DialoguePortrait>().iter(world).cloned().collect::<Vec<_>>()
+        };
+
+        app.update();
+        assert_eq!(portraits(&mut app), vec![DialoguePortrait("character.png".to_string())]);
+        app.update();
+        assert_eq!(portraits(&mut app).len(), 1, "an idle frame leaves the portrait be");
+
+        app.world_mut().resource_mut::<DialogueRuntime>().current_node =
+            Some("without".to_string());
+        app.update();
+        assert!(portraits(&mut app).is_empty(), "a line without one should be text-only");
+
+        // Whether the image exists is the asset server's call, not this one's.
+        app.world_mut().resource_mut::<DialogueRuntime>().current_node =
+            Some("missing".to_string());
+        app.update();
+        assert_eq!(
+            portraits(&mut app),
+            vec![DialoguePortrait("portraits/nobody.png".to_string())],
+        );
+    }
+
+    #[test]
+    fn portrait_opens_once_loaded_and_stays_collapsed_if_it_fails() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Image>()
+            .add_systems(Update, reveal_dialogue_portraits);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let collapsed = || Node { display: Display::None, ..default() };
+        let loaded = app
+            .world_mut()
+            .spawn((
+                collapsed(),
+                ImageNode::new(asset_server.add(Image::default())),
+                DialoguePortrait("character.png".to_string()),
+            ))
+            .id();
+        let missing = asset_server.load::<Image>("portraits/nobody.png");
+        let failed = app
+            .world_mut()
+            .spawn((
+                collapsed(),
+                ImageNode::new(missing.clone()),
+                DialoguePortrait("portraits/nobody.png".to_string()),
+            ))
+            .id();
+
+        // The failed load resolves on the IO task pool.
+        for _ in 0..200 {
+            app.update();
+            if matches!(asset_server.load_state(&missing), LoadState::Failed(_)) {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        app.update();
+
+        assert!(matches!(asset_server.load_state(&missing), LoadState::Failed(_)));
+        let display = |e: Entity| app.world().get::<Node>(e).unwrap().display;
+        assert_eq!(display(loaded), Display::Flex);
+        assert_eq!(display(failed), Display::None);
+    }
+}
@@ -47,8 +47,8 @@ const CARAVAN_RIVAL_LOSS_BPS_PER_STEP: u32 = 600;
 
 type RegionId = u16;
 type MerchantId = u16;
-type ItemId = u16;
-type StackQty = u16;
+pub type ItemId = u16;
+pub type StackQty = u16;
 type CityId = u16;
 type CaravanId = u32;
 
@@ -63,6 +63,7 @@ impl Plugin for EconomyPlugin {
             .init_resource::<Merchants>()
             .init_resource::<ActiveMerchant>()
             .init_resource::<PlayerInventory>()
+            .init_resource::<crate::crafting::RecipeBook>()
             .init_resource::<PlayerWallet>()
             .init_resource::<ShopUiState>()
             .init_resource::<MarketFluctuationClock>()
@@ -2102,7 +2103,7 @@ fn item_dynamic_base_price(item: &Equipment, market: &MaterialMarket) -> u32 {
     (scaled.min(u32::MAX as u128)) as u32
 }
 
-fn add_to_inventory(items: &mut Vec<InventoryStack>, item_id: ItemId, qty: StackQty) {
+pub(crate) fn add_to_inventory(items: &mut Vec<InventoryStack>, item_id: ItemId, qty: StackQty) {
     if let Some(stack) = items.iter_mut().find(|s| s.item_id == item_id) {
         stack.quantity = stack.quantity.saturating_add(qty);
     } else {
@@ -2113,19 +2114,45 @@ fn add_to_inventory(items: &mut Vec<InventoryStack>, item_id: ItemId, qty: Stack
     }
 }
 
-fn remove_from_inventory(items: &mut Vec<InventoryStack>, item_id: ItemId, qty: StackQty) -> bool {
-    if let Some(index) = items.iter().position(|s| s.item_id == item_id) {
-        if items[index].quantity < qty {
-            return false;
-        }
-        items[index].quantity -= qty;
-        if items[index].quantity == 0 {
-            items.remove(index);
+pub(crate) fn remove_from_inventory(
+    items: &mut Vec<InventoryStack>,
+    item_id: ItemId,
+    qty: StackQty,
+) -> bool {
+    take_from_inventory(items, item_id, qty).is_ok()
+}
+
+/// How many of `item_id` are held, summed over every stack of it.
+pub(crate) fn held_quantity(items: &[InventoryStack], item_id: ItemId) -> StackQty {
+    items
+        .iter()
+        .filter(|s| s.item_id == item_id)
+        .fold(0, |sum: StackQty, s| sum.saturating_add(s.quantity))
+}
+
+/// Take `qty` of `item_id`, drawing on as many stacks of it as it takes and
+/// dropping the ones that run out. If the stacks together hold too few,
+/// nothing is touched and the amount actually held comes back as the error.
+pub(crate) fn take_from_inventory(
+    items: &mut Vec<InventoryStack>,
+    item_id: ItemId,
+    qty: StackQty,
+) -> Result<(), StackQty> {
+    let held = held_quantity(items, item_id);
+    if held < qty {
+        return Err(held);
+    }
+    let mut left = qty;
+    for stack in items.iter_mut().filter(|s| s.item_id == item_id) {
+        let taken = stack.quantity.min(left);
+        stack.quantity -= taken;
+        left -= taken;
+        if left == 0 {
+            break;
         }
-        true
-    } else {
-        false
     }
+    items.retain(|s| s.item_id != item_id || s.quantity > 0);
+    Ok(())
 }
 
 fn merchant_id_for_region(merchants: &Merchants, region_id: RegionId) -> Option<MerchantId> {
@@ -14,10 +14,16 @@
 //! - **F3** — `HitFlash` (brief warm-white pulse).
 //! - **F4** — `Dissolve` (1-second burn-away with hot edge, then re-forms so
 //!   the demo is repeatable).
+//!
+//! Combat hit visuals live here too: [`VfxConfig`] maps each [`DamageType`] to
+//! a short-lived burst spawned at the target on every `AfterHitEvent`, which
+//! then burns away via [`Dissolve::die`].
 
 use bevy::prelude::*;
 
-use crate::render3d::ToonMaterial;
+use crate::combat_plugin::{AfterHitEvent, DamageType};
+use crate::render3d::{PlaceholderVisual, ToonMaterial};
+use crate::settings::CombatSpeed;
 
 /// Brief additive warm-white pulse on the toon material — for "hit", "damage
 /// number popped", "power-up" feedback. Intensity ramps from `intensity` down
@@ -73,11 +79,12 @@ impl Dissolve {
 /// (and zero the uniform) when the duration elapses.
 pub fn tick_hit_flash(
     time: Res<Time>,
+    speed: Option<Res<CombatSpeed>>,
     mut commands: Commands,
     mut materials: ResMut<Assets<ToonMaterial>>,
     mut q: Query<(Entity, &mut HitFlash, &MeshMaterial3d<ToonMaterial>)>,
 ) {
-    let dt = time.delta_secs();
+    let dt = speed.as_deref().copied().unwrap_or_default().scale(time.delta_secs());
     for (entity, mut flash, mat) in &mut q {
         let first_tick = flash.elapsed == 0.0;
         flash.elapsed += dt;
@@ -109,11 +116,12 @@ pub fn tick_hit_flash(
 /// Animate `dissolve` 0 → 1 over `duration`; despawn or re-form when complete.
 pub fn tick_dissolve(
     time: Res<Time>,
+    speed: Option<Res<CombatSpeed>>,
     mut commands: Commands,
     mut materials: ResMut<Assets<ToonMaterial>>,
     mut q: Query<(Entity, &mut Dissolve, &MeshMaterial3d<ToonMaterial>)>,
 ) {
-    let dt = time.delta_secs();
+    let dt = speed.as_deref().copied().unwrap_or_default().scale(time.delta_secs());
     for (entity, mut diss, mat) in &mut q {
         let first_tick = diss.elapsed == 0.0;
         diss.elapsed += dt;
@@ -173,10 +181,170 @@ pub fn demo_effect_hotkeys(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Hit VFX
+// ---------------------------------------------------------------------------
+
+/// Look of one hit burst: a toon-shaded placeholder of `size` tinted `color`
+/// that dissolves over `lifetime` seconds.
+#[derive(Clone, Copy, Debug)]
+pub struct VfxSpec {
+    pub color: Color,
+    /// Footprint (x, y) and height (z) of the burst.
+    pub size: Vec3,
+    pub lifetime: f32,
+}
+
+/// Which burst each damage type spawns on the target. Each type reads
+/// differently at a glance: a small grey scuff for physical, a tall orange
+/// flare for fire, a wide pale-blue shard for ice, a white-violet flash for
+/// true damage.
+#[derive(Resource, Clone, Debug)]
+pub struct VfxConfig {
+    pub physical: VfxSpec,
+    pub fire: VfxSpec,
+    pub ice: VfxSpec,
+    pub true_damage: VfxSpec,
+}
+
+impl Default for VfxConfig {
+    fn default() -> Self {
+        Self {
+            physical: VfxSpec {
+                color: Color::srgb(0.75, 0.72, 0.68),
+                size: Vec3::new(14.0, 14.0, 10.0),
+                lifetime: 0.35,
+            },
+            fire: VfxSpec {
+                color: Color::srgb(1.0, 0.45, 0.1),
+                size: Vec3::new(18.0, 18.0, 34.0),
+                lifetime: 0.6,
+            },
+            ice: VfxSpec {
+                color: Color::srgb(0.6, 0.85, 1.0),
+                size: Vec3::new(26.0, 26.0, 12.0),
+                lifetime: 0.7,
+            },
+            true_damage: VfxSpec {
+                color: Color::srgb(0.92, 0.85, 1.0),
+                size: Vec3::new(20.0, 20.0, 20.0),
+                lifetime: 0.45,
+            },
+        }
+    }
+}
+
+impl VfxConfig {
+    pub fn spec(&self, damage_type: DamageType) -> &VfxSpec {
+        match damage_type {
+            DamageType::Physical => &self.physical,
+            DamageType::Fire => &self.fire,
+            DamageType::Ice => &self.ice,
+            DamageType::True => &self.true_damage,
+        }
+    }
+}
+
+/// Marks a spawned hit burst and records what kind of hit it shows.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct HitVfx {
+    pub damage_type: DamageType,
+}
+
+/// Spawn the configured burst at the target of every landed hit. The burst
+/// carries [`Dissolve::die`], so `tick_dissolve` fades and despawns it once the
+/// placeholder has been hydrated into a toon mesh.
+pub fn spawn_hit_vfx_system(
+    mut commands: Commands,
+    config: Res<VfxConfig>,
+    mut hits: MessageReader<AfterHitEvent>,
+    transforms: Query<&Transform>,
+) {
+    for ev in hits.read() {
+        if ev.amount <= 0 {
+            continue;
+        }
+        let Ok(target_tf) = transforms.get(ev.target) else {
+            continue;
+        };
+        let spec = config.spec(ev.damage_type);
+        commands.spawn((
+            PlaceholderVisual::prop(spec.color, spec.size.truncate(), spec.size.z).toon(),
+            Transform::from_translation(target_tf.translation),
+            HitVfx {
+                damage_type: ev.damage_type,
+            },
+            Dissolve::die(spec.lifetime),
+            Name::new("HitVfx"),
+        ));
+    }
+}
+
 pub struct EffectsPlugin;
 
 impl Plugin for EffectsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (tick_hit_flash, tick_dissolve, demo_effect_hotkeys));
+        app.init_resource::<VfxConfig>().add_systems(
+            Update,
+            (
+                tick_hit_flash,
+                tick_dissolve,
+                demo_effect_hotkeys,
+                spawn_hit_vfx_system.after(crate::combat_plugin::apply_damage_system),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat_plugin::ActionCause;
+
+    #[test]
+    fn fire_hit_spawns_fire_vfx_at_target() {
+        let mut app = App::new();
+        app.add_message::<AfterHitEvent>()
+            .init_resource::<VfxConfig>()
+            .add_systems(Update, spawn_hit_vfx_system);
+        let attacker = app.world_mut().spawn(Transform::default()).id();
+        let target_pos = Vec3::new(120.0, -40.0, 0.0);
+        let target = app
+            .world_mut()
+            .spawn(Transform::from_translation(target_pos))
+            .id();
+        app.world_mut().write_message(AfterHitEvent {
+            attacker: Some(attacker),
+            target,
+            amount: 12,
+            damage_type: DamageType::Fire,
+            cause: ActionCause::Player,
+        });
+        app.update();
+
+        let world = app.world_mut();
+        let mut q = world.query::<(&HitVfx, &Transform, &PlaceholderVisual)>();
+        let spawned: Vec<_> = q.iter(world).collect();
+        assert_eq!(spawned.len(), 1);
+        let (vfx, tf, visual) = spawned[0];
+        assert!(matches!(vfx.damage_type, DamageType::Fire));
+        assert_eq!(tf.translation, target_pos);
+        assert_eq!(visual.color, VfxConfig::default().fire.color);
+    }
+
+    #[test]
+    fn each_damage_type_has_a_distinct_look() {
+        let config = VfxConfig::default();
+        let colors = [
+            config.spec(DamageType::Physical).color,
+            config.spec(DamageType::Fire).color,
+            config.spec(DamageType::Ice).color,
+            config.spec(DamageType::True).color,
+        ];
+        for (i, a) in colors.iter().enumerate() {
+            for b in &colors[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
     }
 }
@@ -26,14 +26,17 @@ use bevy::prelude::*;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use crate::battle::{BattleParticipant, BattleSide, EnemyEncounter, FINAL_BOSS_ENCOUNTER_ID};
+use crate::battle::{
+    BattleParticipant, BattleResults, BattleSide, EnemyEncounter, FINAL_BOSS_ENCOUNTER_ID,
+};
 use crate::characters::CharacterKind;
 use crate::combat_plugin::{
     DeathEvent, Equipment, EquipmentLoadout, EquipmentType, Inventory, InventoryItemCatalog,
-    InventoryItemDefinition, InventoryItemKind, PlayerControlled,
+    InventoryItemDefinition, InventoryItemKind, LootItem, PlayerControlled,
 };
 use crate::economy::{ItemCatalog, PlayerInventory, PlayerWallet};
 use crate::money::Money;
+use crate::world_ticker::WorldTicker;
 
 const ITEMS_PATH: &str = "assets/data/items.ron";
 
@@ -117,13 +120,14 @@ pub const LOOT_RANGE: f32 = 40.0;
 /// entity is still alive here because battle-end despawns are deferred). The
 /// body keeps the enemy's inventory for the player to loot afterwards — closing
 /// the kill → loot → equip loop without silently filling the bag.
-fn enemy_loot_drop_system(
+pub(crate) fn enemy_loot_drop_system(
     mut commands: Commands,
     mut deaths: MessageReader<DeathEvent>,
     participants: Query<
         (&BattleSide, Option<&EnemyEncounter>, &Transform),
         With<BattleParticipant>,
     >,
+    mut results: Option<ResMut<BattleResults>>,
 ) {
     let mut rng = rand::rng();
     for ev in deaths.read() {
@@ -138,6 +142,14 @@ fn enemy_loot_drop_system(
         if coins == 0 && items.is_empty() {
             continue;
         }
+        // Tally the drop for the post-battle summary (it's still looted by hand).
+        if let Some(results) = results.as_deref_mut() {
+            let stacks: Vec<LootItem> = items
+                .iter()
+                .map(|&id| LootItem { id, quantity: 1 })
+                .collect();
+            results.add_loot(coins, &stacks);
+        }
         // A low, dark "fallen body" marker on the ground (z = 0).
         let pos = Vec3::new(transform.translation.x, transform.translation.y, 0.0);
         commands.spawn((
@@ -156,7 +168,7 @@ fn enemy_loot_drop_system(
 
 /// Loot the nearest body when the leader is beside one and presses **Y**:
 /// transfer its coins + items, then despawn the body.
-fn ground_loot_pickup_system(
+pub(crate) fn ground_loot_pickup_system(
     mut commands: Commands,
     input: Res<ButtonInput<KeyCode>>,
     game_state: Res<crate::core::GameState>,
@@ -164,6 +176,7 @@ fn ground_loot_pickup_system(
     corpse_q: Query<(Entity, &Transform, &EnemyCorpse)>,
     mut wallet: ResMut<PlayerWallet>,
     mut inventory: ResMut<PlayerInventory>,
+    ticker: Option<ResMut<WorldTicker>>,
 ) {
     if game_state.0 != crate::core::Game_State::Exploring || !input.just_pressed(KeyCode::KeyY) {
         return;
@@ -186,6 +199,13 @@ fn ground_loot_pickup_system(
         inventory_add(&mut inventory, *id);
     }
     info!("looted body: {} mon + {} item(s)", loot.coins, loot.items.len());
+    if let Some(mut ticker) = ticker {
+        ticker.push(match loot.items.len() {
+            0 => format!("Looted {} mon", loot.coins),
+            1 => format!("Looted {} mon and 1 item", loot.coins),
+            n => format!("Looted {} mon and {n} items", loot.coins),
+        });
+    }
     commands.entity(entity).despawn();
 }
 
@@ -1,6 +1,7 @@
 //! Seirei Kuni library entry point.
 //!
-//! `run_full_game()` boots the complete game (used by `src/main.rs`).
+//! `run_full_game()` boots the complete game (used by `src/main.rs`);
+//! `headless_full_game_app()` builds the same app without a window or GPU.
 //! `run_slice_demo()` boots the curated vertical-slice demo (used by
 //! `src/bin/slice.rs`). Other binaries (`editor_server`, `dialogue_editor`,
 //! `ability_editor`) are independent and don't depend on this library
@@ -27,9 +28,11 @@ pub mod combat_ability;
 pub mod combat_hud;
 pub mod combat_overlay;
 pub mod combat_plugin;
+pub mod combat_tutorial;
 pub mod constants;
 pub mod contract;
 pub mod core;
+pub mod crafting;
 pub mod creatures;
 pub mod debug_console;
 pub mod dialogue;
@@ -62,10 +65,13 @@ pub mod skill_screen;
 pub mod skill_tree;
 pub mod status_effects;
 pub mod story_flags;
+#[cfg(test)]
+pub(crate) mod test_support;
 pub mod tuning;
 pub mod ui_style;
 pub mod world;
 pub mod world_rules;
+pub mod world_ticker;
 
 use battle::{
     battle_trigger_system, combat_end_turn_input, end_battle_on_death, resolve_summon_system,
@@ -150,7 +156,40 @@ fn base_default_plugins(window_title: &str) -> impl PluginGroup {
 
 fn full_game_app() -> App {
     let mut app = App::new();
+    app.add_plugins(base_default_plugins("Seirei Kuni"));
+    add_full_game(&mut app);
+    app
+}
+
+/// The full game without a window or a GPU: `DefaultPlugins` with winit
+/// disabled and no wgpu backend, then every plugin, resource and system
+/// `run_full_game()` adds. For tests that need the whole crate wired together
+/// but never draw a frame.
+pub fn headless_full_game_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(
+        DefaultPlugins
+            .set(RenderPlugin {
+                render_creation: WgpuSettings {
+                    backends: None,
+                    ..default()
+                }
+                .into(),
+                ..default()
+            })
+            .set(WindowPlugin {
+                primary_window: None,
+                exit_condition: bevy::window::ExitCondition::DontExit,
+                ..default()
+            })
+            .disable::<bevy::winit::WinitPlugin>(),
+    );
+    add_full_game(&mut app);
+    app
+}
 
+/// Everything the game adds on top of Bevy's default plugins.
+fn add_full_game(app: &mut App) {
     // Named areas drive both the world-map travel UI and the terrain/location
     // ids stamped onto the single continuous tilemap. Build the catalog first
     // so the generated map can be stamped to match it before insertion.
@@ -161,8 +200,7 @@ fn full_game_app() -> App {
     // always wins; the player can neither walk nor fast-travel onto them.
     map::apply_impassable_border(&mut map_tiles);
 
-    app.add_plugins(base_default_plugins("Seirei Kuni"))
-        .add_plugins(bevy::pbr::MaterialPlugin::<render3d::ToonMaterial>::default())
+    app.add_plugins(bevy::pbr::MaterialPlugin::<render3d::ToonMaterial>::default())
         .add_plugins(bevy_mod_outline::OutlinePlugin)
         .add_plugins(bevy_mod_outline::AutoGenerateOutlineNormalsPlugin::default())
         .add_plugins(post_fx::PostFxPlugin)
@@ -200,14 +238,27 @@ fn full_game_app() -> App {
         .add_plugins(character_sheet::CharacterSheetPlugin)
         .add_plugins(equipment::EquipmentPlugin)
         .add_plugins(CombatHudPlugin)
+        .add_plugins(combat_tutorial::CombatTutorialPlugin)
         .add_plugins(CombatOverlayPlugin)
         .add_plugins(AiDecisionPlugin)
         .add_plugins(creatures::CreaturesPlugin)
         .add_plugins(activities::ActivitiesPlugin)
         .add_plugins(DebugConsolePlugin)
         .add_plugins(StoryFlagsPlugin)
+        // Field abilities write story flags, so they are scheduled beside the
+        // flag store rather than inside `CombatPlugin`.
+        .add_systems(
+            Update,
+            (
+                combat_ability::field_ability_hotkey_system,
+                combat_ability::use_field_ability_system,
+                combat_ability::sync_concealed_visibility,
+            )
+                .chain(),
+        )
         .add_plugins(DialoguePlugin)
         .add_plugins(WorldRulesPlugin)
+        .add_plugins(world_ticker::WorldTickerPlugin)
         .add_plugins(areas::AreasPlugin)
         .insert_resource(PlayerMapPosition(map::PLAYER_SPAWN_TILE))
         .insert_resource(ClearColor(Color::srgb(0.1, 0.1, 0.1)))
@@ -223,6 +274,7 @@ fn full_game_app() -> App {
         .insert_resource(Messages::<AwardXpEvent>::default())
         .insert_resource(Messages::<AttackIntentEvent>::default())
         .init_resource::<movement::TravelTimeAccumulator>()
+        .init_resource::<movement::Stamina>()
         .insert_resource(DamageQueue::default())
         .insert_resource(map_tiles)
         .insert_resource(area_catalog)
@@ -247,6 +299,10 @@ fn full_game_app() -> App {
         .insert_resource(Messages::<SaveRequest>::default())
         .insert_resource(AutoSaveSettings::default())
         .init_resource::<battle::PendingHuntBattle>()
+        .init_resource::<battle::BattleResults>()
+        .add_message::<battle::BattleEndEvent>()
+        .init_resource::<battle::ActiveArena>()
+        .init_resource::<battle::GridConfig>()
         .init_resource::<render3d::CameraRig>()
         .init_resource::<characters::SelectedParty>()
         .init_resource::<world::PartySpawned>()
@@ -293,8 +349,10 @@ fn full_game_app() -> App {
         )
         .add_systems(Update, resolve_summon_system)
         .add_systems(Update, tick_summon_lifetime_system)
+        .add_systems(Update, battle::dismiss_orphaned_minions_system)
         .add_systems(Update, battle::tick_obstacle_lifetime_system)
         .add_systems(Update, battle::obstacle_aura_tick_system)
+        .add_systems(Update, (battle::spawn_zone_system, battle::zone_tick_system).chain())
         .add_systems(
             Update,
             battle::obstacle_on_pass_system.run_if(in_game_state(Game_State::Battle)),
@@ -308,7 +366,15 @@ fn full_game_app() -> App {
             battle::ai_combat_movement_system.run_if(in_game_state(Game_State::Battle)),
         )
         .add_systems(Update, battle::bridge_player_death_to_world)
-        .add_systems(Update, follow_path_system)
+        .add_systems(Update, battle::cleanup_combat_effects_system)
+        .add_systems(Update, battle::clear_battle_arena_system)
+        .add_systems(
+            Update,
+            battle::record_battle_results_system
+                .before(end_battle_on_death)
+                .before(battle::bridge_player_death_to_world),
+        )
+        .add_systems(Update, (movement::sprint_system, follow_path_system).chain())
         .add_systems(Update, ally_follow_player_system.after(player_movement))
         .add_systems(Update, toggle_map_mode)
         .add_systems(Update, navigate_map_selection_keyboard)
@@ -329,7 +395,6 @@ fn full_game_app() -> App {
             Update,
             movement::accumulate_manual_travel_time.after(player_movement),
         );
-    app
 }
 
 pub fn graphics_setting_visual_occluder_fade(graphics: Res<settings::GraphicsSettings>) -> bool {
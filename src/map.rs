@@ -117,7 +117,7 @@ pub struct TerrainSlowEffectIndex {
 }
 
 /// Tracks the currently loaded area/location.
-#[derive(Resource, Default, Clone, Copy, Debug)]
+#[derive(Resource, Default, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct CurrentArea(pub u16);
 
 /// Tracks spawned background entities for nearby tiles.
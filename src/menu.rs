@@ -10,7 +10,9 @@ use crate::core::{GameState, Game_State, MainCamera};
 use crate::world::SetLeaderRequest;
 use crate::render3d::{iso_camera_offset, spawn_menu_stage_camera, PlaceholderVisual, CHAR_HEIGHT};
 use crate::save::{AutoSaveSettings, SaveAction, SaveRequest, SaveSlot};
-use crate::settings::{GraphicsSettings, GraphicsToggle, GRAPHICS_TOGGLES};
+use crate::settings::{
+    CombatSpeed, ConfirmActions, GraphicsSettings, GraphicsToggle, GRAPHICS_TOGGLES,
+};
 use crate::ui_style::{
     bottom_scrim, button_node, button_text, button_text_lg, button_visual, font_size, label_text,
     menu_scene_overlay, overlay_root, palette, panel, scene_glow, scene_vignette, spacing, top_scrim,
@@ -38,6 +40,8 @@ impl Plugin for MenuPlugin {
             .add_systems(Update, handle_menu_actions)
             .add_systems(Update, update_autosave_status_text)
             .add_systems(Update, update_graphics_toggle_text)
+            .add_systems(Update, update_combat_speed_text)
+            .add_systems(Update, update_confirm_actions_text)
             .add_systems(Update, update_load_slot_status);
     }
 }
@@ -252,12 +256,20 @@ enum MenuButtonAction {
     LoadSlot2,
     LoadSlot3,
     ToggleAutosave,
+    CycleCombatSpeed,
+    ToggleConfirmActions,
     ToggleGraphics(GraphicsToggle),
 }
 
 #[derive(Component)]
 struct AutosaveStatusText;
 
+#[derive(Component)]
+struct CombatSpeedText;
+
+#[derive(Component)]
+struct ConfirmActionsText;
+
 #[derive(Component)]
 struct GraphicsToggleText(GraphicsToggle);
 
@@ -534,6 +546,32 @@ fn spawn_settings_page(commands: &mut Commands, root: Entity, is_pause: bool) {
                 btn.spawn((button_text("Autosave: ..."), AutosaveStatusText));
             });
 
+            col.spawn((
+                label_text("Combat"),
+                Node {
+                    margin: UiRect::top(Val::Px(spacing::SM)),
+                    ..default()
+                },
+            ));
+            col.spawn((
+                Button::default(),
+                button_node(ROW_BTN),
+                button_visual(),
+                MenuButtonAction::CycleCombatSpeed,
+            ))
+            .with_children(|btn| {
+                btn.spawn((button_text("Combat speed: ..."), CombatSpeedText));
+            });
+            col.spawn((
+                Button::default(),
+                button_node(ROW_BTN),
+                button_visual(),
+                MenuButtonAction::ToggleConfirmActions,
+            ))
+            .with_children(|btn| {
+                btn.spawn((button_text("Confirm actions: ..."), ConfirmActionsText));
+            });
+
             col.spawn((
                 label_text("Performance"),
                 Node {
@@ -922,6 +960,8 @@ fn handle_menu_actions(
     mut resume_state: ResMut<ResumeState>,
     mut autosave: ResMut<AutoSaveSettings>,
     mut graphics: ResMut<GraphicsSettings>,
+    mut combat_speed: ResMut<CombatSpeed>,
+    mut confirm_actions: ResMut<ConfirmActions>,
     mut save_requests: ResMut<Messages<SaveRequest>>,
     mut main_page: ResMut<MainMenuPage>,
     mut pause_page: ResMut<PauseMenuPage>,
@@ -1031,6 +1071,12 @@ fn handle_menu_actions(
                 autosave.enabled = !autosave.enabled;
                 autosave.timer.reset();
             }
+            MenuButtonAction::CycleCombatSpeed => {
+                *combat_speed = combat_speed.next_step();
+            }
+            MenuButtonAction::ToggleConfirmActions => {
+                confirm_actions.0 = !confirm_actions.0;
+            }
             MenuButtonAction::ToggleGraphics(toggle) => {
                 graphics.toggle(*toggle);
             }
@@ -1050,6 +1096,30 @@ fn update_graphics_toggle_text(
     }
 }
 
+fn update_combat_speed_text(
+    combat_speed: Res<CombatSpeed>,
+    mut labels: Query<&mut Text, With<CombatSpeedText>>,
+) {
+    let label = combat_speed.label();
+    for mut text in &mut labels {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+}
+
+fn update_confirm_actions_text(
+    confirm_actions: Res<ConfirmActions>,
+    mut labels: Query<&mut Text, With<ConfirmActionsText>>,
+) {
+    let label = confirm_actions.label();
+    for mut text in &mut labels {
+        if text.0 != label {
+            text.0 = label.to_string();
+        }
+    }
+}
+
 fn update_autosave_status_text(
     autosave: Res<AutoSaveSettings>,
     mut labels: Query<&mut Text, With<AutosaveStatusText>>,
@@ -38,6 +38,81 @@ pub struct TravelTimeAccumulator {
     pub last_tile: Option<IVec2>,
 }
 
+/// Sprinting walks the exploration path this much faster.
+pub const SPRINT_SPEED_MULTIPLIER: f32 = 1.75;
+/// Stamina spent per second of sprinting.
+pub const SPRINT_STAMINA_DRAIN: f32 = 20.0;
+/// Stamina recovered per second while not sprinting.
+pub const STAMINA_REGEN: f32 = 10.0;
+
+/// The party's exploration stamina. Holding Shift while walking a path spends
+/// it to sprint; at zero the party drops back to a walk until Shift is
+/// released. Party-wide rather than on the `Player` entity, so swapping the
+/// leader doesn't hand over a fresh pool.
+#[derive(Resource, Debug, Clone)]
+pub struct Stamina {
+    pub current: f32,
+    pub max: f32,
+    /// Set by [`sprint_system`]; read by [`follow_path_system`].
+    pub sprinting: bool,
+    /// Ran dry during this press of Shift.
+    pub exhausted: bool,
+}
+
+impl Default for Stamina {
+    fn default() -> Self {
+        Self { current: 100.0, max: 100.0, sprinting: false, exhausted: false }
+    }
+}
+
+impl Stamina {
+    /// Multiplier on the exploration walk speed for this frame.
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.sprinting {
+            SPRINT_SPEED_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Spend stamina while Shift is held and the player is walking a path, and
+/// recover it otherwise. Running dry ends the sprint until Shift is released,
+/// so holding it at zero doesn't stutter between a walk and a one-frame sprint
+/// as stamina trickles back.
+pub fn sprint_system(
+    input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut stamina: ResMut<Stamina>,
+    player_q: Query<(), (With<Player>, With<MoveAlongPath>)>,
+) {
+    let held = input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let walking = game_state.0 == Game_State::Exploring && !player_q.is_empty();
+    if !held {
+        stamina.exhausted = false;
+    }
+    stamina.sprinting = held && walking && !stamina.exhausted && stamina.current > 0.0;
+
+    let dt = time.delta_secs();
+    if stamina.sprinting {
+        stamina.current = (stamina.current - SPRINT_STAMINA_DRAIN * dt).max(0.0);
+        if stamina.current <= 0.0 {
+            stamina.exhausted = true;
+        }
+    } else {
+        stamina.current = (stamina.current + STAMINA_REGEN * dt).min(stamina.max);
+    }
+}
+
+/// Stop the player's click-to-move walk, if any. Call this whenever something
+/// takes control away from exploring — an encounter starting, a dialogue or
+/// cutscene opening, an enemy spotting the party — so the player doesn't keep
+/// marching along a stale route (or resume it once control comes back).
+pub fn cancel_player_path(commands: &mut Commands, player: Entity) {
+    commands.entity(player).remove::<MoveAlongPath>();
+}
+
 pub fn fade_out_system(
     mut commands: Commands,
     time: Res<Time>,
@@ -59,14 +134,17 @@ pub fn animate_sprite(
     for (indices, mut timer, mut sprite) in &mut query {
         timer.tick(time.delta());
 
-        if timer.just_finished() {
-            if let Some(atlas) = &mut sprite.texture_atlas {
-                atlas.index = if atlas.index == indices.last {
-                    indices.first
-                } else {
-                    atlas.index + 1
-                };
-            }
+        // A long frame can span several animation frames; step through all of
+        // them so playback speed doesn't depend on the frame rate.
+        let Some(atlas) = &mut sprite.texture_atlas else {
+            continue;
+        };
+        for _ in 0..timer.times_finished_this_tick() {
+            atlas.index = if atlas.index == indices.last {
+                indices.first
+            } else {
+                atlas.index + 1
+            };
         }
     }
 }
@@ -325,22 +403,32 @@ pub fn player_movement(
 
 pub fn follow_path_system(
     mut commands: Commands,
-    mut query: Query<(&mut Transform, &mut MoveAlongPath, Entity), Without<MainCamera>>,
+    mut query: Query<
+        (&mut Transform, &mut MoveAlongPath, Entity, Has<Player>),
+        Without<MainCamera>,
+    >,
     time: Res<Time>,
     mut global_variables: ResMut<Global_Variables>,
     game_state: Res<GameState>,
+    stamina: Option<Res<Stamina>>,
 ) {
     if !(matches!(game_state.0, Game_State::Exploring)) {
         return;
     }
 
     global_variables.0.moving = true;
-    for (mut transform, mut movement, entity) in query.iter_mut() {
-        if movement
+    for (mut transform, mut movement, entity, is_player) in query.iter_mut() {
+        let speed = match (&stamina, is_player) {
+            (Some(stamina), true) => stamina.speed_multiplier(),
+            _ => 1.0,
+        };
+        // Take every step that fell due this frame, so a slow frame doesn't
+        // slow the walk down.
+        let steps = movement
             .timer
-            .tick(time.delta() * PATH_MOVEMENT_SPEED)
-            .just_finished()
-        {
+            .tick(time.delta().mul_f32(speed) * PATH_MOVEMENT_SPEED)
+            .times_finished_this_tick();
+        for _ in 0..steps {
             if movement.current_index < movement.path.len() {
                 let next_tile = movement.path[movement.current_index];
                 let target = Vec3::new(
@@ -360,6 +448,7 @@ pub fn follow_path_system(
                 movement.current_index += 1;
             } else {
                 commands.entity(entity).remove::<MoveAlongPath>();
+                break;
             }
         }
     }
@@ -693,3 +782,99 @@ pub fn ally_follow_player_system(
         ally_tf.translation.y += move_vec.y;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::test_support::{advance, app_with_manual_time};
+
+    #[test]
+    fn fade_out_timer_despawns_exactly_when_it_elapses() {
+        let mut app = app_with_manual_time();
+        app.add_systems(Update, fade_out_system);
+        let marker = app
+            .world_mut()
+            .spawn(FadeOutTimer(Timer::from_seconds(1.0, TimerMode::Once)))
+            .id();
+
+        advance(&mut app, Duration::from_millis(900));
+        assert!(app.world().get_entity(marker).is_ok(), "still fading at 0.9s");
+        advance(&mut app, Duration::from_millis(100));
+        assert!(app.world().get_entity(marker).is_err(), "gone at 1.0s");
+    }
+
+    #[test]
+    fn one_long_frame_walks_as_far_as_many_short_ones() {
+        let walk = |frames: u32| {
+            let mut app = app_with_manual_time();
+            app.init_resource::<GameState>()
+                .init_resource::<Global_Variables>()
+                .add_systems(Update, follow_path_system);
+            let walker = app
+                .world_mut()
+                .spawn((
+                    Transform::default(),
+                    MoveAlongPath {
+                        path: (0..10).map(|x| IVec2::new(x * 16, 0)).collect(),
+                        current_index: 1,
+                        timer: Timer::from_seconds(1.0, TimerMode::Repeating),
+                    },
+                ))
+                .id();
+            // 3 steps' worth of time at PATH_MOVEMENT_SPEED, however it's sliced.
+            let total = Duration::from_secs(3) / PATH_MOVEMENT_SPEED;
+            for _ in 0..frames {
+                advance(&mut app, total / frames);
+            }
+            app.world().get::<Transform>(walker).unwrap().translation.x
+        };
+        assert_eq!(walk(1), 48.0);
+        assert_eq!(walk(6), 48.0);
+    }
+
+    #[test]
+    fn sprinting_drains_stamina_then_drops_back_to_a_walk() {
+        let mut app = app_with_manual_time();
+        app.insert_resource(GameState(Game_State::Exploring))
+            .init_resource::<Global_Variables>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .insert_resource(Stamina { current: 30.0, ..default() })
+            .add_systems(Update, (sprint_system, follow_path_system).chain());
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::default(),
+                MoveAlongPath {
+                    path: (0..200).map(|x| IVec2::new(x * 16, 0)).collect(),
+                    current_index: 1,
+                    timer: Timer::from_seconds(1.0, TimerMode::Repeating),
+                },
+            ))
+            .id();
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::ShiftLeft);
+
+        let steps_in_a_second = |app: &mut App| {
+            let before = app.world().get::<MoveAlongPath>(player).unwrap().current_index;
+            advance(app, Duration::from_secs(1));
+            app.world().get::<MoveAlongPath>(player).unwrap().current_index - before
+        };
+        let walk = PATH_MOVEMENT_SPEED as usize;
+        let sprint = (PATH_MOVEMENT_SPEED as f32 * SPRINT_SPEED_MULTIPLIER) as usize;
+
+        assert_eq!(steps_in_a_second(&mut app), sprint);
+        assert_eq!(app.world().resource::<Stamina>().current, 30.0 - SPRINT_STAMINA_DRAIN);
+
+        // Runs dry partway through this second; the frame still sprints.
+        assert_eq!(steps_in_a_second(&mut app), sprint);
+        assert_eq!(app.world().resource::<Stamina>().current, 0.0);
+
+        // Shift still held, but out of breath: walk pace, and stamina recovers.
+        assert_eq!(steps_in_a_second(&mut app), walk);
+        let stamina = app.world().resource::<Stamina>();
+        assert!(!stamina.sprinting);
+        assert_eq!(stamina.current, STAMINA_REGEN);
+    }
+}
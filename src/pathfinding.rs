@@ -5,7 +5,7 @@ use bevy::prelude::*;
 
 use crate::constants::{GRID_HEIGHT, GRID_WIDTH, WALKING_LIMIT};
 use crate::core::Position;
-use crate::quadtree::{aabb_collision, Collider, QuadTree};
+use crate::quadtree::{aabb_collision, segment_intersects_rect, Collider, QuadTree};
 
 const PATH_DIRECTIONS: [(i32, i32); 8] = [
     (1, -1),
@@ -160,17 +160,23 @@ fn distance(a: Position, b: Position) -> i32 {
     diagonal * 14 + straight * 10
 }
 
+/// The 32×32 square a unit standing on `pos` covers, or `None` off the grid.
+fn unit_footprint(pos: Position) -> Option<Rect> {
+    if pos.x.abs() as u32 > GRID_WIDTH || pos.y.abs() as u32 > GRID_HEIGHT {
+        return None;
+    }
+    let pos_center = Vec2::new(pos.x as f32, pos.y as f32);
+    Some(Rect::from_center_size(pos_center, Vec2::new(32.0, 32.0)))
+}
+
 fn walkable_query<'a>(
     pos: Position,
     quad_tree: &'a QuadTree,
     possible_colliders: &mut Vec<&'a Collider>,
 ) -> bool {
-    if pos.x.abs() as u32 > GRID_WIDTH || pos.y.abs() as u32 > GRID_HEIGHT {
+    let Some(player_rect) = unit_footprint(pos) else {
         return false;
-    }
-
-    let pos_center = Vec2::new(pos.x as f32, pos.y as f32);
-    let player_rect = Rect::from_center_size(pos_center, Vec2::new(32.0, 32.0));
+    };
 
     possible_colliders.clear();
     quad_tree.0.query(player_rect, possible_colliders);
@@ -180,9 +186,10 @@ fn walkable_query<'a>(
         .any(|collider| aabb_collision(player_rect, collider.bounds))
 }
 
+/// Whether a unit may step onto `pos`: in bounds, with no collider under its
+/// 32×32 footprint.
 pub fn is_walkable_move(pos: Position, quad_tree: &QuadTree) -> bool {
-    let mut possible_colliders = Vec::with_capacity(16);
-    walkable_query(pos, quad_tree, &mut possible_colliders)
+    unit_footprint(pos).is_some_and(|footprint| quad_tree.entries_in(footprint).is_empty())
 }
 
 pub fn is_walkable_path(pos: Position, quad_tree: &QuadTree) -> bool {
@@ -190,6 +197,14 @@ pub fn is_walkable_path(pos: Position, quad_tree: &QuadTree) -> bool {
     walkable_query(pos, quad_tree, &mut possible_colliders)
 }
 
+/// True when no collider blocks the straight line between `from` and `to`.
+pub fn line_of_sight(quad_tree: &QuadTree, from: Vec2, to: Vec2) -> bool {
+    !quad_tree
+        .entries_in(Rect::from_corners(from, to))
+        .iter()
+        .any(|(_, collider)| segment_intersects_rect(from, to, collider.bounds))
+}
+
 pub fn pathfinding(
     quad_tree: &QuadTree,
     start: Position,
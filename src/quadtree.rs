@@ -16,10 +16,65 @@ pub struct CachedColliders(pub Vec<(Transform, Collider)>);
 pub struct QuadtreeNode {
     pub bounds: Rect,
     pub level: usize,
-    pub objects: Vec<Collider>,
+    /// Colliders stored at this level, with the entity each came from (`None`
+    /// for a bare collider inserted by hand).
+    pub objects: Vec<(Option<Entity>, Collider)>,
     pub children: Option<[Box<QuadtreeNode>; 4]>,
 }
 
+impl QuadTree {
+    /// A tree whose root encloses every collider in `items` with room to
+    /// spare. The world is centred on the tile origin (~2048), far from (0,0),
+    /// so a fixed origin-centred root would miss every collider; with nothing
+    /// to enclose it falls back to one anyway.
+    pub fn enclosing(items: Vec<(Entity, Collider)>) -> Self {
+        let mut min = Vec2::splat(f32::MAX);
+        let mut max = Vec2::splat(f32::MIN);
+        for (_, collider) in &items {
+            min = min.min(collider.bounds.min);
+            max = max.max(collider.bounds.max);
+        }
+        let root = if min.x <= max.x {
+            Rect::from_corners(min - Vec2::splat(512.0), max + Vec2::splat(512.0))
+        } else {
+            Rect::from_center_size(Vec2::ZERO, Vec2::splat(2048.0))
+        };
+        let mut node = QuadtreeNode::new(root, 0);
+        for (entity, collider) in items {
+            node.insert_for(entity, collider);
+        }
+        QuadTree(node)
+    }
+
+    /// Every stored collider (with its entity, if it has one) that comes
+    /// within `radius` of `point`: the tree is narrowed to the square around
+    /// the circle, then each candidate is checked against the circle itself.
+    pub fn entries_near(&self, point: Vec2, radius: f32) -> Vec<&(Option<Entity>, Collider)> {
+        let mut found = self.entries_in(Rect::from_center_half_size(point, Vec2::splat(radius)));
+        found.retain(|(_, collider)| rect_distance(collider.bounds, point) <= radius);
+        found
+    }
+
+    /// Every stored collider (with its entity, if it has one) that overlaps
+    /// `area`.
+    pub fn entries_in(&self, area: Rect) -> Vec<&(Option<Entity>, Collider)> {
+        let mut found = Vec::new();
+        self.0.query_entries(area, &mut found);
+        found
+    }
+
+    /// Entities whose collider comes within `radius` of `point`. Colliders
+    /// inserted without an entity are skipped.
+    pub fn colliders_near(&self, point: Vec2, radius: f32) -> Vec<Entity> {
+        self.entries_near(point, radius).into_iter().filter_map(|(entity, _)| *entity).collect()
+    }
+}
+
+/// Distance from `point` to the nearest point of `rect` (0 inside it).
+pub fn rect_distance(rect: Rect, point: Vec2) -> f32 {
+    point.clamp(rect.min, rect.max).distance(point)
+}
+
 pub fn aabb_collision(rect1: Rect, rect2: Rect) -> bool {
     rect1.min.x < rect2.max.x
         && rect1.max.x > rect2.min.x
@@ -27,6 +82,34 @@ pub fn aabb_collision(rect1: Rect, rect2: Rect) -> bool {
         && rect1.max.y > rect2.min.y
 }
 
+/// Whether the segment `a`→`b` touches `rect` (slab test).
+pub fn segment_intersects_rect(a: Vec2, b: Vec2, rect: Rect) -> bool {
+    let d = b - a;
+    let mut t_min = 0.0_f32;
+    let mut t_max = 1.0_f32;
+    for (start, delta, lo, hi) in [
+        (a.x, d.x, rect.min.x, rect.max.x),
+        (a.y, d.y, rect.min.y, rect.max.y),
+    ] {
+        if delta.abs() <= f32::EPSILON {
+            if start < lo || start > hi {
+                return false;
+            }
+            continue;
+        }
+        let (mut t0, mut t1) = ((lo - start) / delta, (hi - start) / delta);
+        if t0 > t1 {
+            std::mem::swap(&mut t0, &mut t1);
+        }
+        t_min = t_min.max(t0);
+        t_max = t_max.min(t1);
+        if t_min > t_max {
+            return false;
+        }
+    }
+    true
+}
+
 impl QuadtreeNode {
     pub fn new(bounds: Rect, level: usize) -> Self {
         Self {
@@ -61,34 +144,40 @@ impl QuadtreeNode {
     }
 
     pub fn insert(&mut self, collider: Collider) {
+        self.insert_entry(None, collider);
+    }
+
+    /// Insert `entity`'s collider, so [`QuadTree::colliders_near`] can report
+    /// it.
+    pub fn insert_for(&mut self, entity: Entity, collider: Collider) {
+        self.insert_entry(Some(entity), collider);
+    }
+
+    fn insert_entry(&mut self, entity: Option<Entity>, collider: Collider) {
         if !aabb_collision(self.bounds, collider.bounds) {
             return;
         }
 
-        if self.children.is_some() {
-            if let Some(children) = &mut self.children {
-                for child in children.iter_mut() {
-                    if child.bounds.contains(collider.bounds.center()) {
-                        child.insert(collider);
-                        return;
-                    }
+        // A collider only moves down into a child that holds all of it; one
+        // straddling a split stays here, so a query that skips a child whose
+        // bounds it misses can't miss the collider too.
+        if let Some(children) = &mut self.children {
+            for child in children.iter_mut() {
+                if child.bounds.contains(collider.bounds.min)
+                    && child.bounds.contains(collider.bounds.max)
+                {
+                    child.insert_entry(entity, collider);
+                    return;
                 }
             }
         }
 
-        self.objects.push(collider);
-
-        if self.objects.len() > MAX_OBJECTS && self.level < MAX_LEVELS {
-            if self.children.is_none() {
-                self.subdivide();
-            }
+        self.objects.push((entity, collider));
 
-            if let Some(_children) = &mut self.children {
-                let mut reinsert = Vec::new();
-                std::mem::swap(&mut self.objects, &mut reinsert);
-                for obj in reinsert {
-                    self.insert(obj);
-                }
+        if self.objects.len() > MAX_OBJECTS && self.level < MAX_LEVELS && self.children.is_none() {
+            self.subdivide();
+            for (entity, obj) in std::mem::take(&mut self.objects) {
+                self.insert_entry(entity, obj);
             }
         }
     }
@@ -98,7 +187,7 @@ impl QuadtreeNode {
             return;
         }
 
-        for collider in &self.objects {
+        for (_, collider) in &self.objects {
             if aabb_collision(collider.bounds, area) {
                 found.push(collider);
             }
@@ -110,6 +199,29 @@ impl QuadtreeNode {
             }
         }
     }
+
+    /// Like [`Self::query`], keeping each collider's entity.
+    pub fn query_entries<'a>(
+        &'a self,
+        area: Rect,
+        found: &mut Vec<&'a (Option<Entity>, Collider)>,
+    ) {
+        if !aabb_collision(self.bounds, area) {
+            return;
+        }
+
+        for entry in &self.objects {
+            if aabb_collision(entry.1.bounds, area) {
+                found.push(entry);
+            }
+        }
+
+        if let Some(children) = &self.children {
+            for child in children {
+                child.query_entries(area, found);
+            }
+        }
+    }
 }
 
 impl Default for QuadtreeNode {
@@ -122,3 +234,59 @@ impl Default for QuadtreeNode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colliders_near_returns_only_what_is_in_range() {
+        let mut world = World::new();
+        let mut tree = QuadtreeNode::new(Rect::new(0.0, 0.0, 4096.0, 4096.0), 0);
+        // A 32×32 crate every 128 units across the whole area: 1024 colliders.
+        let mut all = Vec::new();
+        for gx in 0..32 {
+            for gy in 0..32 {
+                let center = Vec2::new(64.0 + gx as f32 * 128.0, 64.0 + gy as f32 * 128.0);
+                let entity = world.spawn_empty().id();
+                let bounds = Rect::from_center_size(center, Vec2::splat(32.0));
+                tree.insert_for(entity, Collider { bounds });
+                all.push((entity, center));
+            }
+        }
+        let tree = QuadTree(tree);
+
+        let point = Vec2::new(1000.0, 1000.0);
+        let radius = 200.0;
+        let mut near = tree.colliders_near(point, radius);
+        near.sort();
+        let mut expected: Vec<Entity> = all
+            .iter()
+            .filter(|(_, c)| {
+                rect_distance(Rect::from_center_size(*c, Vec2::splat(32.0)), point) <= radius
+            })
+            .map(|(e, _)| *e)
+            .collect();
+        expected.sort();
+
+        assert!(!expected.is_empty());
+        assert!(expected.len() < 20, "the fixture should leave most colliders out of range");
+        assert_eq!(near, expected);
+    }
+
+    #[test]
+    fn a_collider_straddling_a_split_is_still_found() {
+        let mut world = World::new();
+        let mut tree = QuadtreeNode::new(Rect::new(0.0, 0.0, 1024.0, 1024.0), 0);
+        for i in 0..(MAX_OBJECTS as i32 + 4) {
+            let center = Vec2::new(100.0 + i as f32 * 10.0, 100.0);
+            let bounds = Rect::from_center_size(center, Vec2::splat(4.0));
+            tree.insert_for(world.spawn_empty().id(), Collider { bounds });
+        }
+        // Spans the vertical split at x = 512.
+        let wall = world.spawn_empty().id();
+        tree.insert_for(wall, Collider { bounds: Rect::new(400.0, 700.0, 600.0, 720.0) });
+
+        assert_eq!(QuadTree(tree).colliders_near(Vec2::new(590.0, 690.0), 15.0), vec![wall]);
+    }
+}
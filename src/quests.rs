@@ -635,11 +635,11 @@ fn ingest_update_events(
 }
 
 fn trigger_on_item_pickup(
-    mut pickup_events: ResMut<Messages<ItemPickupEvent>>,
+    mut pickup_events: MessageReader<ItemPickupEvent>,
     hooks: Query<&OnItemPickup>,
     mut updates: ResMut<Messages<AdvanceObjectiveEvent>>,
 ) {
-    for event in pickup_events.drain() {
+    for event in pickup_events.read() {
         if let Ok(OnItemPickup(Some(action))) = hooks.get(event.entity) {
             updates.write(AdvanceObjectiveEvent {
                 quest_id: action.quest_id,
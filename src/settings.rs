@@ -122,14 +122,39 @@ impl GraphicsSettings {
         }
     }
 
+    fn load_from_disk() -> Option<Self> {
+        SettingsFile::load_from_disk().map(|file| file.graphics)
+    }
+}
+
+/// Everything `saves/settings.ron` holds. Files written before combat speed
+/// was saved are a bare [`GraphicsSettings`]; those still load, at 1× speed
+/// and without the confirm step.
+#[derive(Serialize, Deserialize)]
+struct SettingsFile {
+    graphics: GraphicsSettings,
+    #[serde(default)]
+    combat_speed: CombatSpeed,
+    #[serde(default)]
+    confirm_actions: ConfirmActions,
+}
+
+impl SettingsFile {
     fn load_from_disk() -> Option<Self> {
         let contents = fs::read_to_string(SETTINGS_PATH).ok()?;
-        match ron::de::from_str::<GraphicsSettings>(&contents) {
-            Ok(settings) => Some(settings),
-            Err(err) => {
-                warn!("Failed to parse {}: {err}", SETTINGS_PATH);
-                None
-            }
+        match ron::de::from_str::<SettingsFile>(&contents) {
+            Ok(file) => Some(file),
+            Err(err) => match ron::de::from_str::<GraphicsSettings>(&contents) {
+                Ok(graphics) => Some(Self {
+                    graphics,
+                    combat_speed: CombatSpeed::default(),
+                    confirm_actions: ConfirmActions::default(),
+                }),
+                Err(_) => {
+                    warn!("Failed to parse {}: {err}", SETTINGS_PATH);
+                    None
+                }
+            },
         }
     }
 
@@ -267,20 +292,87 @@ pub const GRAPHICS_TOGGLES: [GraphicsToggle; 10] = [
     GraphicsToggle::LogOccluderMotion,
 ];
 
+/// Presentation pace for battles: the pause between turns, floating damage
+/// numbers and hit VFX all run this many times faster. Combat logic and the
+/// RNG never read it, so a sped-up fight resolves exactly like a slow one.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CombatSpeed(pub f32);
+
+impl Default for CombatSpeed {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl CombatSpeed {
+    pub const MIN: f32 = 0.25;
+    pub const MAX: f32 = 4.0;
+
+    /// The speeds the settings menu cycles through.
+    pub const STEPS: [f32; 4] = [0.5, 1.0, 2.0, 4.0];
+
+    /// `dt` on the combat presentation clock.
+    pub fn scale(self, dt: f32) -> f32 {
+        dt * self.0.clamp(Self::MIN, Self::MAX)
+    }
+
+    /// The next menu step above this speed, wrapping back to the slowest.
+    pub fn next_step(self) -> Self {
+        let next = Self::STEPS.iter().copied().find(|&step| step > self.0);
+        Self(next.unwrap_or(Self::STEPS[0]))
+    }
+
+    pub fn label(self) -> String {
+        format!("Combat speed: {}x", self.0)
+    }
+}
+
+/// Whether a battle turn asks for confirmation: the chosen action is staged
+/// (see [`crate::combat_plugin::ActionStaging`]) until confirmed or undone,
+/// rather than resolving the moment it's picked. Off by default.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConfirmActions(pub bool);
+
+impl ConfirmActions {
+    pub fn label(self) -> &'static str {
+        if self.0 {
+            "Confirm actions: On"
+        } else {
+            "Confirm actions: Off"
+        }
+    }
+}
+
 pub struct SettingsPlugin;
 
 impl Plugin for SettingsPlugin {
     fn build(&self, app: &mut App) {
+        // `CombatSpeed::default()` stays a plain 1× (systems fall back to it
+        // when the resource is absent), so the saved pace is loaded here.
+        let (combat_speed, confirm_actions) = SettingsFile::load_from_disk()
+            .map(|file| (file.combat_speed, file.confirm_actions))
+            .unwrap_or_default();
         app.init_resource::<GraphicsSettings>()
-            .add_systems(Update, persist_graphics_settings);
+            .insert_resource(combat_speed)
+            .insert_resource(confirm_actions)
+            .add_systems(Update, persist_settings);
     }
 }
 
-/// Persist settings whenever the resource changes. `is_changed()` is true on
-/// the frame after `init_resource`, so the first persist happens on startup
-/// (which writes the on-disk default if the file did not exist yet).
-fn persist_graphics_settings(graphics: Res<GraphicsSettings>) {
-    if graphics.is_changed() {
-        graphics.save_to_disk();
+/// Persist settings whenever any of the resources changes. `is_changed()` is
+/// true on the frame after they're inserted, so the first persist happens on
+/// startup (which writes the on-disk default if the file did not exist yet).
+fn persist_settings(
+    graphics: Res<GraphicsSettings>,
+    combat_speed: Res<CombatSpeed>,
+    confirm_actions: Res<ConfirmActions>,
+) {
+    if graphics.is_changed() || combat_speed.is_changed() || confirm_actions.is_changed() {
+        SettingsFile {
+            graphics: *graphics,
+            combat_speed: *combat_speed,
+            confirm_actions: *confirm_actions,
+        }
+        .save_to_disk();
     }
 }
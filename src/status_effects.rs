@@ -125,6 +125,24 @@ pub enum StatusKind {
     Contract(ContractDebuffKind),
 }
 
+impl StatusKind {
+    /// Icon the HUD status bar draws for this kind, as an asset-relative id
+    /// (`icons/status/<category>/<Variant>`).
+    pub fn icon_id(self) -> String {
+        match self {
+            StatusKind::BadCondition(k) => format!("icons/status/condition/{k:?}"),
+            StatusKind::Debuff(k) => format!("icons/status/debuff/{k:?}"),
+            StatusKind::Buff(k) => format!("icons/status/buff/{k:?}"),
+            StatusKind::Contract(k) => format!("icons/status/contract/{k:?}"),
+        }
+    }
+
+    /// Whether the bearer wants to keep this (buffs) rather than shed it.
+    pub fn is_beneficial(self) -> bool {
+        matches!(self, StatusKind::Buff(_))
+    }
+}
+
 /// The signature status an on-wheel hit applies, by its phase + polarity
 /// (§7 of `docs/gogyo_elemental_system.md`). Yō phases lean *offensive*, In
 /// phases lean *control / drain*.
@@ -230,6 +248,21 @@ impl StatusEffects {
         self.0.retain(|s| s.kind != kind);
     }
 
+    /// Drop everything scoped to the fight that just ended. Debuffs and
+    /// contract penalties that only cleansing / atonement lifts stay, as do
+    /// the buffs and injuries whose durations are measured in world hours
+    /// rather than turns (see [`runs_in_world_hours`]), unless they were
+    /// applied to last only until the end of combat.
+    pub fn clear_combat_scoped(&mut self) {
+        self.0.retain(|s| match s.kind {
+            StatusKind::Debuff(_) | StatusKind::Contract(_) => {
+                matches!(s.expiry, Expiry::UntilCleansed | Expiry::UntilAtonement)
+            }
+            kind if runs_in_world_hours(kind) => !matches!(s.expiry, Expiry::EndOfCombat),
+            _ => false,
+        });
+    }
+
     pub fn has(&self, kind: StatusKind) -> bool {
         self.0.iter().any(|s| s.kind == kind)
     }
@@ -253,6 +286,24 @@ impl StatusEffects {
 // Default duration table (GDD Part 1 + Part 2)
 // ---------------------------------------------------------------------------
 
+/// Whether `kind`'s default duration (see [`default_expiry`]) runs in world
+/// hours rather than combat turns: the lingering injuries and the blessing
+/// buffs. These outlive the battle they were gained in.
+pub fn runs_in_world_hours(kind: StatusKind) -> bool {
+    use BadConditionKind::*;
+    matches!(
+        kind,
+        StatusKind::BadCondition(ShatteredResolve | Crippled | Broken)
+            | StatusKind::Buff(
+                BuffKind::BolsteredMorale
+                    | BuffKind::OverflowingVessel
+                    | BuffKind::UnbreakableSpirit
+                    | BuffKind::SacredReserve
+                    | BuffKind::PropheticCalm
+            )
+    )
+}
+
 /// Returns the GDD-specified expiry for a freshly applied effect at `tier`,
 /// using `now` as the current Timestamp for hour-based effects.
 pub fn default_expiry(kind: StatusKind, _tier: Tier, now: u32) -> Expiry {
@@ -446,7 +497,8 @@ fn bad_condition_duration_multiplier(existing: Option<&StatusEffects>) -> f32 {
 /// Per-character DoT (Bleeding) ticks. Fires on the affected entity's
 /// `TurnEndEvent` so DoT damage lands on *their* turn (their action triggers
 /// the cadence). Duration / expiry is timestamp-based and handled by
-/// `status_expiry_tick_system`, not here.
+/// `status_expiry_tick_system`, not here. The tick carries no attacker: a
+/// bleed-out is nobody's kill, so it never credits XP.
 pub fn status_turn_end_tick_system(
     mut reader: MessageReader<TurnEndEvent>,
     mut status_q: Query<(&mut StatusEffects, &CombatStats)>,
@@ -470,7 +522,7 @@ pub fn status_turn_end_tick_system(
                     let dmg = ((stats.health.base as f32) * pct).round() as i32;
                     if dmg > 0 {
                         damage_writer.write(crate::combat_plugin::DamageEvent {
-                            attacker: s.source.unwrap_or(ev.who),
+                            attacker: None,
                             target: ev.who,
                             amount: dmg,
                             damage_type: DamageType::True,
@@ -485,6 +537,100 @@ pub fn status_turn_end_tick_system(
     }
 }
 
+/// Scripted confusion with an explicit per-turn retarget `chance`, for
+/// abilities/encounters that want finer control than the tiered
+/// `BadCondition(Confused)` table in [`action_gates`]. Counts down on the
+/// bearer's own `TurnEndEvent` and is removed at zero. The combat pipeline
+/// takes the larger of this and the tiered chance (see [`retarget_chance`]).
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Confused {
+    pub remaining_turns: u8,
+    /// Probability in 0.0..=1.0 that the bearer's chosen target is swapped for
+    /// a random other combatant.
+    pub chance: f32,
+}
+
+pub fn confused_turn_end_tick_system(
+    mut commands: Commands,
+    mut reader: MessageReader<TurnEndEvent>,
+    mut q: Query<&mut Confused>,
+) {
+    for ev in reader.read() {
+        let Ok(mut confused) = q.get_mut(ev.who) else {
+            continue;
+        };
+        confused.remaining_turns = confused.remaining_turns.saturating_sub(1);
+        if confused.remaining_turns == 0 {
+            commands.entity(ev.who).remove::<Confused>();
+        }
+    }
+}
+
+/// Temporary fast recovery granted by an ability or consumable (a cleric's
+/// blessing, a kampo draught). Restores `amount_per_turn` of `resource` at the
+/// start of each of the bearer's turns, on top of the permanent
+/// `*_per_rest_hour` rates, and is removed once `remaining_turns` runs out.
+/// Health gains respect [`heal_gate`] like any other heal. Reapplying replaces
+/// the existing buff rather than stacking a second one.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct RegenBuff {
+    pub amount_per_turn: u32,
+    pub remaining_turns: u8,
+    pub resource: ResourceKind,
+}
+
+#[derive(Debug, Clone, Message)]
+pub struct ApplyRegenBuffEvent {
+    pub target: Entity,
+    pub buff: RegenBuff,
+    pub source: Option<Entity>,
+}
+
+pub fn apply_regen_buff_system(
+    mut commands: Commands,
+    mut reader: MessageReader<ApplyRegenBuffEvent>,
+) {
+    for ev in reader.read() {
+        if ev.buff.amount_per_turn == 0 || ev.buff.remaining_turns == 0 {
+            continue;
+        }
+        commands.entity(ev.target).insert(ev.buff);
+    }
+}
+
+/// Tick every [`RegenBuff`] on its bearer's `TurnStartEvent`. Magic regen tops
+/// up each school pool by the full amount, matching how rest treats the four
+/// schools as separate reservoirs.
+pub fn regen_buff_turn_start_system(
+    mut commands: Commands,
+    mut reader: MessageReader<crate::combat_plugin::TurnStartEvent>,
+    mut q: Query<(&mut CombatStats, Option<&StatusEffects>, &mut RegenBuff)>,
+) {
+    for ev in reader.read() {
+        let Ok((mut stats, se, mut buff)) = q.get_mut(ev.who) else {
+            continue;
+        };
+        let amount = buff.amount_per_turn as f32;
+        match buff.resource {
+            ResourceKind::Health => {
+                let gain = (amount * heal_gate(se).mult).round() as i32;
+                stats.health.restore_to_base(gain);
+            }
+            ResourceKind::Morale => stats.morale.restore_to_base(amount as i32),
+            ResourceKind::Magic => {
+                stats.kiho.restore_to_base(amount);
+                stats.onmyodo.restore_to_base(amount);
+                stats.yokaijutsu.restore_to_base(amount);
+                stats.kamishin.restore_to_base(amount);
+            }
+        }
+        buff.remaining_turns = buff.remaining_turns.saturating_sub(1);
+        if buff.remaining_turns == 0 {
+            commands.entity(ev.who).remove::<RegenBuff>();
+        }
+    }
+}
+
 /// Universal expiry sweep: drops every effect whose `AtTimestamp` deadline
 /// has passed. Runs whenever `Timestamp` changes, which covers both combat
 /// (each turn ticks +1) and world (travel / inn jumps by hours).
@@ -1061,8 +1207,8 @@ pub struct ActionGates {
     pub force_first_action_move: bool,
     /// The actor's turn must end immediately (Terrified T3).
     pub forfeit_turn: bool,
-    /// Probability in 0.0..=1.0 that the actor's intended attack target
-    /// gets re-pointed to their nearest ally (Confused).
+    /// Probability in 0.0..=1.0 that the actor's intended target gets
+    /// re-pointed to a random other combatant, ally or foe (Confused).
     pub confused_retarget_chance: f32,
 }
 
@@ -1127,6 +1273,17 @@ pub fn action_gates(se: Option<&StatusEffects>) -> ActionGates {
     gates
 }
 
+/// Effective chance that a bearer's action is retargeted this turn: the tiered
+/// `BadCondition(Confused)` gate or an explicit [`Confused`] component,
+/// whichever is stronger.
+pub fn retarget_chance(gates: &ActionGates, confused: Option<&Confused>) -> f32 {
+    let explicit = confused
+        .filter(|c| c.remaining_turns > 0)
+        .map(|c| c.chance)
+        .unwrap_or(0.0);
+    gates.confused_retarget_chance.max(explicit).clamp(0.0, 1.0)
+}
+
 /// Signed hit-chance shift from Lucky (buff on attacker's allies) and Unlucky
 /// (debuff on target). Same `tier × 0.10` curve, single function — both
 /// contribute *positively* to the attacker's hit chance ("luck" makes you hit
@@ -1518,12 +1675,17 @@ impl Plugin for StatusEffectsPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<ApplyStatusEvent>()
             .add_message::<RemoveStatusEvent>()
+            .add_message::<ApplyRegenBuffEvent>()
             .add_systems(
                 Update,
                 (
                     apply_status_system,
                     remove_status_system,
                     status_turn_end_tick_system,
+                    confused_turn_end_tick_system,
+                    apply_regen_buff_system,
+                    regen_buff_turn_start_system
+                        .after(crate::combat_plugin::on_turn_start_system),
                     status_expiry_tick_system,
                     status_end_of_combat_system,
                     apply_ap_modifier_system
@@ -1551,3 +1713,88 @@ impl Plugin for StatusEffectsPlugin {
             );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat_plugin::{
+        apply_damage_system, AfterHitEvent, AwardXpEvent, DamageEvent, DeathEvent,
+        InventoryItemCatalog, ItemUsedEvent, LootEvent, TurnManager, TurnStartEvent,
+    };
+    use crate::test_support::enemy_deaths;
+
+    #[test]
+    fn regen_buff_heals_each_turn_start_then_expires() {
+        let mut app = App::new();
+        app.add_message::<TurnStartEvent>()
+            .add_systems(Update, regen_buff_turn_start_system);
+        let mut stats = CombatStats::default();
+        stats.health = StatPool { current: 50, base: 100 };
+        let who = app
+            .world_mut()
+            .spawn((
+                stats,
+                RegenBuff {
+                    amount_per_turn: 5,
+                    remaining_turns: 3,
+                    resource: ResourceKind::Health,
+                },
+            ))
+            .id();
+
+        for expected in [55, 60, 65, 65] {
+            app.world_mut().write_message(TurnStartEvent { who });
+            app.update();
+            let health = app.world().get::<CombatStats>(who).unwrap().health.current;
+            assert_eq!(health, expected);
+        }
+        assert!(app.world().get::<RegenBuff>(who).is_none());
+    }
+
+    #[test]
+    fn bleed_out_has_no_killer_and_awards_no_xp() {
+        let mut app = App::new();
+        app.add_message::<TurnEndEvent>()
+            .add_message::<DamageEvent>()
+            .add_message::<AfterHitEvent>()
+            .add_message::<ItemUsedEvent>()
+            .add_message::<DeathEvent>()
+            .add_message::<LootEvent>()
+            .add_message::<AwardXpEvent>()
+            .init_resource::<InventoryItemCatalog>()
+            .init_resource::<TurnManager>()
+            .init_resource::<crate::combat_plugin::CombatRng>()
+            .add_systems(
+                Update,
+                (status_turn_end_tick_system, apply_damage_system, enemy_deaths).chain(),
+            );
+        let world = app.world_mut();
+        let poisoner = world.spawn_empty().id();
+        let mut stats = CombatStats::default();
+        stats.health = StatPool { current: 5, base: 100 };
+        let mut se = StatusEffects::default();
+        se.apply(StatusInstance {
+            kind: StatusKind::BadCondition(BadConditionKind::Bleeding),
+            tier: 3,
+            expiry: Expiry::EndOfCombat,
+            source: Some(poisoner),
+            dot_counter: 0,
+            resource_focus: None,
+        });
+        let victim = world.spawn((stats, se)).id();
+
+        // Bleeding bites every second turn end.
+        world.write_message(TurnEndEvent { who: victim });
+        world.write_message(TurnEndEvent { who: victim });
+        app.update();
+
+        let deaths: Vec<(Entity, Option<Entity>)> = app
+            .world()
+            .resource::<Messages<DeathEvent>>()
+            .iter_current_update_messages()
+            .map(|d| (d.entity, d.killer))
+            .collect();
+        assert_eq!(deaths, vec![(victim, None)]);
+        assert!(app.world().resource::<Messages<AwardXpEvent>>().is_empty());
+    }
+}
@@ -0,0 +1,74 @@
+//! Helpers for headless system tests that depend on `Res<Time>`.
+//!
+//! An `App` built without `TimePlugin` never advances `Time` on its own, so
+//! timer-driven systems (`fade_out_system`, `follow_path_system`, creature
+//! AI, regen) only move when a test says so, by exactly the delta it asks for.
+//! [`run_until`] covers the other kind of wait: "keep going until X happens".
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::combat_plugin::{
+    AwardXpEvent, DeathBehavior, DeathEvent, EnemyDeathBehavior, Experience, LootEvent,
+    TurnManager,
+};
+
+/// An empty `App` whose `Time` only moves through [`advance`] /
+/// [`advance_frames`].
+pub fn app_with_manual_time() -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::<()>::default());
+    app
+}
+
+/// Advance `Time` by `dt` and run one update.
+pub fn advance(app: &mut App, dt: Duration) {
+    app.world_mut().resource_mut::<Time>().advance_by(dt);
+    app.update();
+}
+
+/// Run `frames` updates, each advancing `Time` by `dt`.
+pub fn advance_frames(app: &mut App, dt: Duration, frames: u32) {
+    for _ in 0..frames {
+        advance(app, dt);
+    }
+}
+
+/// Run updates until `done` holds for the world, at most `max_ticks` of them.
+/// Returns whether the condition was reached; it is checked before the first
+/// tick too, so an already-true condition costs no updates.
+pub fn run_until(app: &mut App, mut done: impl FnMut(&World) -> bool, max_ticks: u32) -> bool {
+    for _ in 0..max_ticks {
+        if done(app.world()) {
+            return true;
+        }
+        app.update();
+    }
+    done(app.world())
+}
+
+/// Resolve this frame's deaths the way an enemy does (marked `Dead`, dropped
+/// from the turn manager), crediting XP only to a real killer. For tests that
+/// run the damage pipeline without the battle plugin's death handling.
+pub fn enemy_deaths(
+    mut commands: Commands,
+    mut deaths: MessageReader<DeathEvent>,
+    mut loot: MessageWriter<LootEvent>,
+    mut xp: MessageWriter<AwardXpEvent>,
+    experience: Query<&Experience>,
+    mut tm: ResMut<TurnManager>,
+) {
+    let behavior = EnemyDeathBehavior { loot_table: vec![] };
+    for ev in deaths.read() {
+        behavior.on_death(
+            ev.entity,
+            ev.killer,
+            &mut commands,
+            &mut loot,
+            &mut xp,
+            &experience,
+            &mut tm,
+        );
+    }
+}
@@ -6,12 +6,14 @@ use crate::battle::{
 };
 use crate::city_data::CityCatalog;
 use crate::combat_plugin::{
-    AwaitingResurrection, Bound, Dead, ResurrectionPoint, ResurrectionStanding,
+    Abilities, AwaitingResurrection, Bound, Dead, ResurrectionPoint, ResurrectionStanding,
 };
 use crate::characters::{CharacterKind, SelectedParty};
 use crate::skill_tree::PartyProgression;
 use crate::core::{GameState, Game_State, MainCamera, Player, Timestamp};
-use crate::dialogue::{CachedInteractables, Interactable};
+use crate::dialogue::{
+    interactable_footprint, CachedInteractables, Interactable, InteractableIndex,
+};
 use crate::economy::{MerchantNpc, Merchants};
 use crate::governance::GovernorNpc;
 use crate::light_plugin::Occluder;
@@ -43,7 +45,7 @@ pub fn setup(
     map: Res<MapTiles>,
     cities: Res<CityCatalog>,
     merchants: Res<Merchants>,
-    query: Query<&Collider>,
+    query: Query<(Entity, &Collider)>,
     creatures: Res<crate::creatures::CreatureCatalog>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
@@ -61,8 +63,8 @@ pub fn setup(
 
     let mut quadtree = QuadtreeNode::new(Rect::from_center_size(Vec2::ZERO, Vec2::splat(2048.0)), 0);
 
-    for collider in &query {
-        quadtree.insert(collider.clone());
+    for (entity, collider) in &query {
+        quadtree.insert_for(entity, collider.clone());
     }
 
     commands.insert_resource(QuadTree(quadtree));
@@ -155,14 +157,16 @@ pub fn setup(
     // Data-driven creatures (see `assets/data/creatures.ron`). Each carries a
     // disposition that `crate::creatures::drive_creatures` reads every frame:
     // hostile ones chase + start fights, territorial ones guard a home, the
-    // skittish hare flees, the tanuki wanders, the shrine fox stands guard.
+    // prowling kasha paths after the player, the skittish hare flees, the
+    // tanuki wanders, the shrine fox stands guard.
     // The encounter id is supplied here at placement time (not in the
     // template): the aggressive ones get ids in the 300+ range for quest/hunt
     // matching, the ambient critters get `None`.
-    let creature_seedlings: [(&str, Vec3, Option<u32>); 6] = [
+    let creature_seedlings: [(&str, Vec3, Option<u32>); 7] = [
         ("wild_onibi", Vec3::new(9.0 * 32.0, -2.0 * 32.0, 0.0), Some(300)),
         ("river_kappa", Vec3::new(-7.0 * 32.0, 7.0 * 32.0, 0.0), Some(301)),
         ("kasha_stalker", Vec3::new(5.0 * 32.0, 8.0 * 32.0, 0.0), Some(302)),
+        ("prowling_kasha", Vec3::new(14.0 * 32.0, 10.0 * 32.0, 0.0), Some(303)),
         ("skittish_hare", Vec3::new(3.0 * 32.0, 5.0 * 32.0, 0.0), None),
         ("wandering_tanuki", Vec3::new(-3.0 * 32.0, -7.0 * 32.0, 0.0), None),
         ("shrine_fox", Vec3::new(-11.0 * 32.0, 5.0 * 32.0, 0.0), None),
@@ -435,13 +439,22 @@ pub fn setup(
 
     // The mid-game mini-boss: the Jorōgumo on the river road, gating the way to
     // the shrine. Tough, casts, drops good loot — but not a `FinalBoss`, so
-    // beating it returns to exploration (no Victory).
+    // beating it returns to exploration (no Victory). The fight is hemmed in
+    // to a strip of road between river and cliff, with two web-strung boulders
+    // to fight around.
     commands.spawn((
         PlaceholderVisual::character(Color::srgb(0.55, 0.20, 0.35)),
         Transform::from_translation(origin3 + Vec3::new(15.0 * 32.0, 3.0 * 32.0, 0.0)),
         EnemyEncounter {
             id: crate::battle::MINIBOSS_ENCOUNTER_ID,
         },
+        crate::battle::BattleArena {
+            half_extents: Vec2::new(10.0 * 32.0, 3.0 * 32.0),
+            obstacles: vec![
+                Rect::from_center_size(Vec2::new(-4.0 * 32.0, 32.0), Vec2::splat(48.0)),
+                Rect::from_center_size(Vec2::new(3.0 * 32.0, -32.0), Vec2::splat(48.0)),
+            ],
+        },
         VisualOcclusionTarget,
         YSort { base_z: 0.0 },
         crate::light_plugin::LightSensitive { threshold: 0.15 },
@@ -518,13 +531,14 @@ fn first_tile_world_position_for_region(map: &MapTiles, region_id: u16) -> Optio
 
 pub fn update_interactable_cache(
     mut cache: ResMut<CachedInteractables>,
-    query: Query<(&Transform, &Interactable), With<Interactable>>,
+    mut index: ResMut<InteractableIndex>,
+    query: Query<(Entity, &Transform, &Interactable)>,
 ) {
-    rebuild_interactable_cache(&mut cache, &query);
+    rebuild_interactable_cache(&mut cache, &mut index, &query);
 }
 
 pub fn update_quad_tree(
-    query: Query<&Collider>,
+    query: Query<(Entity, &Collider)>,
     mut quad_tree: ResMut<QuadTree>,
 ) {
     rebuild_quad_tree(&query, &mut quad_tree);
@@ -532,37 +546,30 @@ pub fn update_quad_tree(
 
 fn rebuild_interactable_cache(
     cache: &mut CachedInteractables,
-    query: &Query<(&Transform, &Interactable), With<Interactable>>,
+    index: &mut InteractableIndex,
+    query: &Query<(Entity, &Transform, &Interactable)>,
 ) {
     let entries = cache.0.len().max(query.iter().size_hint().0);
     cache.0.clear();
     cache.0.reserve(entries);
     // Transform is Copy, so dereferencing avoids the clone we used to do.
-    for (transform, interactable) in query.iter() {
+    for (_, transform, interactable) in query.iter() {
         cache.0.push((*transform, interactable.clone()));
     }
+    index.0 = QuadTree::enclosing(
+        query
+            .iter()
+            .map(|(entity, transform, _)| {
+                let bounds = interactable_footprint(transform.translation.truncate());
+                (entity, Collider { bounds })
+            })
+            .collect(),
+    );
 }
 
-fn rebuild_quad_tree(query: &Query<&Collider>, quad_tree: &mut QuadTree) {
-    // Enclose all colliders. The world is centered on the tile origin (~2048),
-    // far from (0,0), so the old fixed origin-centered root rect (±1024) missed
-    // every collider and collision never registered.
-    let mut min = Vec2::splat(f32::MAX);
-    let mut max = Vec2::splat(f32::MIN);
-    for collider in query.iter() {
-        min = min.min(collider.bounds.min);
-        max = max.max(collider.bounds.max);
-    }
-    let root = if min.x <= max.x {
-        Rect::from_corners(min - Vec2::splat(512.0), max + Vec2::splat(512.0))
-    } else {
-        Rect::from_center_size(Vec2::ZERO, Vec2::splat(2048.0))
-    };
-    let mut quadtree = QuadtreeNode::new(root, 0);
-    for collider in query.iter() {
-        quadtree.insert(collider.clone());
-    }
-    quad_tree.0 = quadtree;
+fn rebuild_quad_tree(query: &Query<(Entity, &Collider)>, quad_tree: &mut QuadTree) {
+    *quad_tree =
+        QuadTree::enclosing(query.iter().map(|(entity, c)| (entity, c.clone())).collect());
 }
 
 /// Only rebuilds the interactable cache and quadtree when something actually
@@ -572,17 +579,21 @@ fn rebuild_quad_tree(query: &Query<&Collider>, quad_tree: &mut QuadTree) {
 /// short-circuit is a clear win.
 pub fn update_cache(
     mut cache_interactables: ResMut<CachedInteractables>,
-    interactable_query: Query<(&Transform, &Interactable), With<Interactable>>,
+    mut interactable_index: ResMut<InteractableIndex>,
+    interactable_query: Query<(Entity, &Transform, &Interactable)>,
     interactable_changed: Query<
         Entity,
-        Or<(
-            Added<Interactable>,
-            Changed<Interactable>,
-            Changed<Transform>,
-        )>,
+        (
+            With<Interactable>,
+            Or<(
+                Added<Interactable>,
+                Changed<Interactable>,
+                Changed<Transform>,
+            )>,
+        ),
     >,
     removed_interactables: RemovedComponents<Interactable>,
-    collider_query: Query<&Collider>,
+    collider_query: Query<(Entity, &Collider)>,
     collider_changed: Query<Entity, Or<(Added<Collider>, Changed<Collider>)>>,
     removed_colliders: RemovedComponents<Collider>,
     mut quad_tree: ResMut<QuadTree>,
@@ -590,7 +601,11 @@ pub fn update_cache(
     let interactables_dirty =
         !interactable_changed.is_empty() || !removed_interactables.is_empty();
     if interactables_dirty {
-        rebuild_interactable_cache(&mut cache_interactables, &interactable_query);
+        rebuild_interactable_cache(
+            &mut cache_interactables,
+            &mut interactable_index,
+            &interactable_query,
+        );
     }
 
     let colliders_dirty = !collider_changed.is_empty() || !removed_colliders.is_empty();
@@ -667,6 +682,8 @@ pub fn spawn_party(
         Bound,
         ResurrectionStanding::default(),
         leader.combat_stats(),
+        // Known abilities, for the field ones cast while exploring.
+        Abilities(leader.abilities()),
         VisualOcclusionTarget,
         YSort { base_z: 0.0 },
         crate::light_plugin::LightSensitive { threshold: 0.15 },
@@ -688,6 +705,7 @@ pub fn spawn_party(
             // leader role without missing any state (see `apply_set_leader_system`
             // and `auto_promote_dead_leader_system`).
             kind.combat_stats(),
+            Abilities(kind.abilities()),
             Bound,
             ResurrectionStanding::default(),
             CombatMovePoints::default(),
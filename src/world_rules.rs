@@ -306,8 +306,12 @@ fn apply_action(
                 );
                 return;
             }
-            if !mutations.runtime.start(scene_id.clone(), &mutations.catalog) {
-                warn!("world_rules: StartDialogueScene '{scene_id}' — scene not found");
+            if !mutations.runtime.start(
+                scene_id.clone(),
+                &mutations.catalog,
+                &effects.condition_view(),
+            ) {
+                warn!("world_rules: StartDialogueScene '{scene_id}' — nothing to open");
             }
         }
     }
@@ -0,0 +1,282 @@
+//! Recent-events ticker for exploration: the overworld's answer to the
+//! battle log.
+//!
+//! Systems that notice something worth a line outside battle (a body looted,
+//! dusk falling) call [`WorldTicker::push`]; item pickups and story flags are
+//! read off their messages here. Each entry lives for
+//! [`TICKER_ENTRY_SECONDS`] and the last [`TICKER_LINES`] still alive are shown
+//! bottom-left while `Exploring`; the panel is hidden when nothing is left.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::constants::TIMESTAMP_TICKS_PER_HOUR;
+use crate::core::{GameState, Game_State, Timestamp};
+use crate::quests::ItemPickupEvent;
+use crate::story_flags::FlagChangedEvent;
+use crate::ui_style::{font_size, palette, radius, spacing};
+
+/// Most entries kept (and shown) at once; older ones drop off the top.
+pub const TICKER_LINES: usize = 5;
+
+/// How long one entry stays on the ticker, in seconds.
+pub const TICKER_ENTRY_SECONDS: f32 = 6.0;
+
+#[derive(Debug, Clone)]
+pub struct TickerEntry {
+    pub text: String,
+    pub remaining: f32,
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct WorldTicker {
+    pub entries: VecDeque<TickerEntry>,
+    dirty: bool,
+}
+
+impl WorldTicker {
+    pub fn push(&mut self, text: impl Into<String>) {
+        self.entries.push_back(TickerEntry { text: text.into(), remaining: TICKER_ENTRY_SECONDS });
+        while self.entries.len() > TICKER_LINES {
+            self.entries.pop_front();
+        }
+        self.dirty = true;
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|e| e.text.as_str())
+    }
+}
+
+/// Coarse parts of the day. Night matches the camp-ambush window in
+/// `rest.rs` (20:00 to 05:00).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayPeriod {
+    Dawn,
+    Day,
+    Dusk,
+    Night,
+}
+
+impl DayPeriod {
+    pub fn at(tick: u32) -> Self {
+        match (tick / TIMESTAMP_TICKS_PER_HOUR) % 24 {
+            5..=7 => DayPeriod::Dawn,
+            8..=16 => DayPeriod::Day,
+            17..=19 => DayPeriod::Dusk,
+            _ => DayPeriod::Night,
+        }
+    }
+
+    fn announcement(self) -> &'static str {
+        match self {
+            DayPeriod::Dawn => "Dawn breaks.",
+            DayPeriod::Day => "The sun is up.",
+            DayPeriod::Dusk => "Dusk falls.",
+            DayPeriod::Night => "Night settles in.",
+        }
+    }
+}
+
+/// Push a line whenever the clock crosses into a new part of the day, however
+/// it got there (walking, travel, a night at the inn). The first run only
+/// records where the clock starts.
+pub fn ticker_time_of_day(
+    timestamp: Res<Timestamp>,
+    mut last: Local<Option<DayPeriod>>,
+    mut ticker: ResMut<WorldTicker>,
+) {
+    let now = DayPeriod::at(timestamp.0);
+    if last.is_some_and(|prev| prev != now) {
+        ticker.push(now.announcement());
+    }
+    *last = Some(now);
+}
+
+/// Push a line for each item picked up in the world, by name while the item
+/// still has one.
+pub fn ticker_item_pickups(
+    mut pickups: MessageReader<ItemPickupEvent>,
+    names: Query<&Name>,
+    mut ticker: ResMut<WorldTicker>,
+) {
+    for ev in pickups.read() {
+        ticker.push(match names.get(ev.entity) {
+            Ok(name) => format!("Picked up {name}."),
+            Err(_) => "Picked something up.".to_string(),
+        });
+    }
+}
+
+/// Push a line for each story flag that gets set (a discovery, a persuaded
+/// guard), written out from its snake_case name. Clearing one is bookkeeping
+/// and stays off the ticker.
+pub fn ticker_flag_changes(
+    mut changes: MessageReader<FlagChangedEvent>,
+    mut ticker: ResMut<WorldTicker>,
+) {
+    for ev in changes.read().filter(|ev| ev.set) {
+        ticker.push(flag_line(&ev.name));
+    }
+}
+
+/// `found_shrine_cache` → `Found shrine cache.`
+fn flag_line(name: &str) -> String {
+    let words = name.replace('_', " ");
+    let mut chars = words.chars();
+    let first = chars.next().map(|c| c.to_uppercase().to_string()).unwrap_or_default();
+    format!("{first}{}.", chars.as_str())
+}
+
+/// Age the entries and drop the expired ones. Only counts down while
+/// exploring, so a line pushed just before a battle is still there after it.
+fn tick_world_ticker(time: Res<Time>, game_state: Res<GameState>, mut ticker: ResMut<WorldTicker>) {
+    if game_state.0 != Game_State::Exploring {
+        return;
+    }
+    let before = ticker.entries.len();
+    let dt = time.delta_secs();
+    ticker.entries.retain_mut(|e| {
+        e.remaining -= dt;
+        e.remaining > 0.0
+    });
+    if ticker.entries.len() != before {
+        ticker.dirty = true;
+    }
+}
+
+#[derive(Component)]
+struct WorldTickerRoot;
+
+#[derive(Component)]
+struct WorldTickerText;
+
+fn spawn_world_ticker(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(spacing::LG),
+                left: Val::Px(spacing::LG),
+                max_width: Val::Px(320.0),
+                padding: UiRect::all(Val::Px(spacing::SM)),
+                border: UiRect::all(Val::Px(1.0)),
+                border_radius: BorderRadius::all(Val::Px(radius::MD)),
+                ..default()
+            },
+            BackgroundColor(palette::BG_OVERLAY),
+            BorderColor::all(palette::BORDER_SUBTLE),
+            Visibility::Hidden,
+            WorldTickerRoot,
+        ))
+        .with_children(|p| {
+            p.spawn((
+                Text::new(""),
+                TextFont { font_size: font_size::SMALL, ..default() },
+                TextColor(palette::TEXT_SECONDARY),
+                WorldTickerText,
+            ));
+        });
+}
+
+fn render_world_ticker(
+    game_state: Res<GameState>,
+    mut ticker: ResMut<WorldTicker>,
+    mut root_q: Query<&mut Visibility, With<WorldTickerRoot>>,
+    mut text_q: Query<&mut Text, With<WorldTickerText>>,
+) {
+    let visible = game_state.0 == Game_State::Exploring && !ticker.entries.is_empty();
+    for mut vis in &mut root_q {
+        let want = if visible { Visibility::Inherited } else { Visibility::Hidden };
+        if *vis != want {
+            *vis = want;
+        }
+    }
+    if !ticker.dirty {
+        return;
+    }
+    ticker.dirty = false;
+    let joined = ticker.lines().collect::<Vec<_>>().join("\n");
+    for mut text in &mut text_q {
+        text.0 = joined.clone();
+    }
+}
+
+pub struct WorldTickerPlugin;
+
+impl Plugin for WorldTickerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WorldTicker>()
+            .add_systems(Startup, spawn_world_ticker)
+            .add_systems(
+                Update,
+                (
+                    ticker_time_of_day,
+                    ticker_item_pickups,
+                    ticker_flag_changes,
+                    tick_world_ticker,
+                    render_world_ticker,
+                )
+                    .chain(),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Player;
+    use crate::economy::{PlayerInventory, PlayerWallet};
+    use crate::equipment::{ground_loot_pickup_system, EnemyCorpse};
+
+    #[test]
+    fn looting_and_nightfall_both_reach_the_ticker() {
+        let mut app = crate::test_support::app_with_manual_time();
+        app.insert_resource(GameState(Game_State::Exploring))
+            .insert_resource(Timestamp(19 * TIMESTAMP_TICKS_PER_HOUR))
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<PlayerWallet>()
+            .init_resource::<PlayerInventory>()
+            .init_resource::<WorldTicker>()
+            .add_systems(Update, (ground_loot_pickup_system, ticker_time_of_day));
+        app.world_mut().spawn((Player, Transform::from_xyz(0.0, 0.0, 0.0)));
+        app.world_mut().spawn((
+            Transform::from_xyz(10.0, 0.0, 0.0),
+            EnemyCorpse { coins: 40, items: vec![1001] },
+        ));
+        app.update();
+        assert!(app.world().resource::<WorldTicker>().entries.is_empty());
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyY);
+        app.update();
+        let lines: Vec<String> =
+            app.world().resource::<WorldTicker>().lines().map(str::to_string).collect();
+        assert_eq!(lines.len(), 1, "{lines:?}");
+        assert!(lines[0].contains("40 mon"), "{lines:?}");
+
+        app.world_mut().resource_mut::<Timestamp>().0 = 20 * TIMESTAMP_TICKS_PER_HOUR;
+        app.update();
+        let ticker = app.world().resource::<WorldTicker>();
+        assert_eq!(ticker.lines().last(), Some(DayPeriod::Night.announcement()));
+        assert_eq!(ticker.entries.len(), 2);
+    }
+
+    #[test]
+    fn pickups_and_set_flags_reach_the_ticker() {
+        let mut app = App::new();
+        app.add_message::<ItemPickupEvent>()
+            .add_message::<FlagChangedEvent>()
+            .init_resource::<WorldTicker>()
+            .add_systems(Update, (ticker_item_pickups, ticker_flag_changes).chain());
+        let comb = app.world_mut().spawn(Name::new("Jade Comb")).id();
+        let world = app.world_mut();
+        world.write_message(ItemPickupEvent { entity: comb });
+        world.write_message(FlagChangedEvent { name: "found_shrine_cache".into(), set: true });
+        world.write_message(FlagChangedEvent { name: "gate_locked".into(), set: false });
+        app.update();
+
+        let lines: Vec<&str> = app.world().resource::<WorldTicker>().lines().collect();
+        assert_eq!(lines, vec!["Picked up Jade Comb.", "Found shrine cache."]);
+    }
+}
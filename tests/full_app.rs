@@ -0,0 +1,19 @@
+//! Canary: the real game `App` builds and survives a frame.
+//!
+//! `headless_full_game_app()` is `run_full_game()`'s app minus the window and
+//! the GPU: every game plugin, resource and system is there. A missing
+//! resource, a conflicting system-set order or a duplicate plugin only shows up
+//! once the whole thing is assembled, and the narrower tests build their own
+//! small apps, so this is the one place that catches it.
+
+use SeireiKuniBevy::core::{GameState, Game_State};
+use SeireiKuniBevy::headless_full_game_app;
+
+#[test]
+fn full_app_builds_and_runs_a_frame() {
+    let mut app = headless_full_game_app();
+    app.update();
+    app.update();
+
+    assert_eq!(app.world().resource::<GameState>().0, Game_State::MainMenu);
+}
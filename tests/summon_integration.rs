@@ -17,7 +17,9 @@
 
 use bevy::asset::{AssetApp, AssetPlugin};
 use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
 use bevy::MinimalPlugins;
+use std::time::Duration;
 
 use SeireiKuniBevy::ai_decision::AiDecisionPlugin;
 use SeireiKuniBevy::battle::{
@@ -85,6 +87,7 @@ fn spawn_inert(app: &mut App, side: BattleSide, hp: i32, speed: i32) -> Entity {
         lethality: <StatPool<i32>>::new(10),
         hit: <StatPool<i32>>::new(60),
         armor: <StatPool<i32>>::new(2),
+        resist: <StatPool<i32>>::new(0),
         speed: <StatPool<i32>>::new(speed),
         evasion: <StatPool<i32>>::new(0), // never dodge, so shikigami damage lands
         mind: <StatPool<i32>>::new(4),
@@ -151,6 +154,10 @@ fn shikigami_summons_acts_and_expires_after_three_turns() {
         // summon, which just spawns beside the caster.
         .init_resource::<SeireiKuniBevy::quadtree::QuadTree>()
         .insert_resource(ShikiTurns::default())
+        // Turns wait out a short between-turns pause on the real clock, which
+        // barely moves between back-to-back headless updates. Step a fixed
+        // tenth of a second per update so the 400-tick cap covers the fight.
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)))
         // The two summon systems live in the game's app builder (lib.rs), not in
         // CombatPlugin — wire them here exactly as the game does.
         .add_systems(Update, resolve_summon_system)